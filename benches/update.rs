@@ -0,0 +1,29 @@
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use pillbugplants::world::World;
+
+// Ticks timed per iteration, from the same seeded world every time
+const STEPS: usize = 10;
+
+fn seeded(populated: Option<f32>) -> World {
+    let mut world = World::new_seeded(120, 60, 42);
+    if let Some(density) = populated {
+        world.populate_stress_test(density);
+    }
+    world
+}
+
+fn bench_update(c: &mut Criterion) {
+    for (name, density) in [("generated", None), ("crowded", Some(0.6))] {
+        c.bench_function(&format!("{}_{}_steps", name, STEPS), |b| {
+            b.iter_batched(|| seeded(density), |mut world| {
+                for _ in 0..STEPS {
+                    world.step_once();
+                }
+                world
+            }, BatchSize::LargeInput)
+        });
+    }
+}
+
+criterion_group!(benches, bench_update);
+criterion_main!(benches);
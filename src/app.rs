@@ -1,32 +1,329 @@
-use std::io;
-use crossterm::event::{self, Event, KeyCode};
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, Write};
+use std::path::Path;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+use crossterm::event::{self, Event, KeyCode, MouseButton, MouseEventKind};
 use ratatui::{
     backend::Backend,
-    layout::{Constraint, Direction, Layout},
+    layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
     Frame, Terminal,
 };
+use crate::replay::{Recording, Replay};
+use crate::types::{CLEAN_WATER, ColorTheme, RenderPalette, TileType, Size, SystemFlags, blend, parse_color_theme};
 use crate::world::World;
 
+/// Fastest and slowest tick intervals reachable with '+' and '-'
+pub const MIN_TICK_INTERVAL_MS: u64 = 10;
+pub const MAX_TICK_INTERVAL_MS: u64 = 2000;
+
+/// Tiles the number keys select for mouse painting
+pub const BRUSHES: [(char, TileType); 9] = [
+    ('0', TileType::Empty),
+    ('1', TileType::Dirt),
+    ('2', TileType::Sand),
+    ('3', TileType::Water(150, CLEAN_WATER)),
+    ('4', TileType::Nutrient),
+    ('5', TileType::PlantStem(0, Size::Medium)),
+    ('6', TileType::Seed(0, Size::Medium)),
+    ('7', TileType::Clay),
+    ('8', TileType::Rock),
+];
+
+/// File the 's' and 'l' keys save to and load from
+pub const SAVE_PATH: &str = "world.save";
+
+// Share of the season's ambient color mixed into every tile while the seasonal tint is on
+const AMBIENT_TINT_STRENGTH: f32 = 0.25;
+
 pub struct App {
     pub world: World,
     pub show_taxonomy: bool,
     pub show_performance: bool,
+    pub show_inspector: bool,
+    pub show_nutrients: bool,   // Shade the world view by nutrient density instead of tile colors
+    pub show_pheromones: bool,  // Shade the world view by pillbug pheromone, over the nutrients if both are on
+    pub show_disturbance: bool, // Shade the world view by disturbance, over the nutrients but under the pheromone
+    pub show_events: bool,      // Side panel of the latest events, newest first
+    pub palette: RenderPalette, // Colors tiles are drawn in, cycled with 'c'
+    pub theme: ColorTheme,      // Colors from `load_palette` that replace the default palette's for the tiles they name
+    pub seasonal_tint: bool,    // Lean the default colors toward the season and time of day, toggled with 'a'
+    pub cursor: (usize, usize), // Tile under inspection, moved with the arrow keys
+    pub status: Option<String>, // Result of the last save or load, shown in the info bar
+    pub paused: bool,           // Freeze the simulation; '.' still steps one tick
+    pub tick_interval_ms: u64,  // Target time between ticks, adjusted with '+' and '-'
+    pub brush: TileType,        // Tile painted with the mouse, picked with the number keys
+    pub camera: (usize, usize), // World tile at the top-left of the view
+    pub view: (usize, usize),   // Tiles that fit in the view, updated every frame
+    pub recording: Option<Recording<BufWriter<File>>>, // Every tick and edit is written here while set
+    pub replay: Option<Replay>, // Ticks step through this recording instead of simulating
 }
 
 impl App {
     pub fn new(width: usize, height: usize) -> Self {
+        App::with_world(World::new(width, height))
+    }
+    
+    pub fn with_world(world: World) -> Self {
+        let (width, height) = (world.width, world.height);
         App {
-            world: World::new(width, height),
+            world,
             show_taxonomy: false,
             show_performance: false,
+            show_inspector: false,
+            show_nutrients: false,
+            show_pheromones: false,
+            show_disturbance: false,
+            show_events: false,
+            palette: RenderPalette::Default,
+            theme: ColorTheme::new(),
+            seasonal_tint: true,
+            cursor: (width / 2, height / 2),
+            status: None,
+            paused: false,
+            tick_interval_ms: 100,
+            brush: TileType::Dirt,
+            camera: (0, 0),
+            view: (width, height),
+            recording: None,
+            replay: None,
+        }
+    }
+    
+    /// Play back a recording, starting paused at its first frame. Editing the world is off
+    /// while replaying.
+    pub fn with_replay(world: World, replay: Replay) -> Self {
+        let mut app = App::with_world(world);
+        app.replay = Some(replay);
+        app.paused = true;
+        app
+    }
+    
+    /// Throw the current world away and generate the next seed's world at the same size,
+    /// keeping the settings
+    pub fn regenerate(&mut self) {
+        let old = &self.world;
+        let mut world = World::new_seeded_with_config(old.width, old.height, old.seed.wrapping_add(1), old.world_config);
+        world.apply_settings(old.settings());
+        self.world = world;
+    }
+    
+    /// Save the world to `SAVE_PATH`, reporting the outcome in the status line
+    pub fn save(&mut self) {
+        let result = File::create(SAVE_PATH).and_then(|file| {
+            let mut writer = BufWriter::new(file);
+            self.world.save_to_writer(&mut writer)?;
+            writer.flush()
+        });
+        self.status = Some(match result {
+            Ok(()) => format!("Saved tick {} to {}", self.world.tick, SAVE_PATH),
+            Err(err) => format!("Save failed: {}", err),
+        });
+    }
+    
+    /// Replace the world with the one in `SAVE_PATH`, keeping the current world if loading fails
+    pub fn load(&mut self) {
+        let result = File::open(SAVE_PATH).and_then(|file| World::load_from_reader(&mut BufReader::new(file)));
+        self.status = Some(match result {
+            Ok(world) => {
+                self.world = world;
+                self.move_cursor(0, 0); // Keep the cursor inside a smaller world
+                format!("Loaded tick {} from {}", self.world.tick, SAVE_PATH)
+            }
+            Err(err) => format!("Load failed: {}", err),
+        });
+    }
+    
+    /// Draw tiles in the colors a theme file gives them (see `parse_color_theme`) while the default
+    /// palette is on, keeping the built-in colors for tiles it doesn't name
+    pub fn load_palette(&mut self, path: impl AsRef<Path>) -> io::Result<()> {
+        self.theme = parse_color_theme(&fs::read_to_string(path)?)?;
+        Ok(())
+    }
+    
+    /// A tile's color in the default palette: the theme's for its kind, or else the world's own
+    /// with species tints
+    fn default_color(&self, x: usize, y: usize) -> Color {
+        let tile = self.world.tiles[y][x];
+        if self.theme.contains_key(tile.name()) {
+            tile.to_color_themed(&self.theme)
+        } else {
+            self.world.tile_color(x, y)
+        }
+    }
+    
+    /// Halve the tick interval, down to `MIN_TICK_INTERVAL_MS`
+    pub fn speed_up(&mut self) {
+        self.tick_interval_ms = (self.tick_interval_ms / 2).max(MIN_TICK_INTERVAL_MS);
+    }
+    
+    /// Double the tick interval, up to `MAX_TICK_INTERVAL_MS`
+    pub fn slow_down(&mut self) {
+        self.tick_interval_ms = (self.tick_interval_ms * 2).min(MAX_TICK_INTERVAL_MS);
+    }
+    
+    /// Set the tile under the inspection cursor alight
+    pub fn ignite_at_cursor(&mut self) {
+        let (x, y) = self.cursor;
+        if !self.world.ignite(x, y) {
+            self.status = Some(format!("{} won't burn", self.world.tiles[y][x].name()));
+        }
+    }
+    
+    /// Pick the brush bound to a number key in `BRUSHES`
+    pub fn select_brush(&mut self, key: char) {
+        if let Some((_, tile)) = BRUSHES.iter().find(|(k, _)| *k == key) {
+            self.brush = *tile;
+        }
+    }
+    
+    /// Paint the brush onto a world tile as part of the current edit batch, which a click
+    /// starts with `World::begin_edit`. Painted cells don't belong to a generated soil stratum.
+    pub fn paint(&mut self, x: usize, y: usize) {
+        if x < self.world.width && y < self.world.height {
+            self.world.edit_tile(x, y, self.brush);
+            self.world.soil_layers[y][x] = None;
+        }
+    }
+    
+    /// Revert the last batch of edits, reporting it in the status line
+    pub fn undo(&mut self) {
+        self.status = Some(match self.world.undo_edit() {
+            0 => "Nothing to undo".to_string(),
+            restored => format!("Undid {} tile edit{}", restored, if restored == 1 { "" } else { "s" }),
+        });
+    }
+    
+    /// World tile under a terminal cell, given the terminal area `ui` drew into
+    pub fn tile_at(&self, area: Rect, column: u16, row: u16) -> Option<(usize, usize)> {
+        let view = world_view(area, self);
+        if column < view.x || row < view.y || column >= view.x + view.width || row >= view.y + view.height {
+            return None;
+        }
+        let x = self.camera.0 + (column - view.x) as usize;
+        let y = self.camera.1 + (row - view.y) as usize;
+        (x < self.world.width && y < self.world.height).then_some((x, y))
+    }
+    
+    /// Resize the view to the terminal area `ui` drew into, keeping the camera inside the world
+    pub fn fit_view(&mut self, area: Rect) {
+        let view = world_view(area, self);
+        self.view = (view.width as usize, view.height as usize);
+        self.pan(0, 0);
+    }
+    
+    /// Scroll the camera, stopping where the view meets the world's edges
+    pub fn pan(&mut self, dx: i32, dy: i32) {
+        let max_x = self.world.width.saturating_sub(self.view.0) as i32;
+        let max_y = self.world.height.saturating_sub(self.view.1) as i32;
+        self.camera = (
+            (self.camera.0 as i32 + dx).clamp(0, max_x) as usize,
+            (self.camera.1 as i32 + dy).clamp(0, max_y) as usize,
+        );
+    }
+    
+    /// Move the inspection cursor, staying inside the world and scrolling the camera to follow it
+    pub fn move_cursor(&mut self, dx: i32, dy: i32) {
+        let (x, y) = self.cursor;
+        self.cursor = (
+            (x as i32 + dx).clamp(0, self.world.width as i32 - 1) as usize,
+            (y as i32 + dy).clamp(0, self.world.height as i32 - 1) as usize,
+        );
+        let (x, y) = (self.cursor.0 as i32, self.cursor.1 as i32);
+        let (left, top) = (self.camera.0 as i32, self.camera.1 as i32);
+        let (view_width, view_height) = (self.view.0.max(1) as i32, self.view.1.max(1) as i32);
+        let pan_x = (x - left).min(0) + (x - (left + view_width - 1)).max(0);
+        let pan_y = (y - top).min(0) + (y - (top + view_height - 1)).max(0);
+        self.pan(pan_x, pan_y);
+    }
+    
+    /// Arrow keys move the cursor while the inspector is open, and pan the camera otherwise
+    fn arrow(&mut self, dx: i32, dy: i32) {
+        if self.show_inspector {
+            self.move_cursor(dx, dy);
+        } else {
+            self.pan(dx, dy);
         }
     }
     
     pub fn tick(&mut self) {
+        if let Some(replay) = self.replay.as_mut() {
+            // Playback stops at the end of the recording
+            if !replay.step_forward(&mut self.world) {
+                self.paused = true;
+            }
+            return;
+        }
         self.world.update();
+        if let Some(recording) = self.recording.as_mut() {
+            if let Err(err) = recording.capture(&self.world) {
+                self.recording = None;
+                self.status = Some(format!("Recording stopped: {}", err));
+            }
+        }
+    }
+    
+    /// Step a replay back one frame
+    pub fn step_back(&mut self) {
+        if let Some(replay) = self.replay.as_mut() {
+            replay.step_back(&mut self.world);
+        }
+    }
+    
+    /// Apply one key press or mouse event from the terminal `area`. Returns true when it asks to
+    /// quit; the simulation only ticks on `run_app`'s clock, never in response to input.
+    pub fn handle_event(&mut self, event: Event, area: Rect) -> bool {
+        match event {
+            Event::Key(key) => match key.code {
+                KeyCode::Char('q') => return true,
+                KeyCode::Char('t') => self.show_taxonomy = !self.show_taxonomy,
+                KeyCode::Char('p') => self.show_performance = !self.show_performance,
+                KeyCode::Char('i') => self.show_inspector = !self.show_inspector,
+                KeyCode::Char('e') => self.show_events = !self.show_events,
+                KeyCode::Char('n') => self.show_nutrients = !self.show_nutrients,
+                KeyCode::Char('o') => self.show_pheromones = !self.show_pheromones,
+                KeyCode::Char('d') => self.show_disturbance = !self.show_disturbance,
+                KeyCode::Char('c') => self.palette = self.palette.next(),
+                KeyCode::Char('a') => self.seasonal_tint = !self.seasonal_tint,
+                // Shifted letters switch whole systems off and on
+                KeyCode::Char('R') => self.world.systems.toggle(SystemFlags::RAIN),
+                KeyCode::Char('P') => self.world.systems.toggle(SystemFlags::PHYSICS),
+                KeyCode::Char('G') => self.world.systems.toggle(SystemFlags::GRAVITY),
+                KeyCode::Char('W') => self.world.systems.toggle(SystemFlags::WIND),
+                KeyCode::Char('N') => self.world.systems.toggle(SystemFlags::NUTRIENTS),
+                KeyCode::Char('L') => self.world.systems.toggle(SystemFlags::LIFE),
+                KeyCode::Char('r') if self.replay.is_none() => self.regenerate(),
+                KeyCode::Char('f') if self.replay.is_none() => self.ignite_at_cursor(),
+                KeyCode::Char('s') => self.save(),
+                KeyCode::Char('l') if self.replay.is_none() => self.load(),
+                KeyCode::Char('u') if self.replay.is_none() => self.undo(),
+                KeyCode::Char(' ') => self.paused = !self.paused,
+                KeyCode::Char('.') if self.paused => self.tick(),
+                KeyCode::Char(',') if self.paused => self.step_back(),
+                KeyCode::Char('+') | KeyCode::Char('=') => self.speed_up(),
+                KeyCode::Char('-') => self.slow_down(),
+                KeyCode::Char(key @ '0'..='8') => self.select_brush(key),
+                KeyCode::Left => self.arrow(-1, 0),
+                KeyCode::Right => self.arrow(1, 0),
+                KeyCode::Up => self.arrow(0, -1),
+                KeyCode::Down => self.arrow(0, 1),
+                _ => {}
+            },
+            Event::Mouse(mouse) if self.replay.is_none() && matches!(mouse.kind, MouseEventKind::Down(MouseButton::Left) | MouseEventKind::Drag(MouseButton::Left)) => {
+                // Each click starts a batch, so one undo takes back a whole drag
+                if mouse.kind == MouseEventKind::Down(MouseButton::Left) {
+                    self.world.begin_edit();
+                }
+                if let Some((x, y)) = self.tile_at(area, mouse.column, mouse.row) {
+                    self.paint(x, y);
+                }
+            }
+            _ => {}
+        }
+        false
     }
 }
 
@@ -34,190 +331,267 @@ pub fn run_app<B: Backend>(
     terminal: &mut Terminal<B>,
     app: &mut App,
 ) -> io::Result<()> {
+    let mut last_tick = Instant::now();
     loop {
-        terminal.draw(|f| ui(f, app))?;
-
-        if event::poll(std::time::Duration::from_millis(100))? {
-            if let Event::Key(key) = event::read()? {
-                match key.code {
-                    KeyCode::Char('q') => return Ok(()),
-                    KeyCode::Char('t') => app.show_taxonomy = !app.show_taxonomy,
-                    KeyCode::Char('p') => app.show_performance = !app.show_performance,
-                    _ => {}
-                }
-            }
+        let area = terminal.draw(|f| ui(f, app))?.area;
+        app.fit_view(area);
+        
+        // Wait out only what's left of this tick, so a stream of key presses or mouse drags
+        // can't speed the simulation up
+        let tick_interval = Duration::from_millis(app.tick_interval_ms);
+        if event::poll(tick_interval.saturating_sub(last_tick.elapsed()))? && app.handle_event(event::read()?, area) {
+            return Ok(());
         }
         
-        app.tick();
+        if last_tick.elapsed() >= tick_interval {
+            if !app.paused {
+                app.tick();
+            }
+            last_tick = Instant::now();
+        }
     }
 }
 
-pub fn ui(f: &mut Frame, app: &App) {
-    let main_chunks = match (app.show_taxonomy, app.show_performance) {
-        (true, true) => {
-            Layout::default()
-                .direction(Direction::Horizontal)
-                .margin(1)
-                .constraints([Constraint::Min(0), Constraint::Length(25), Constraint::Length(30)].as_ref())
-                .split(f.area())
-        },
-        (true, false) => {
-            Layout::default()
-                .direction(Direction::Horizontal)
-                .margin(1)
-                .constraints([Constraint::Min(0), Constraint::Length(25)].as_ref())
-                .split(f.area())
-        },
-        (false, true) => {
-            Layout::default()
-                .direction(Direction::Horizontal)
-                .margin(1)
-                .constraints([Constraint::Min(0), Constraint::Length(30)].as_ref())
-                .split(f.area())
-        },
-        (false, false) => {
-            Layout::default()
-                .direction(Direction::Horizontal)
-                .margin(1)
-                .constraints([Constraint::Min(0)].as_ref())
-                .split(f.area())
-        }
+/// Heatmap shade for a value from 0.0 (dark blue) through red to 1.0 (yellow)
+fn heat_color(value: f32) -> Color {
+    let value = value.clamp(0.0, 1.0);
+    let red = (value * 2.0).min(1.0);
+    let green = (value * 2.0 - 1.0).max(0.0);
+    let blue = 1.0 - red;
+    Color::Rgb((red * 230.0) as u8, (green * 220.0) as u8, (blue * 90.0 + 20.0) as u8)
+}
+
+/// Headings of the taxonomy panel's sections, each with notes printed below its tiles
+const LEGEND_SECTIONS: [(&str, &[&str]); 5] = [
+    ("GROUND & WATER:", &[]),
+    ("PLANTS (small/med/large):", &[
+        "  - Size affects: lifespan, growth rate, spread",
+        "  - Large: live longer, grow/reproduce slower",
+        "  - Small: live shorter, grow/reproduce faster",
+        "  - Large flowers spread seeds farther",
+    ]),
+    ("PILLBUGS (small/med/large):", &[
+        "  - Size affects: movement, eating, lifespan",
+        "  - Large: eat better, move slower, starve faster",
+        "  - Small: move faster, struggle with big plants",
+    ]),
+    ("BEETLES:", &[
+        "  - Chases the nearest pillbug in sight",
+        "  - Starves without kills, breeds when fed",
+    ]),
+    ("OTHER:", &[]),
+];
+
+/// Which of `LEGEND_SECTIONS` a tile is listed under
+fn legend_section(tile: TileType) -> usize {
+    match tile {
+        TileType::Empty | TileType::Ice(_) | TileType::Sediment(_) | TileType::Algae(_) => 0,
+        _ if tile.is_ground() || tile.is_water() => 0,
+        TileType::Seed(_, _) => 1,
+        _ if tile.is_plant() => 1,
+        _ if tile.is_pillbug() => 2,
+        _ if tile.is_beetle() => 3,
+        _ => 4,
+    }
+}
+
+/// The taxonomy panel, built from `TileType::all_variants` so every tile kind is listed with
+/// the characters and color the world view draws it in
+fn legend_lines(palette: RenderPalette, theme: &ColorTheme) -> Vec<Line<'static>> {
+    let entry = |tile: TileType| {
+        let (ch, _, description) = tile.legend_entry();
+        // Sized kinds show each size's character, small to large
+        let mut chars: Vec<char> = match tile.get_size() {
+            Some(_) => [Size::Small, Size::Medium, Size::Large].into_iter()
+                .filter_map(|size| TileType::from_parts(tile.tile_id(), tile.value(), Some(size)))
+                .map(TileType::to_char)
+                .collect(),
+            None => vec![ch],
+        };
+        chars.dedup();
+        let color = match palette {
+            RenderPalette::Default => tile.to_color_themed(theme),
+            palette => tile.to_color_with(palette),
+        };
+        Line::from(vec![
+            Span::styled(chars.into_iter().collect::<String>(), Style::default().fg(color)),
+            Span::raw(format!(" = {}", description)),
+        ])
     };
+    
+    let tiles = TileType::all_variants();
+    let mut lines = Vec::new();
+    for (section, (heading, notes)) in LEGEND_SECTIONS.iter().enumerate() {
+        lines.push(Line::from(*heading));
+        lines.extend(tiles.iter().copied().filter(|&tile| legend_section(tile) == section).map(entry));
+        lines.extend(notes.iter().map(|&note| Line::from(note)));
+        lines.push(Line::from(""));
+    }
+    lines.extend([
+        Line::from("Physics:"),
+        Line::from("- Gravity affects all"),
+        Line::from("- 8-way support check"),
+        Line::from("- Rain spawns at night"),
+        Line::from(""),
+        Line::from("Ecosystem:"),
+        Line::from("- Plants die → nutrients"),
+        Line::from("- Bugs eat plants"),
+        Line::from("- Closed nutrient loop"),
+    ]);
+    lines
+}
 
+/// Split the terminal into the side-panel columns and the world view/info rows of the first column
+fn layout(area: Rect, app: &App) -> (Rc<[Rect]>, Rc<[Rect]>) {
+    // World view first, then whichever side panels are enabled, in a fixed order
+    let mut constraints = vec![Constraint::Min(0)];
+    if app.show_taxonomy {
+        constraints.push(Constraint::Length(25));
+    }
+    if app.show_performance {
+        constraints.push(Constraint::Length(30));
+    }
+    if app.show_inspector {
+        constraints.push(Constraint::Length(32));
+    }
+    if app.show_events {
+        constraints.push(Constraint::Length(34));
+    }
+    let main_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .margin(1)
+        .constraints(constraints)
+        .split(area);
+    
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .margin(1)
         .constraints([Constraint::Min(0), Constraint::Length(3)].as_ref())
         .split(main_chunks[0]);
+    (main_chunks, chunks)
+}
 
+/// Terminal cells inside the world view's border, where tiles are drawn
+fn world_view(area: Rect, app: &App) -> Rect {
+    let (_, chunks) = layout(area, app);
+    Block::default().borders(Borders::ALL).inner(chunks[0])
+}
+
+pub fn ui(f: &mut Frame, app: &App) {
+    let (main_chunks, chunks) = layout(f.area(), app);
+    
+    // Only the window of the world under the camera is drawn
+    let view = world_view(f.area(), app);
+    let (left, top) = app.camera;
+    let right = (left + view.width as usize).min(app.world.width);
+    let bottom = (top + view.height as usize).min(app.world.height);
+    // Background heatmap, 0.0 to 1.0: pheromone out of its most, disturbance as it is, or nutrients
+    // relative to the richest spot
+    let heatmap: Option<Vec<Vec<f32>>> = if app.show_pheromones {
+        Some(app.world.pheromone.iter().map(|row| row.iter().map(|&scent| scent as f32 / 255.0).collect()).collect())
+    } else if app.show_disturbance {
+        Some(app.world.disturbance.clone())
+    } else if app.show_nutrients {
+        let field = app.world.nutrient_field();
+        let richest = field.iter().flatten().fold(0.0f32, |richest, value| richest.max(*value)).max(f32::EPSILON);
+        Some(field.iter().map(|row| row.iter().map(|value| value / richest).collect()).collect())
+    } else {
+        None
+    };
+    let ambient = app.world.ambient_tint();
     let mut lines = Vec::new();
-    for y in 0..app.world.height {
+    for y in top..bottom {
         let mut spans = Vec::new();
-        for x in 0..app.world.width {
-            let tile = app.world.tiles[y][x];
-            spans.push(Span::styled(
-                tile.to_char().to_string(),
-                Style::default().fg(tile.to_color()),
-            ));
+        for x in left..right {
+            // Species and seasonal tints only show in the default palette, leaving the others
+            // exact for telling tiles apart
+            let color = match app.palette {
+                RenderPalette::Default if app.seasonal_tint => blend(app.default_color(x, y), ambient, AMBIENT_TINT_STRENGTH),
+                RenderPalette::Default => app.default_color(x, y),
+                palette => app.world.tiles[y][x].to_color_with(palette),
+            };
+            let mut style = Style::default().fg(color);
+            if let Some(field) = &heatmap {
+                style = style.bg(heat_color(field[y][x]));
+            }
+            if app.show_inspector && (x, y) == app.cursor {
+                style = style.bg(Color::White);
+            }
+            spans.push(Span::styled(app.world.tile_char(x, y).to_string(), style));
         }
         lines.push(Line::from(spans));
     }
-
+    
+    let title = if app.show_pheromones {
+        "Pillbug Plants - Pheromone trails (blue faint, yellow strong)"
+    } else if app.show_disturbance {
+        "Pillbug Plants - Disturbance (blue settled, yellow freshly disturbed)"
+    } else if app.show_nutrients {
+        "Pillbug Plants - Nutrients (blue poor, yellow rich)"
+    } else {
+        "Pillbug Plants"
+    };
     let world_block = Paragraph::new(lines)
-        .block(Block::default().title("Pillbug Plants").borders(Borders::ALL));
+        .block(Block::default().title(title).borders(Borders::ALL));
     f.render_widget(world_block, chunks[0]);
-
+    
     let day_night = if app.world.is_day() { "Day" } else { "Night" };
     let rain_status = if app.world.rain_intensity > 0.1 {
         format!(" | Rain: {:.1}", app.world.rain_intensity)
     } else {
         String::new()
     };
-    let season_info = format!(" | {} | Temp: {:.1} | Humid: {:.1}", 
-        app.world.get_season_name(), app.world.temperature, app.world.humidity);
+    let gust_status = match app.world.gust {
+        Some((extra, ticks)) => format!(" | Gust: +{:.1} ({} ticks)", extra, ticks),
+        None => String::new(),
+    };
+    let season_info = format!(" | {} | Temp: {:.1} | Humid: {:.1} | Wind: {:.1} {}", 
+        app.world.get_season_name(), app.world.temperature, app.world.humidity, app.world.wind_strength, app.world.wind_compass());
+    let drought_stress = app.world.drought_stress();
+    let drought_status = if drought_stress > 0.0 {
+        format!(" | Drought: {:.0}%", drought_stress * 100.0)
+    } else {
+        String::new()
+    };
+    let paused = if app.paused { "PAUSED | " } else { "" };
+    let mode = match (&app.replay, &app.recording) {
+        (Some(replay), _) => format!("REPLAY {}/{} (',' steps back) | ", replay.position(), replay.len()),
+        (None, Some(_)) => "REC | ".to_string(),
+        (None, None) => String::new(),
+    };
+    let camera = if app.world.width > app.view.0 || app.world.height > app.view.1 {
+        format!(" | Camera: {},{}", app.camera.0, app.camera.1)
+    } else {
+        String::new()
+    };
+    let rate = format!(" | Rate: {:.1} TPS | Brush: {}", 1000.0 / app.tick_interval_ms as f32, app.brush.name());
+    let palette = match app.palette {
+        RenderPalette::Default => String::new(),
+        palette => format!(" | Colors: {}", palette.name()),
+    };
+    let disabled = app.world.systems.disabled_names();
+    let systems = if disabled.is_empty() {
+        String::new()
+    } else {
+        format!(" | Off: {}", disabled.join(", "))
+    };
+    let status = app.status.as_ref().map_or(String::new(), |status| format!(" | {}", status));
+    // While inspecting, the first line of the cursor tile's description leads the status
+    let status = if app.show_inspector {
+        format!(" | {}{}", app.world.describe_tile(app.cursor.0, app.cursor.1).lines().next().unwrap_or_default(), status)
+    } else {
+        status
+    };
     let info = Paragraph::new(format!(
-        "Tick: {} | {}{}{} | Press 'q' to quit | Press 't' for taxonomy",
-        app.world.tick, day_night, rain_status, season_info
+        "{}{}Seed: {} | Tick: {}{}{}{} | {}{}{}{}{}{}{} | Press 'q' to quit | Press 't' for taxonomy, 'i' to inspect, 'e' for events, 'n' for nutrients, 'o' for pheromone trails, 'd' for disturbance, 'c' for colors, 'a' for season tint, 'r' for a new world, 'f' to ignite, 's'/'l' to save/load, Space to pause, '.' to step, '+'/'-' for speed, 0-8 and click to paint, 'u' to undo, Shift+R/P/G/W/N/L to switch rain/physics/gravity/wind/nutrients/life off and on",
+        paused, mode, app.world.seed, app.world.tick, rate, palette, camera, day_night, rain_status, gust_status, season_info, drought_status, systems, status
     ))
     .block(Block::default().title("Info").borders(Borders::ALL));
     f.render_widget(info, chunks[1]);
-
+    
     // Render taxonomy panel if enabled
     if app.show_taxonomy {
-        let taxonomy_text = vec![
-            Line::from(vec![
-                Span::styled(" ", Style::default().fg(Color::Black)),
-                Span::raw(" = Empty space")
-            ]),
-            Line::from(vec![
-                Span::styled("#", Style::default().fg(Color::Rgb(101, 67, 33))),
-                Span::raw(" = Dirt (solid ground)")
-            ]),
-            Line::from(vec![
-                Span::styled(".", Style::default().fg(Color::Yellow)),
-                Span::raw(" = Sand (falls)")
-            ]),
-            Line::from(vec![
-                Span::styled("~", Style::default().fg(Color::Blue)),
-                Span::raw(" = Water (flows)")
-            ]),
-            Line::from("PLANTS (now with size variations!):"),
-            Line::from(vec![
-                Span::styled("i|║", Style::default().fg(Color::Rgb(80, 200, 60))),
-                Span::raw(" = Plant Stem (small/med/large)")
-            ]),
-            Line::from(vec![
-                Span::styled("lLŁ", Style::default().fg(Color::Green)),
-                Span::raw(" = Plant Leaf (small/med/large)")
-            ]),
-            Line::from(vec![
-                Span::styled("°oO", Style::default().fg(Color::Rgb(200, 100, 0))),
-                Span::raw(" = Plant Bud (small/med/large)")
-            ]),
-            Line::from(vec![
-                Span::styled("\\|╱", Style::default().fg(Color::Rgb(60, 180, 80))),
-                Span::raw(" = Plant Branch (small/med/large)")
-            ]),
-            Line::from(vec![
-                Span::styled("·*✱", Style::default().fg(Color::Rgb(255, 150, 200))),
-                Span::raw(" = Plant Flower (small/med/large)")
-            ]),
-            Line::from(vec![
-                Span::styled("·rR", Style::default().fg(Color::Rgb(80, 50, 30))),
-                Span::raw(" = Plant Root (small/med/large)")
-            ]),
-            Line::from(vec![
-                Span::styled("x", Style::default().fg(Color::Rgb(100, 50, 0))),
-                Span::raw(" = Plant Withered (gradual decay)")
-            ]),
-            Line::from("  - Size affects: lifespan, growth rate, spread"),
-            Line::from("  - Large: live longer, grow/reproduce slower"),
-            Line::from("  - Small: live shorter, grow/reproduce faster"),
-            Line::from("  - Large flowers spread seeds farther"),
-            Line::from("  - Buds mature into branches (60%) or flowers (40%)"),
-            Line::from("  - Branches create Y-shaped growth patterns"),
-            Line::from("  - Roots absorb nutrients and extend toward food"),
-            Line::from(""),
-            Line::from("PILLBUGS (multi-segment with sizes!):"),
-            Line::from(vec![
-                Span::styled("ó@●", Style::default().fg(Color::Rgb(140, 120, 110))),
-                Span::raw(" = Pillbug Head (small/med/large)")
-            ]),
-            Line::from(vec![
-                Span::styled("oO●", Style::default().fg(Color::Gray)),
-                Span::raw(" = Pillbug Body (small/med/large)")
-            ]),
-            Line::from(vec![
-                Span::styled("vwW", Style::default().fg(Color::Rgb(110, 120, 140))),
-                Span::raw(" = Pillbug Legs (small/med/large)")
-            ]),
-            Line::from(vec![
-                Span::styled("░", Style::default().fg(Color::Rgb(80, 26, 40))),
-                Span::raw(" = Pillbug Decaying (gradual decay)")
-            ]),
-            Line::from("  - Size affects: movement, eating, lifespan"),
-            Line::from("  - Large: eat better, move slower, starve faster"),
-            Line::from("  - Small: move faster, struggle with big plants"),
-            Line::from("  - Size inheritance with some variation"),
-            Line::from(vec![
-                Span::styled("+", Style::default().fg(Color::Magenta)),
-                Span::raw(" = Nutrient (diffuses)")
-            ]),
-            Line::from("  - From decomposition"),
-            Line::from("  - Consumed by plants"),
-            Line::from(""),
-            Line::from("Physics:"),
-            Line::from("- Gravity affects all"),
-            Line::from("- 8-way support check"),
-            Line::from("- Rain spawns at night"),
-            Line::from(""),
-            Line::from("Ecosystem:"),
-            Line::from("- Plants die → nutrients"),
-            Line::from("- Bugs eat plants"),
-            Line::from("- Closed nutrient loop"),
-        ];
-
-        let taxonomy_panel = Paragraph::new(taxonomy_text)
+        let taxonomy_panel = Paragraph::new(legend_lines(app.palette, &app.theme))
             .block(Block::default().title("Taxonomy").borders(Borders::ALL))
             .wrap(ratatui::widgets::Wrap { trim: true });
         f.render_widget(taxonomy_panel, main_chunks[1]);
@@ -225,7 +599,7 @@ pub fn ui(f: &mut Frame, app: &App) {
     
     // Performance panel (toggleable with 'p')
     if app.show_performance {
-        let panel_index = if app.show_taxonomy { 2 } else { 1 };
+        let panel_index = 1 + app.show_taxonomy as usize;
         
         let perf = &app.world.performance;
         let performance_text = vec![
@@ -241,6 +615,7 @@ pub fn ui(f: &mut Frame, app: &App) {
             Line::from(format!("Wind: {:.1}ms", perf.wind_time.as_secs_f64() * 1000.0)),
             Line::from(format!("Plant Support: {:.1}ms", perf.plant_support_time.as_secs_f64() * 1000.0)),
             Line::from(format!("Nutrient Diffusion: {:.1}ms", perf.nutrient_diffusion_time.as_secs_f64() * 1000.0)),
+            Line::from(format!("Fire: {:.1}ms", perf.fire_time.as_secs_f64() * 1000.0)),
             Line::from(format!("Life Update: {:.1}ms", perf.life_update_time.as_secs_f64() * 1000.0)),
             Line::from(format!("Spawn Entities: {:.1}ms", perf.spawn_entities_time.as_secs_f64() * 1000.0)),
             Line::from(""),
@@ -257,4 +632,56 @@ pub fn ui(f: &mut Frame, app: &App) {
             .wrap(ratatui::widgets::Wrap { trim: true });
         f.render_widget(performance_panel, main_chunks[panel_index]);
     }
-}
\ No newline at end of file
+    
+    // Biome inspector panel (toggleable with 'i')
+    if app.show_inspector {
+        let panel_index = 1 + app.show_taxonomy as usize + app.show_performance as usize;
+        
+        let (x, y) = app.cursor;
+        let biome = app.world.get_biome_at(x, y);
+        let (dirt_ratio, sand_ratio) = biome.get_terrain_preferences();
+        let genome = app.world.genome_at(x, y);
+        let mut inspector_text: Vec<Line> = app.world.describe_tile(x, y).lines().map(|line| Line::from(line.to_string())).collect();
+        inspector_text.extend([
+            Line::from(""),
+            Line::from(format!("Biome: {}", biome.name())),
+            Line::from(format!("Moisture retention: {:.2}", biome.moisture_retention())),
+            Line::from(format!("Plant growth: {:.2}", biome.plant_growth_modifier())),
+            Line::from(format!("Nutrients: {:.2}", biome.nutrient_modifier())),
+            Line::from(format!("Terrain: {:.0}% dirt / {:.0}% sand", dirt_ratio * 100.0, sand_ratio * 100.0)),
+            Line::from(format!("Rain accumulation: {:.2}", biome.rain_accumulation_bonus())),
+            Line::from(""),
+            Line::from(format!("Season/weather growth: {:.2}", app.world.get_seasonal_growth_modifier(&genome, biome))),
+            Line::from(format!("Effective growth here: {:.2}", app.world.local_growth_rate(x, y))),
+            Line::from(match app.world.mean_genome(biome) {
+                Some(mean) => format!("Biome plants: speed {:.2} / drought {:.2} / disease {:.2}",
+                                      mean.growth_speed, mean.drought_tolerance, mean.disease_resistance),
+                None => "Biome plants: none".to_string(),
+            }),
+            Line::from(""),
+            Line::from("- Arrow keys move the cursor"),
+            Line::from("- Press 'i' to toggle this panel"),
+        ]);
+        
+        let inspector_panel = Paragraph::new(inspector_text)
+            .block(Block::default().title("Inspect").borders(Borders::ALL))
+            .wrap(ratatui::widgets::Wrap { trim: true });
+        f.render_widget(inspector_panel, main_chunks[panel_index]);
+    }
+    
+    // Event feed panel (toggleable with 'e'), newest first until the panel is full
+    if app.show_events {
+        let panel_index = 1 + app.show_taxonomy as usize + app.show_performance as usize + app.show_inspector as usize;
+        
+        let events_text: Vec<Line> = if app.world.events.is_empty() {
+            vec![Line::from("Nothing notable yet")]
+        } else {
+            app.world.events.iter().rev().map(|event| Line::from(event.to_string())).collect()
+        };
+        
+        let events_panel = Paragraph::new(events_text)
+            .block(Block::default().title("Events").borders(Borders::ALL))
+            .wrap(ratatui::widgets::Wrap { trim: true });
+        f.render_widget(events_panel, main_chunks[panel_index]);
+    }
+}
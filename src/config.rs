@@ -0,0 +1,218 @@
+use crate::types::{EdgeBehavior, SystemFlags, WindMode};
+
+/// Tunable rates for the physical simulation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PhysicsConfig {
+    pub evaporation_rate: f32, // Multiplier on water evaporation chance (0.0 disables evaporation)
+    pub absorption_rate: f32,  // Multiplier on water soaking into soil (0.0 disables absorption)
+    // Chance per tick a loose particle over empty space drops a cell; near 1.0 everything settles
+    // fast, low values leave it hanging in the air
+    pub seed_fall_chance: f32,
+    pub spore_fall_chance: f32,
+    pub nutrient_fall_chance: f32,
+}
+
+impl Default for PhysicsConfig {
+    fn default() -> Self {
+        PhysicsConfig {
+            evaporation_rate: 1.0,
+            absorption_rate: 1.0,
+            seed_fall_chance: 0.6,
+            spore_fall_chance: 0.3,
+            nutrient_fall_chance: 0.2,
+        }
+    }
+}
+
+/// When a dry spell turns into drought stress
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DroughtConfig {
+    pub rain_threshold: f32,   // Rain intensity below this doesn't count as rain
+    pub stress_after: u32,     // Dry ticks before drought stress starts
+    pub full_stress_after: u32, // Further dry ticks until stress reaches its maximum
+}
+
+impl Default for DroughtConfig {
+    fn default() -> Self {
+        DroughtConfig {
+            rain_threshold: 0.1,
+            stress_after: 400,
+            full_stress_after: 600,
+        }
+    }
+}
+
+/// Limits on how densely plants can pack an area
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GrowthConfig {
+    pub density_cap: f32,      // Above-ground plant fraction of the neighbourhood that stops new growth (1.0 disables)
+    pub density_radius: usize, // Neighbourhood is the square of this radius around the growth site
+}
+
+impl Default for GrowthConfig {
+    fn default() -> Self {
+        GrowthConfig {
+            density_cap: 0.6,
+            density_radius: 3,
+        }
+    }
+}
+
+/// How plant species help and hinder their neighbours, each table indexed by
+/// `PlantSpecies as usize` (grass, shrub, tree, cactus)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InteractionConfig {
+    // Germination multiplier for a seed of species `[seedling]` with a living plant of species
+    // `[neighbour]` within `radius`: below 1.0 the neighbour suppresses it (allelopathy), above
+    // 1.0 it nurses it. Each species nearby counts once, however much of it there is
+    pub germination: [[f32; 4]; 4],
+    pub nitrogen_fixing: [f32; 4], // Chance per tick each species' roots enrich a neighbouring cell of dirt
+    pub radius: usize,             // Plants within this many cells of a seed count as its neighbours
+}
+
+impl Default for InteractionConfig {
+    fn default() -> Self {
+        InteractionConfig {
+            // Shrubs nurse cactus seedlings; trees hold back grass and shrubs, and cacti grass
+            germination: [
+                [1.0, 1.0, 1.0, 1.0], // Grass
+                [1.0, 1.0, 1.0, 1.5], // Shrub
+                [0.4, 0.7, 1.0, 1.0], // Tree
+                [0.7, 1.0, 1.0, 1.0], // Cactus
+            ],
+            // Wetland shrubs fix nitrogen, feeding whatever grows around them
+            nitrogen_fixing: [0.0, 0.02, 0.0, 0.0],
+            radius: 3,
+        }
+    }
+}
+
+/// Daily rhythm of pillbug activity
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ActivityConfig {
+    pub nocturnal: bool,          // Pillbugs are active at night and rest by day (false inverts the rhythm)
+    pub active_move_chance: f32,  // Chance per tick a pillbug tries to move while active
+    pub resting_move_chance: f32, // Chance per tick a pillbug tries to move while resting
+}
+
+impl Default for ActivityConfig {
+    fn default() -> Self {
+        ActivityConfig {
+            nocturnal: true,
+            active_move_chance: 0.45,
+            resting_move_chance: 0.15,
+        }
+    }
+}
+
+/// How plant disease breaks out, spreads, and runs its course, from harmless to apocalyptic.
+/// Plants' `disease_resistance` scales every infection down and every recovery up
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DiseaseConfig {
+    pub outbreak_chance: f32,        // Chance per tick a random plant part falls ill, before season and humidity
+    pub spread_chance: f32,          // Chance per tick a sick part infects each neighbouring part, doubling as it progresses
+    pub spore_chance: f32,           // Chance per tick a sick part sheds a spore, before the wind adds to it
+    pub spore_infection_chance: f32, // Chance per tick a ripe spore tries to infect an old plant part beside it
+    pub foul_water_chance: f32,      // Chance per tick a root beside the foulest water sickens
+    pub lethal_age: u8,              // Ticks a sick part lasts before it withers
+    pub recovery_chance: f32,        // Chance per tick a sick part surrounded by healthy tissue, out of drought, recovers
+}
+
+impl Default for DiseaseConfig {
+    fn default() -> Self {
+        DiseaseConfig {
+            outbreak_chance: 0.0005,
+            spread_chance: 0.02,
+            spore_chance: 0.05,
+            spore_infection_chance: 0.02,
+            foul_water_chance: 0.05,
+            lethal_age: 60,
+            recovery_chance: 0.005,
+        }
+    }
+}
+
+/// When pillbugs breed and how alike their young are, from r-selected (breeding young and often)
+/// to K-selected (breeding late and rarely)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PillbugConfig {
+    pub maturity_age: u8,      // Age a pillbug has to be past before it can breed
+    pub max_breeding_age: u8,  // Age it stops breeding at
+    pub breed_chance: f32,     // Chance per tick a well-fed adult breeds, before its size's growth rate
+    pub litter_variation: f32, // Chance a baby is a random size instead of its parent's
+}
+
+impl Default for PillbugConfig {
+    fn default() -> Self {
+        PillbugConfig {
+            maturity_age: 30,
+            max_breeding_age: 100,
+            breed_chance: 0.05,
+            litter_variation: 0.2,
+        }
+    }
+}
+
+/// When the emergency spawner tops up a world whose plants or pillbugs are dying out
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RespawnConfig {
+    pub min_plants: usize,     // Fewer plant stems than this brings new plants in
+    pub plant_target: usize,   // Stems the spawner tries to bring the count back up to
+    pub min_pillbugs: usize,   // Fewer pillbugs than this brings new pillbugs in
+    pub pillbug_target: usize, // Pillbugs the spawner tries to bring the count back up to
+}
+
+impl Default for RespawnConfig {
+    fn default() -> Self {
+        RespawnConfig {
+            min_plants: 2,
+            plant_target: 3,
+            min_pillbugs: 1,
+            pillbug_target: 2,
+        }
+    }
+}
+
+/// Starting conditions for a generated world
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WorldConfig {
+    pub initial_plants: usize,    // Attempts to place a starting plant; the biome and `plant_density` decide if each takes
+    pub initial_pillbugs: usize,  // Attempts to place a starting pillbug on open ground
+    pub initial_nutrients: usize, // Attempts to scatter a nutrient, more likely to take in rich biomes
+    pub soil_depth: usize,        // Rows of terrain at the bottom of the world (clamped to leave room above)
+    pub plant_density: f32,       // Base chance a plant attempt takes, before the biome's growth modifier
+}
+
+impl Default for WorldConfig {
+    fn default() -> Self {
+        WorldConfig {
+            initial_plants: 8,
+            initial_pillbugs: 2,
+            initial_nutrients: 10,
+            soil_depth: 10,
+            plant_density: 0.6,
+        }
+    }
+}
+
+/// How a world runs, apart from its state: the rules and tuning `World::settings` reads off one
+/// world and `World::apply_settings` hands to another, e.g. when the app moves on to the next seed
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WorldSettings {
+    pub wind_mode: WindMode,
+    pub ticks_per_year: u64,
+    pub ticks_per_day: u64,
+    pub edge_behavior: EdgeBehavior,
+    pub wrap_horizontal: bool,
+    pub physics: PhysicsConfig,
+    pub drought: DroughtConfig,
+    pub growth: GrowthConfig,
+    pub interaction: InteractionConfig,
+    pub activity: ActivityConfig,
+    pub disease: DiseaseConfig,
+    pub pillbug: PillbugConfig,
+    pub allow_respawn: bool,
+    pub respawn: RespawnConfig,
+    pub systems: SystemFlags,
+    pub world_config: WorldConfig,
+}
@@ -0,0 +1,376 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::io;
+use image::{ExtendedColorType, ImageEncoder, codecs::png::PngEncoder};
+use ratatui::style::Color;
+use crate::types::{TileType, Size, Biome};
+use crate::world::{World, EcosystemStats, PerformanceMetrics};
+
+pub(crate) const BIOMES: [Biome; 4] = [Biome::Wetland, Biome::Grassland, Biome::Drylands, Biome::Woodland];
+pub(crate) const SIZES: [Size; 3] = [Size::Small, Size::Medium, Size::Large];
+
+/// Plain RGB pixel buffer, row-major, 3 bytes per pixel
+#[derive(Debug, Clone, PartialEq)]
+pub struct RgbImage {
+    pub width: usize,
+    pub height: usize,
+    pub data: Vec<u8>,
+}
+
+impl RgbImage {
+    pub fn new(width: usize, height: usize) -> Self {
+        RgbImage { width, height, data: vec![0; width * height * 3] }
+    }
+    
+    pub fn pixel(&self, x: usize, y: usize) -> [u8; 3] {
+        let i = (y * self.width + x) * 3;
+        [self.data[i], self.data[i + 1], self.data[i + 2]]
+    }
+    
+    pub fn set_pixel(&mut self, x: usize, y: usize, rgb: [u8; 3]) {
+        let i = (y * self.width + x) * 3;
+        self.data[i..i + 3].copy_from_slice(&rgb);
+    }
+    
+    /// Copy another image into this one with its top-left corner at (x, y)
+    pub fn blit(&mut self, other: &RgbImage, x: usize, y: usize) {
+        for oy in 0..other.height.min(self.height.saturating_sub(y)) {
+            for ox in 0..other.width.min(self.width.saturating_sub(x)) {
+                self.set_pixel(x + ox, y + oy, other.pixel(ox, oy));
+            }
+        }
+    }
+    
+    /// Encode as a binary PPM (P6)
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut ppm = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        ppm.extend_from_slice(&self.data);
+        ppm
+    }
+    
+    /// Encode as an 8-bit RGB PNG
+    pub fn to_png(&self) -> io::Result<Vec<u8>> {
+        let mut png = Vec::new();
+        PngEncoder::new(&mut png)
+            .write_image(&self.data, self.width as u32, self.height as u32, ExtendedColorType::Rgb8)
+            .map_err(io::Error::other)?;
+        Ok(png)
+    }
+}
+
+/// What a client needs to draw a world: its tiles and the weather and time of day, copied out
+/// by `World::clone_snapshot`. It owns its data, so a simulation running on one thread can
+/// send a snapshot down a channel for another, e.g. a web handler, to render.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorldSnapshot {
+    pub width: usize,
+    pub height: usize,
+    pub tick: u64,
+    pub tiles: Vec<Vec<TileType>>,
+    pub day_cycle: f32,
+    pub season_cycle: f32,
+    pub temperature: f32,
+    pub humidity: f32,
+    pub rain_intensity: f32,
+    pub wind_direction: f32,
+    pub wind_strength: f32, // Including any gust in progress
+    pub ticks_since_rain: u32,
+}
+
+/// How thumbnails are arranged in a time-lapse sheet
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ThumbnailLayout {
+    Strip, // All frames side by side in one row
+    Grid,  // Roughly square grid, filled row by row
+}
+
+/// Composite frames into one image, separated by a thin black gutter
+pub fn thumbnail_sheet(frames: &[RgbImage], layout: ThumbnailLayout) -> RgbImage {
+    let gutter = 2;
+    let frame_width = frames.iter().map(|f| f.width).max().unwrap_or(0);
+    let frame_height = frames.iter().map(|f| f.height).max().unwrap_or(0);
+    let columns = match layout {
+        ThumbnailLayout::Strip => frames.len().max(1),
+        ThumbnailLayout::Grid => (frames.len() as f64).sqrt().ceil().max(1.0) as usize,
+    };
+    let rows = frames.len().div_ceil(columns).max(1);
+    
+    let mut sheet = RgbImage::new(
+        columns * frame_width + (columns - 1) * gutter,
+        rows * frame_height + (rows - 1) * gutter,
+    );
+    for (i, frame) in frames.iter().enumerate() {
+        let (column, row) = (i % columns, i / columns);
+        sheet.blit(frame, column * (frame_width + gutter), row * (frame_height + gutter));
+    }
+    sheet
+}
+
+/// CSV header matching `World::log_csv_row`: tick, season, weather, every `EcosystemStats` total,
+/// one column per tile kind, then one per biome or size in each of the stats' breakdowns, named
+/// like `plants_by_biome_wetland`
+pub fn log_csv_header() -> String {
+    let mut names = TileType::NAMES.to_vec();
+    names.sort(); // Same order as the census map
+    let mut header = format!("tick,season,temperature,humidity,rain_intensity,\
+                              total_plants,total_pillbugs,total_beetles,water_coverage,nutrient_count,\
+                              plant_health_ratio,biome_diversity,ticks_since_rain,drought_stress,{}", names.join(","));
+    for breakdown in ["plants_by_biome", "pillbugs_by_biome"] {
+        for biome in BIOMES {
+            let _ = write!(header, ",{}_{}", breakdown, biome.name());
+        }
+    }
+    for breakdown in ["plants_by_size", "pillbugs_by_size", "beetles_by_size"] {
+        for size in SIZES {
+            let _ = write!(header, ",{}_{}", breakdown, size.name());
+        }
+    }
+    header
+}
+
+/// RGB for a terminal color, approximating the named colors
+fn color_to_rgb(color: Color) -> [u8; 3] {
+    match color {
+        Color::Rgb(r, g, b) => [r, g, b],
+        Color::Black => [0, 0, 0],
+        Color::Red => [205, 49, 49],
+        Color::Green => [13, 188, 121],
+        Color::Yellow => [229, 229, 16],
+        Color::Blue => [36, 114, 200],
+        Color::Magenta => [188, 63, 188],
+        Color::Cyan => [17, 168, 205],
+        Color::Gray => [170, 170, 170],
+        Color::DarkGray => [102, 102, 102],
+        Color::White => [255, 255, 255],
+        _ => [128, 128, 128],
+    }
+}
+
+impl World {
+    /// Copy out the tiles and the scalars a renderer reads, leaving everything else behind
+    pub fn clone_snapshot(&self) -> WorldSnapshot {
+        WorldSnapshot {
+            width: self.width,
+            height: self.height,
+            tick: self.tick,
+            tiles: self.tiles.clone(),
+            day_cycle: self.day_cycle,
+            season_cycle: self.season_cycle,
+            temperature: self.temperature,
+            humidity: self.humidity,
+            rain_intensity: self.rain_intensity,
+            wind_direction: self.wind_direction,
+            wind_strength: self.effective_wind_strength(),
+            ticks_since_rain: self.ticks_since_rain,
+        }
+    }
+    
+    /// Render the world as an image, each tile a `scale` x `scale` block of its color
+    pub fn to_image(&self, scale: usize) -> RgbImage {
+        let scale = scale.max(1);
+        let mut image = RgbImage::new(self.width * scale, self.height * scale);
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let rgb = color_to_rgb(self.tile_color(x, y));
+                for py in 0..scale {
+                    for px in 0..scale {
+                        image.set_pixel(x * scale + px, y * scale + py, rgb);
+                    }
+                }
+            }
+        }
+        image
+    }
+    
+    /// One CSV row of weather, ecosystem statistics, and the tile census, see `log_csv_header`
+    pub fn log_csv_row(&self) -> String {
+        let stats = self.calculate_ecosystem_stats();
+        let mut row = format!("{},{},{:.3},{:.3},{:.3},{},{},{},{},{},{:.3},{},{},{:.3}",
+                              self.tick, self.get_season_name(), self.temperature, self.humidity, self.rain_intensity,
+                              stats.total_plants, stats.total_pillbugs, stats.total_beetles, stats.water_coverage,
+                              stats.nutrient_count, stats.plant_health_ratio, stats.biome_diversity,
+                              stats.ticks_since_rain, stats.drought_stress);
+        for count in self.tile_census().values() {
+            let _ = write!(row, ",{}", count);
+        }
+        // The breakdowns list every biome and size, in the header's order
+        for breakdown in [&stats.plants_by_biome, &stats.pillbugs_by_biome] {
+            for count in breakdown.values() {
+                let _ = write!(row, ",{}", count);
+            }
+        }
+        for breakdown in [&stats.plants_by_size, &stats.pillbugs_by_size, &stats.beetles_by_size] {
+            for count in breakdown.values() {
+                let _ = write!(row, ",{}", count);
+            }
+        }
+        row
+    }
+    
+    /// Render the world as a binary PPM image (see `to_image`)
+    pub fn to_ppm(&self, scale: usize) -> Vec<u8> {
+        self.to_image(scale).to_ppm()
+    }
+    
+    /// Write the world to `path` as a PNG, each tile a `scale` x `scale` block of its color (see `to_image`)
+    pub fn render_to_png(&self, path: &str, scale: u32) -> io::Result<()> {
+        std::fs::write(path, self.to_image(scale as usize).to_png()?)
+    }
+    
+    /// Export the world as a semantic 2D tilemap in JSON.
+    ///
+    /// Schema (`"format": "pillbugplants-tilemap"`, `"version": 1`):
+    /// - `width`, `height`, `tick`: world dimensions and the tick of the snapshot
+    /// - `legend`: `{ "tiles": [...], "sizes": [...], "biomes": [...] }`, each a list of
+    ///   `{ "id": n, "name": "..." }` mapping layer values to names
+    /// - `layers`: list of `{ "name", "width", "height", "data" }` where `data` is row-major
+    ///   (`data[y * width + x]`), top row first:
+    ///   - `tiles`: tile id from `TileType::tile_id`
+    ///   - `values`: the tile's age, water depth, or nutrient level (0 when it has none)
+    ///   - `sizes`: 0 for unsized tiles, otherwise size id + 1
+    ///   - `biomes`: biome id
+    pub fn to_tilemap_json(&self) -> String {
+        let mut json = String::new();
+        
+        json.push_str("{\n");
+        json.push_str("  \"format\": \"pillbugplants-tilemap\",\n");
+        json.push_str("  \"version\": 1,\n");
+        let _ = writeln!(json, "  \"width\": {},", self.width);
+        let _ = writeln!(json, "  \"height\": {},", self.height);
+        let _ = writeln!(json, "  \"tick\": {},", self.tick);
+        
+        // Legend
+        json.push_str("  \"legend\": {\n");
+        let tile_names: Vec<(usize, &str)> = TileType::NAMES.iter().copied().enumerate().collect();
+        let size_names: Vec<(usize, &str)> = SIZES.iter().map(|s| (*s as usize + 1, s.name())).collect();
+        let biome_names: Vec<(usize, &str)> = BIOMES.iter().map(|b| (*b as usize, b.name())).collect();
+        write_legend(&mut json, "tiles", &tile_names, true);
+        write_legend(&mut json, "sizes", &size_names, true);
+        write_legend(&mut json, "biomes", &biome_names, false);
+        json.push_str("  },\n");
+        
+        // Layers
+        json.push_str("  \"layers\": [\n");
+        self.write_layer(&mut json, "tiles", |world, x, y| world.tiles[y][x].tile_id(), true);
+        self.write_layer(&mut json, "values", |world, x, y| world.tiles[y][x].value(), true);
+        self.write_layer(&mut json, "sizes", |world, x, y| tile_size(world.tiles[y][x]).map_or(0, |s| s as u8 + 1), true);
+        self.write_layer(&mut json, "biomes", |world, x, y| world.biome_map[y][x] as u8, false);
+        json.push_str("  ]\n");
+        json.push_str("}\n");
+        
+        json
+    }
+    
+    /// Compact binary snapshot for streaming frames.
+    ///
+    /// Layout (big-endian): magic `b"PBPF"`, version byte `1`, `width: u32`, `height: u32`,
+    /// `tick: u64`, then `width * height` cells row-major, each two bytes:
+    /// tile id (`TileType::tile_id`) and value (`TileType::value`).
+    pub fn to_frame_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(21 + self.width * self.height * 2);
+        bytes.extend_from_slice(b"PBPF");
+        bytes.push(1);
+        bytes.extend_from_slice(&(self.width as u32).to_be_bytes());
+        bytes.extend_from_slice(&(self.height as u32).to_be_bytes());
+        bytes.extend_from_slice(&self.tick.to_be_bytes());
+        for tile in self.tiles.iter().flatten() {
+            bytes.push(tile.tile_id());
+            bytes.push(tile.value());
+        }
+        bytes
+    }
+    
+    fn write_layer(&self, json: &mut String, name: &str, value_at: impl Fn(&World, usize, usize) -> u8, trailing_comma: bool) {
+        let _ = write!(json, "    {{ \"name\": \"{}\", \"width\": {}, \"height\": {}, \"data\": [", name, self.width, self.height);
+        for y in 0..self.height {
+            for x in 0..self.width {
+                if x > 0 || y > 0 {
+                    json.push(',');
+                }
+                let _ = write!(json, "{}", value_at(self, x, y));
+            }
+        }
+        json.push_str("] }");
+        json.push_str(if trailing_comma { ",\n" } else { "\n" });
+    }
+}
+
+impl EcosystemStats {
+    /// Export the statistics as a flat JSON object: the tick, every total and ratio, then the
+    /// `plants_by_biome`, `pillbugs_by_biome`, `plants_by_size`, `pillbugs_by_size`, and
+    /// `beetles_by_size` breakdowns as objects keyed by biome or size name, in declaration order
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        
+        json.push_str("{\n");
+        let _ = writeln!(json, "  \"tick\": {},", self.tick);
+        let _ = writeln!(json, "  \"total_plants\": {},", self.total_plants);
+        let _ = writeln!(json, "  \"total_pillbugs\": {},", self.total_pillbugs);
+        let _ = writeln!(json, "  \"total_beetles\": {},", self.total_beetles);
+        let _ = writeln!(json, "  \"water_coverage\": {},", self.water_coverage);
+        let _ = writeln!(json, "  \"nutrient_count\": {},", self.nutrient_count);
+        let _ = writeln!(json, "  \"plant_health_ratio\": {},", self.plant_health_ratio);
+        let _ = writeln!(json, "  \"biome_diversity\": {},", self.biome_diversity);
+        let _ = writeln!(json, "  \"ticks_since_rain\": {},", self.ticks_since_rain);
+        let _ = writeln!(json, "  \"drought_stress\": {},", self.drought_stress);
+        write_counts(&mut json, "plants_by_biome", &self.plants_by_biome, Biome::name, true);
+        write_counts(&mut json, "pillbugs_by_biome", &self.pillbugs_by_biome, Biome::name, true);
+        write_counts(&mut json, "plants_by_size", &self.plants_by_size, Size::name, true);
+        write_counts(&mut json, "pillbugs_by_size", &self.pillbugs_by_size, Size::name, true);
+        write_counts(&mut json, "beetles_by_size", &self.beetles_by_size, Size::name, false);
+        json.push_str("}\n");
+        
+        json
+    }
+}
+
+impl PerformanceMetrics {
+    /// Export the timings as a flat JSON object in milliseconds: the whole update, each
+    /// subsystem as `<name>_ms`, then ticks per second and the `bottleneck` subsystem
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        
+        json.push_str("{\n");
+        let _ = writeln!(json, "  \"total_update_ms\": {},", self.total_update_time.as_secs_f64() * 1000.0);
+        for (name, time) in self.system_times() {
+            let _ = writeln!(json, "  \"{}_ms\": {},", name, time.as_secs_f64() * 1000.0);
+        }
+        let _ = writeln!(json, "  \"ticks_per_second\": {},", self.ticks_per_second);
+        let _ = writeln!(json, "  \"bottleneck\": \"{}\"", self.bottleneck());
+        json.push_str("}\n");
+        
+        json
+    }
+}
+
+fn write_counts<K: Copy>(json: &mut String, name: &str, counts: &BTreeMap<K, usize>, key_name: fn(K) -> &'static str, trailing_comma: bool) {
+    let _ = write!(json, "  \"{}\": {{", name);
+    for (i, (key, count)) in counts.iter().enumerate() {
+        if i > 0 {
+            json.push_str(", ");
+        }
+        let _ = write!(json, "\"{}\": {}", key_name(*key), count);
+    }
+    json.push('}');
+    json.push_str(if trailing_comma { ",\n" } else { "\n" });
+}
+
+// Seeds carry a size too, even though they aren't plant or pillbug parts
+pub(crate) fn tile_size(tile: TileType) -> Option<Size> {
+    match tile {
+        TileType::Seed(_, size) => Some(size),
+        _ => tile.get_size(),
+    }
+}
+
+fn write_legend(json: &mut String, name: &str, entries: &[(usize, &str)], trailing_comma: bool) {
+    let _ = write!(json, "    \"{}\": [", name);
+    for (i, (id, entry_name)) in entries.iter().enumerate() {
+        if i > 0 {
+            json.push_str(", ");
+        }
+        let _ = write!(json, "{{ \"id\": {}, \"name\": \"{}\" }}", id, entry_name);
+    }
+    json.push(']');
+    json.push_str(if trailing_comma { ",\n" } else { "\n" });
+}
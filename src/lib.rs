@@ -0,0 +1,10 @@
+pub mod types;
+pub mod config;
+pub mod world;
+pub mod builder;
+pub mod weather;
+pub mod app;
+pub mod export;
+pub mod server;
+pub mod replay;
+mod save;
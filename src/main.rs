@@ -1,15 +1,9 @@
-mod types;
-mod world;
-mod life;
-mod physics;
-mod environment;
-mod app;
-
 use std::env;
-use std::fs::File;
+use std::fs::{self, File};
+use std::path::Path;
 use std::io::{self, Write};
 use crossterm::{
-    event::DisableMouseCapture,
+    event::{DisableMouseCapture, EnableMouseCapture},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -18,15 +12,83 @@ use ratatui::{
     Terminal,
 };
 
-use crate::world::World;
-use crate::app::{App, run_app};
+use pillbugplants::world::{World, EcosystemPhase, PerformanceMetrics, MIN_WORLD_WIDTH, MIN_WORLD_HEIGHT, parse_biome_map};
+use pillbugplants::builder::WorldBuilder;
+use pillbugplants::types::Biome;
+use pillbugplants::config::WorldConfig;
+use pillbugplants::app::{App, run_app};
+use pillbugplants::export::{ThumbnailLayout, thumbnail_sheet, log_csv_header};
+use pillbugplants::server::{FrameServer, FrameFormat, FramePacing, ListenEvent};
+use pillbugplants::replay::Replay;
+
+// Options for a headless simulation run
+struct SimOptions {
+    ticks: u64,
+    seed: Option<u64>,
+    world_size: (usize, usize),
+    world_config: WorldConfig,
+    year_length: Option<u64>,
+    biome_map: Option<Vec<Vec<Biome>>>,
+    allow_respawn: bool,
+    output_file: Option<String>,
+    tilemap_file: Option<String>,
+    stats_file: Option<String>,
+    perf_file: Option<String>,
+    png_file: Option<String>,
+    log_file: Option<String>,
+    log_interval: u64,
+    record_file: Option<String>,
+    frames_dir: Option<String>,
+    frame_every: u64,
+    thumbnails: usize,
+    thumb_output: Option<String>,
+    thumb_scale: usize,
+    thumb_layout: ThumbnailLayout,
+    stop_on_extinction: bool,
+    log_events: bool,
+}
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args: Vec<String> = env::args().collect();
     
+    // `replay FILE` plays a recording back in the terminal UI
+    if args.get(1).map(String::as_str) == Some("replay") {
+        let path = args.get(2).ok_or("replay needs a recording file")?;
+        let (world, replay) = Replay::load(path)?;
+        return run_interactive(|_, _| App::with_replay(world, replay));
+    }
+    
     // Parse command line arguments
     let mut sim_ticks: Option<u64> = None;
+    let mut seed: Option<u64> = None;
+    let mut world_width: Option<usize> = None;
+    let mut world_height: Option<usize> = None;
+    let mut world_size_arg: Option<(usize, usize)> = None; // --world-size, under --width and --height
+    let mut world_config = WorldConfig::default();
+    let mut year_length: Option<u64> = None;
+    let mut biome_map: Option<Vec<Vec<Biome>>> = None;
+    let mut allow_respawn = true;
     let mut output_file: Option<String> = None;
+    let mut tilemap_file: Option<String> = None;
+    let mut stats_file: Option<String> = None;
+    let mut perf_file: Option<String> = None;
+    let mut png_file: Option<String> = None;
+    let mut log_file: Option<String> = None;
+    let mut log_interval: u64 = 1;
+    let mut record_file: Option<String> = None;
+    let mut frames_dir: Option<String> = None;
+    let mut frame_every: u64 = 1;
+    let mut thumbnails: usize = 0;
+    let mut thumb_output: Option<String> = None;
+    let mut thumb_scale: usize = 2;
+    let mut thumb_layout = ThumbnailLayout::Strip;
+    let mut stop_on_extinction = false;
+    let mut log_events = false;
+    let mut serve_addr: Option<String> = None;
+    let mut serve_stdio = false;
+    let mut frame_format = FrameFormat::Json;
+    let mut frame_interval: Option<u64> = None;
+    let mut palette_file: Option<String> = None;
     
     let mut i = 1;
     while i < args.len() {
@@ -35,16 +97,196 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 let ticks_str = arg.strip_prefix("--sim-ticks=").unwrap();
                 sim_ticks = Some(ticks_str.parse().map_err(|_| "Invalid --sim-ticks value")?);
             }
+            arg if arg.starts_with("--seed=") => {
+                let seed_str = arg.strip_prefix("--seed=").unwrap();
+                seed = Some(seed_str.parse().map_err(|_| "Invalid --seed value")?);
+            }
+            arg if arg.starts_with("--world-size=") => {
+                let size_str = arg.strip_prefix("--world-size=").unwrap();
+                let (width, height) = size_str.split_once('x').ok_or("Invalid --world-size value (use WxH)")?;
+                world_size_arg = Some((
+                    width.parse().map_err(|_| "Invalid --world-size width")?,
+                    height.parse().map_err(|_| "Invalid --world-size height")?,
+                ));
+            }
+            arg if arg.starts_with("--width=") => {
+                let width_str = arg.strip_prefix("--width=").unwrap();
+                world_width = Some(width_str.parse().map_err(|_| "Invalid --width value")?);
+            }
+            arg if arg.starts_with("--height=") => {
+                let height_str = arg.strip_prefix("--height=").unwrap();
+                world_height = Some(height_str.parse().map_err(|_| "Invalid --height value")?);
+            }
+            arg if arg.starts_with("--initial-plants=") => {
+                let count_str = arg.strip_prefix("--initial-plants=").unwrap();
+                world_config.initial_plants = count_str.parse().map_err(|_| "Invalid --initial-plants value")?;
+            }
+            arg if arg.starts_with("--initial-pillbugs=") => {
+                let count_str = arg.strip_prefix("--initial-pillbugs=").unwrap();
+                world_config.initial_pillbugs = count_str.parse().map_err(|_| "Invalid --initial-pillbugs value")?;
+            }
+            arg if arg.starts_with("--initial-nutrients=") => {
+                let count_str = arg.strip_prefix("--initial-nutrients=").unwrap();
+                world_config.initial_nutrients = count_str.parse().map_err(|_| "Invalid --initial-nutrients value")?;
+            }
+            arg if arg.starts_with("--soil-depth=") => {
+                let depth_str = arg.strip_prefix("--soil-depth=").unwrap();
+                world_config.soil_depth = depth_str.parse().map_err(|_| "Invalid --soil-depth value")?;
+            }
+            arg if arg.starts_with("--plant-density=") => {
+                let density_str = arg.strip_prefix("--plant-density=").unwrap();
+                world_config.plant_density = density_str.parse().map_err(|_| "Invalid --plant-density value")?;
+                if !(0.0..=1.0).contains(&world_config.plant_density) {
+                    return Err("--plant-density must be between 0 and 1".into());
+                }
+            }
+            arg if arg.starts_with("--year-length=") => {
+                let length_str = arg.strip_prefix("--year-length=").unwrap();
+                let length: u64 = length_str.parse().map_err(|_| "Invalid --year-length value")?;
+                if length == 0 {
+                    return Err("--year-length must be at least 1".into());
+                }
+                year_length = Some(length);
+            }
+            arg if arg.starts_with("--biome-map=") => {
+                let file_str = arg.strip_prefix("--biome-map=").unwrap();
+                let text = fs::read_to_string(file_str).map_err(|err| format!("Could not read --biome-map file: {}", err))?;
+                biome_map = Some(parse_biome_map(&text).map_err(|err| format!("Invalid --biome-map file: {}", err))?);
+            }
+            "--no-respawn" => allow_respawn = false,
             arg if arg.starts_with("--output-file=") => {
                 let file_str = arg.strip_prefix("--output-file=").unwrap();
                 output_file = Some(file_str.to_string());
             }
+            arg if arg.starts_with("--tilemap-file=") => {
+                let file_str = arg.strip_prefix("--tilemap-file=").unwrap();
+                tilemap_file = Some(file_str.to_string());
+            }
+            arg if arg.starts_with("--stats-json=") => {
+                let file_str = arg.strip_prefix("--stats-json=").unwrap();
+                stats_file = Some(file_str.to_string());
+            }
+            arg if arg.starts_with("--perf-json=") => {
+                let file_str = arg.strip_prefix("--perf-json=").unwrap();
+                perf_file = Some(file_str.to_string());
+            }
+            arg if arg.starts_with("--png=") => {
+                let file_str = arg.strip_prefix("--png=").unwrap();
+                png_file = Some(file_str.to_string());
+            }
+            arg if arg.starts_with("--log-csv=") => {
+                let file_str = arg.strip_prefix("--log-csv=").unwrap();
+                log_file = Some(file_str.to_string());
+            }
+            arg if arg.starts_with("--log-interval=") => {
+                let interval_str = arg.strip_prefix("--log-interval=").unwrap();
+                log_interval = interval_str.parse().map_err(|_| "Invalid --log-interval value")?;
+                if log_interval == 0 {
+                    return Err("--log-interval must be at least 1".into());
+                }
+            }
+            arg if arg.starts_with("--record=") => {
+                let file_str = arg.strip_prefix("--record=").unwrap();
+                record_file = Some(file_str.to_string());
+            }
+            arg if arg.starts_with("--frames-dir=") => {
+                let dir_str = arg.strip_prefix("--frames-dir=").unwrap();
+                frames_dir = Some(dir_str.to_string());
+            }
+            arg if arg.starts_with("--thumbnails=") => {
+                let count_str = arg.strip_prefix("--thumbnails=").unwrap();
+                thumbnails = count_str.parse().map_err(|_| "Invalid --thumbnails value")?;
+            }
+            arg if arg.starts_with("--thumb-output=") => {
+                let file_str = arg.strip_prefix("--thumb-output=").unwrap();
+                thumb_output = Some(file_str.to_string());
+            }
+            arg if arg.starts_with("--thumb-scale=") => {
+                let scale_str = arg.strip_prefix("--thumb-scale=").unwrap();
+                thumb_scale = scale_str.parse().map_err(|_| "Invalid --thumb-scale value")?;
+            }
+            arg if arg.starts_with("--thumb-layout=") => {
+                thumb_layout = match arg.strip_prefix("--thumb-layout=").unwrap() {
+                    "strip" => ThumbnailLayout::Strip,
+                    "grid" => ThumbnailLayout::Grid,
+                    _ => return Err("Invalid --thumb-layout value (use strip or grid)".into()),
+                };
+            }
+            arg if arg.starts_with("--serve=") => {
+                let addr_str = arg.strip_prefix("--serve=").unwrap();
+                serve_addr = Some(addr_str.to_string());
+            }
+            "--serve-stdio" => serve_stdio = true,
+            "--stop-on-extinction" => stop_on_extinction = true,
+            "--log-events" => log_events = true,
+            arg if arg.starts_with("--frame-format=") => {
+                frame_format = match arg.strip_prefix("--frame-format=").unwrap() {
+                    "json" => FrameFormat::Json,
+                    "binary" => FrameFormat::Binary,
+                    _ => return Err("Invalid --frame-format value (use json or binary)".into()),
+                };
+            }
+            arg if arg.starts_with("--frame-every=") => {
+                let every_str = arg.strip_prefix("--frame-every=").unwrap();
+                frame_every = every_str.parse().map_err(|_| "Invalid --frame-every value")?;
+                if frame_every == 0 {
+                    return Err("--frame-every must be at least 1".into());
+                }
+            }
+            arg if arg.starts_with("--frame-interval=") => {
+                let interval_str = arg.strip_prefix("--frame-interval=").unwrap();
+                frame_interval = Some(interval_str.parse().map_err(|_| "Invalid --frame-interval value")?);
+                if frame_interval == Some(0) {
+                    return Err("--frame-interval must be at least 1".into());
+                }
+            }
+            arg if arg.starts_with("--palette=") => {
+                let file_str = arg.strip_prefix("--palette=").unwrap();
+                palette_file = Some(file_str.to_string());
+            }
             "--help" | "-h" => {
                 println!("Pillbug Plants Simulation");
                 println!("Usage: {} [options]", args[0]);
+                println!("       {} replay FILE   Play back a recording made with --record", args[0]);
                 println!("Options:");
                 println!("  --sim-ticks=N    Run simulation for N ticks and exit");
+                println!("  --seed=N         Generate the world from seed N; same seed and ticks give identical runs");
+                println!("  --world-size=WxH World size in tiles (default 80x40 headless, terminal size interactively),");
+                println!("                   at least {}x{}", MIN_WORLD_WIDTH, MIN_WORLD_HEIGHT);
+                println!("  --width=N        World width alone, overriding --world-size's in either order; the height is left as is");
+                println!("  --height=N       World height alone, overriding --world-size's in either order; the width is left as is");
+                println!("  --initial-plants=N   Attempts to place a starting plant (default 8)");
+                println!("  --initial-pillbugs=N Attempts to place a starting pillbug (default 2)");
+                println!("  --initial-nutrients=N Attempts to scatter a starting nutrient (default 10)");
+                println!("  --soil-depth=N   Rows of terrain at the bottom of the world (default 10)");
+                println!("  --plant-density=F Base chance each starting plant takes, 0 to 1 (default 0.6)");
+                println!("  --year-length=N  Ticks from one spring to the next (default 1000)");
+                println!("  --biome-map=F    Lay out biomes from text file F, one letter per tile: W wetland, G grassland,");
+                println!("                   D drylands, F woodland; sets the world size, which any size flags have to match");
+                println!("  --no-respawn     Close the ecosystem: no plants or pillbugs are topped up when they die out,");
+                println!("                   and no beetles wander in");
                 println!("  --output-file=F  Save simulation output to file F");
+                println!("  --tilemap-file=F Save the final world as tilemap JSON to file F");
+                println!("  --stats-json=F   Save the final ecosystem statistics as JSON to file F");
+                println!("  --perf-json=F    Save each system's average time per tick, and the slowest, as JSON to file F");
+                println!("  --png=F          Save the final world as a PNG to file F (scaled by --thumb-scale)");
+                println!("  --log-csv=F      Log weather, ecosystem stats, and a census of every tile kind as CSV to file F");
+                println!("  --log-interval=N Write a CSV row every N ticks (default 1)");
+                println!("  --record=F       Record the run to file F, to play back with `replay F`");
+                println!("  --frames-dir=DIR Write the world as text to DIR/frame_00001.txt, ... every --frame-every ticks");
+                println!("  --frame-every=N  Write a frame to --frames-dir every N ticks (default 1)");
+                println!("  --thumbnails=N   Capture N evenly spaced frames into a time-lapse PPM");
+                println!("  --thumb-output=F Save the time-lapse PPM to file F");
+                println!("  --thumb-scale=S  Pixels per tile in each thumbnail (default 2)");
+                println!("  --thumb-layout=L Arrange thumbnails as a strip or grid (default strip)");
+                println!("  --stop-on-extinction End the run early once the plants or pillbugs have died out");
+                println!("  --log-events     Print notable events (flowering, births, outbreaks, lakes, collapses) as they happen");
+                println!("  --serve=ADDR     Run headless and stream frames to TCP clients on ADDR");
+                println!("  --serve-stdio    Run headless, read commands from stdin, stream frames to stdout");
+                println!("  --frame-format=F Frame encoding: json (tilemap) or binary (default json)");
+                println!("  --frame-interval=MS Tick and send a frame every MS milliseconds instead of on request");
+                println!("  --palette=F      Color tiles in the terminal UI from file F, one name=R,G,B per line naming a tile");
+                println!("                   kind as the CSV census does, e.g. plant_leaf=40,180,60; unnamed tiles keep their colors");
                 println!("  --help, -h       Show this help message");
                 return Ok(());
             }
@@ -57,11 +299,107 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         i += 1;
     }
     
+    // --width and --height override their side of --world-size wherever they come on the line
+    if let Some((width, height)) = world_size_arg {
+        world_width = world_width.or(Some(width));
+        world_height = world_height.or(Some(height));
+    }
+    if world_width.is_some_and(|width| width < MIN_WORLD_WIDTH) || world_height.is_some_and(|height| height < MIN_WORLD_HEIGHT) {
+        return Err(format!("The world must be at least {}x{}", MIN_WORLD_WIDTH, MIN_WORLD_HEIGHT).into());
+    }
+    
+    // A biome map decides the world size, so the size flags have to agree with it
+    if let Some(map) = &biome_map {
+        let map_size = (map[0].len(), map.len());
+        if map_size.0 < MIN_WORLD_WIDTH || map_size.1 < MIN_WORLD_HEIGHT {
+            return Err(format!("--biome-map is {}x{} but must be at least {}x{}", map_size.0, map_size.1, MIN_WORLD_WIDTH, MIN_WORLD_HEIGHT).into());
+        }
+        let size = (world_width.unwrap_or(map_size.0), world_height.unwrap_or(map_size.1));
+        if size != map_size {
+            return Err(format!("--biome-map is {}x{} but the world size asked for is {}x{}", map_size.0, map_size.1, size.0, size.1).into());
+        }
+        (world_width, world_height) = (Some(map_size.0), Some(map_size.1));
+    }
+    // Size for headless runs, and interactively once the terminal's size is known, from
+    // whichever of the width and height weren't given
+    let world_size = |default_width: usize, default_height: usize| (world_width.unwrap_or(default_width), world_height.unwrap_or(default_height));
+    
+    // Frame server mode: no terminal UI, frames go to a client
+    if serve_addr.is_some() || serve_stdio {
+        let pacing = match frame_interval {
+            Some(ms) => FramePacing::Interval(std::time::Duration::from_millis(ms)),
+            None => FramePacing::OnDemand,
+        };
+        let (world_width, world_height) = world_size(80, 40);
+        let world = generate_world(world_width, world_height, seed, world_config, year_length, biome_map, allow_respawn);
+        let mut server = FrameServer::new(world, frame_format);
+        match serve_addr {
+            Some(addr) => server.listen(addr, pacing, |event| match event {
+                ListenEvent::Listening(addr) => println!("Serving frames on {}", addr),
+                ListenEvent::ClientFailed(peer, err) => eprintln!("Client {} session ended: {}", peer, err),
+                ListenEvent::AcceptFailed(err) => eprintln!("Couldn't accept a client: {}", err),
+            })?,
+            None => server.serve(io::stdin().lock(), io::stdout().lock(), pacing)?,
+        }
+        return Ok(());
+    }
+    
     // Run in simulation mode if --sim-ticks is specified
     if let Some(ticks) = sim_ticks {
-        return run_simulation(ticks, output_file);
+        if thumbnails > 0 && thumb_output.is_none() {
+            return Err("--thumbnails requires --thumb-output".into());
+        }
+        return run_simulation(SimOptions {
+            ticks,
+            seed,
+            world_size: world_size(80, 40),
+            world_config,
+            year_length,
+            biome_map,
+            allow_respawn,
+            output_file,
+            tilemap_file,
+            stats_file,
+            perf_file,
+            png_file,
+            log_file,
+            log_interval,
+            record_file,
+            frames_dir,
+            frame_every,
+            thumbnails,
+            thumb_output,
+            thumb_scale,
+            thumb_layout,
+            stop_on_extinction,
+            log_events,
+        });
     }
     
+    // Without a size the world fills the terminal, however small; larger worlds scroll with the camera
+    run_interactive(|terminal_width, terminal_height| {
+        let (world_width, world_height) = world_size(
+            (terminal_width.saturating_sub(4) as usize).max(MIN_WORLD_WIDTH),
+            (terminal_height.saturating_sub(6) as usize).max(MIN_WORLD_HEIGHT),
+        );
+        let mut app = App::with_world(generate_world(world_width, world_height, seed, world_config, year_length, biome_map, allow_respawn));
+        if let Some(file_path) = &record_file {
+            match app.world.record_to(file_path) {
+                Ok(recording) => app.recording = Some(recording),
+                Err(err) => app.status = Some(format!("Recording failed: {}", err)),
+            }
+        }
+        if let Some(file_path) = &palette_file {
+            if let Err(err) = app.load_palette(file_path) {
+                app.status = Some(format!("Palette failed: {}", err));
+            }
+        }
+        app
+    })
+}
+
+// Run the terminal UI on the app `make_app` builds for the terminal's width and height
+fn run_interactive(make_app: impl FnOnce(u16, u16) -> App) -> Result<(), Box<dyn std::error::Error>> {
     // Set up panic hook to restore terminal state
     std::panic::set_hook(Box::new(|panic_info| {
         // Try to restore terminal state
@@ -77,17 +415,14 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen)?;
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
-
-    let size = terminal.size()?;
-    let world_width = size.width.saturating_sub(4) as usize;
-    let world_height = size.height.saturating_sub(6) as usize;
     
-    let mut app = App::new(world_width, world_height);
+    let size = terminal.size()?;
+    let mut app = make_app(size.width, size.height);
     let res = run_app(&mut terminal, &mut app);
-
+    
     disable_raw_mode()?;
     execute!(
         terminal.backend_mut(),
@@ -95,36 +430,133 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         DisableMouseCapture
     )?;
     terminal.show_cursor()?;
-
+    
     if let Err(err) = res {
         println!("{err:?}");
     }
-
+    
     Ok(())
 }
 
-fn run_simulation(ticks: u64, output_file: Option<String>) -> Result<(), Box<dyn std::error::Error>> {
-    // Create a world with fixed dimensions for consistency
-    let world_width = 80;
-    let world_height = 40;
-    let mut world = World::new(world_width, world_height);
+// Generate from the given seed, or a random one when none was asked for
+fn generate_world(width: usize, height: usize, seed: Option<u64>, config: WorldConfig, year_length: Option<u64>, biome_map: Option<Vec<Vec<Biome>>>, allow_respawn: bool) -> World {
+    let seed = seed.unwrap_or_else(rand::random);
+    let mut world = match biome_map {
+        Some(map) => WorldBuilder::new(width, height).seed(seed).world_config(config).biome_map(map).build(),
+        None => World::new_seeded_with_config(width, height, seed, config),
+    };
+    if let Some(ticks) = year_length {
+        world.set_year_length(ticks);
+    }
+    world.allow_respawn = allow_respawn;
+    world
+}
+
+fn run_simulation(options: SimOptions) -> Result<(), Box<dyn std::error::Error>> {
+    let ticks = options.ticks;
+    let (world_width, world_height) = options.world_size;
+    let mut world = generate_world(world_width, world_height, options.seed, options.world_config, options.year_length, options.biome_map, options.allow_respawn);
+    
+    println!("Running simulation for {} ticks (seed {})...", ticks, world.seed);
     
-    println!("Running simulation for {} ticks...", ticks);
+    // Ticks after which a thumbnail is captured, evenly spaced and ending on the final tick
+    let thumbnail_ticks: Vec<u64> = (1..=options.thumbnails as u64)
+        .map(|i| (ticks * i / options.thumbnails as u64).max(1))
+        .collect();
+    let mut frames = Vec::new();
     
-    // Run simulation
-    for tick in 0..ticks {
-        world.update();
+    // CSV log gets a header now and a row every log interval
+    let mut log_csv = match &options.log_file {
+        Some(file_path) => {
+            let mut file = File::create(file_path)?;
+            writeln!(file, "{}", log_csv_header())?;
+            Some(file)
+        }
+        None => None,
+    };
+    
+    // Text frames get numbered from 1, zero-padded so they sort in order
+    if let Some(dir) = &options.frames_dir {
+        fs::create_dir_all(dir)?;
+    }
+    let mut frame_count = 0;
+    
+    // Per-system timings summed over the run, averaged at the end
+    let mut perf_total = PerformanceMetrics::default();
+    
+    // A recording starts from the world as generated
+    let mut recording = match &options.record_file {
+        Some(file_path) => Some(world.record_to(file_path)?),
+        None => None,
+    };
+    
+    // Run simulation, warning as the ecosystem collapses or dies out. The first write that
+    // fails stops the run and is returned once it's over
+    let mut phase = EcosystemPhase::Thriving;
+    let mut failure: Option<io::Error> = None;
+    let ticks_run = world.run_while(ticks, |step, world| {
+        let mut write_outputs = || -> io::Result<()> {
+            if let Some(recording) = recording.as_mut() {
+                recording.capture(world)?;
+            }
+            
+            if let Some(file) = log_csv.as_mut() {
+                if world.tick.is_multiple_of(options.log_interval) {
+                    writeln!(file, "{}", world.log_csv_row())?;
+                }
+            }
+            
+            if let Some(dir) = &options.frames_dir {
+                if world.tick.is_multiple_of(options.frame_every) {
+                    frame_count += 1;
+                    fs::write(Path::new(dir).join(format!("frame_{:05}.txt", frame_count)), world.to_string())?;
+                }
+            }
+            Ok(())
+        };
+        if let Err(err) = write_outputs() {
+            failure = Some(err);
+            return false;
+        }
+        perf_total.accumulate(&world.performance);
+        
+        if thumbnail_ticks.contains(&step) {
+            frames.push(world.to_image(options.thumb_scale));
+        }
         
         // Print progress every 100 ticks
-        if tick % 100 == 0 || tick == ticks - 1 {
-            println!("Progress: {}/{} ticks", tick + 1, ticks);
+        if step % 100 == 1 || step == ticks {
+            println!("Progress: {}/{} ticks", step, ticks);
         }
+        
+        // Events from this tick, in the order they happened
+        if options.log_events {
+            let fresh = world.events.iter().rev().take_while(|event| event.tick == world.tick).count();
+            for event in world.events.iter().skip(world.events.len() - fresh) {
+                println!("{}", event);
+            }
+        }
+        
+        let now = world.ecosystem_phase();
+        if now != phase && now >= EcosystemPhase::Collapsed {
+            let stats = world.calculate_ecosystem_stats();
+            println!("Warning: ecosystem {} at tick {} ({} plant tiles, {} pillbug tiles)", now.name().to_lowercase(), world.tick, stats.total_plants, stats.total_pillbugs);
+        }
+        phase = now;
+        if phase == EcosystemPhase::Extinct && options.stop_on_extinction {
+            println!("Stopping early after {}/{} ticks", step, ticks);
+            return false;
+        }
+        true
+    });
+    if let Some(err) = failure {
+        return Err(err.into());
     }
     
     let final_state = world.to_string();
     
     // Output results
-    if let Some(file_path) = output_file {
+    if let Some(file_path) = options.output_file {
         let mut file = File::create(&file_path)?;
         write!(file, "{}", final_state)?;
         println!("Simulation results saved to: {}", file_path);
@@ -133,5 +565,46 @@ fn run_simulation(ticks: u64, output_file: Option<String>) -> Result<(), Box<dyn
         print!("{}", final_state);
     }
     
+    if let Some(file_path) = options.tilemap_file {
+        let mut file = File::create(&file_path)?;
+        write!(file, "{}", world.to_tilemap_json())?;
+        println!("Tilemap saved to: {}", file_path);
+    }
+    
+    if let Some(file_path) = options.stats_file {
+        let mut file = File::create(&file_path)?;
+        write!(file, "{}", world.ecosystem_report().to_json())?;
+        println!("Ecosystem stats saved to: {}", file_path);
+    }
+    
+    if let Some(file_path) = options.perf_file {
+        let mut file = File::create(&file_path)?;
+        write!(file, "{}", perf_total.averaged(ticks_run).to_json())?;
+        println!("Performance metrics saved to: {}", file_path);
+    }
+    
+    if let Some(file_path) = options.png_file {
+        world.render_to_png(&file_path, options.thumb_scale as u32)?;
+        println!("PNG saved to: {}", file_path);
+    }
+    
+    if let Some(file_path) = options.log_file {
+        println!("CSV log saved to: {}", file_path);
+    }
+    
+    if let Some(file_path) = options.record_file {
+        println!("Recording saved to: {}", file_path);
+    }
+    
+    if let Some(dir) = options.frames_dir {
+        println!("{} text frames saved to: {}", frame_count, dir);
+    }
+    
+    if let Some(file_path) = options.thumb_output {
+        let sheet = thumbnail_sheet(&frames, options.thumb_layout);
+        File::create(&file_path)?.write_all(&sheet.to_ppm())?;
+        println!("{} thumbnails saved to: {}", frames.len(), file_path);
+    }
+    
     Ok(())
 }
\ No newline at end of file
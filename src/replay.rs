@@ -0,0 +1,194 @@
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+use crate::save::{invalid_data, write_tile, write_u32, write_u64, read_bytes, read_u8, read_tile, read_u32, read_u64};
+use crate::types::TileType;
+use crate::world::{World, TileChange};
+
+const REPLAY_VERSION: u8 = 1;
+
+/// Writes a run as a starting snapshot followed by one frame of tile changes per capture,
+/// far smaller than saving every frame whole.
+///
+/// Layout (big-endian): magic `b"PBPR"`, version byte `1`, the starting world in the
+/// `save_to_writer` format, then frames until the end of the file: `tick: u64`, `count: u32`,
+/// and `count` changes of `x: u32`, `y: u32`, and the new tile as saves store it.
+///
+/// ```
+/// use pillbugplants::builder::WorldBuilder;
+/// use pillbugplants::replay::Replay;
+///
+/// let mut world = WorldBuilder::new(10, 5).fill_soil(2).build();
+/// let mut bytes = Vec::new();
+/// let mut recording = world.record_to_writer(&mut bytes).unwrap();
+/// for _ in 0..3 {
+///     recording.tick(&mut world).unwrap();
+/// }
+/// drop(recording);
+///
+/// let (mut replayed, mut replay) = Replay::load_from_reader(&mut &bytes[..]).unwrap();
+/// while replay.step_forward(&mut replayed) {}
+/// assert_eq!(replayed.tiles, world.tiles);
+/// ```
+pub struct Recording<W: Write> {
+    writer: W,
+    last: Vec<Vec<TileType>>, // Tiles as of the last frame written
+}
+
+impl<W: Write> Recording<W> {
+    /// Run one `update` and write the frame it made
+    pub fn tick(&mut self, world: &mut World) -> io::Result<()> {
+        world.update();
+        self.capture(world)
+    }
+    
+    /// Write a frame of every tile that changed since the last one, whether a tick or an edit
+    /// changed it. The world has to keep the size it was recorded at.
+    pub fn capture(&mut self, world: &World) -> io::Result<()> {
+        if world.width != self.last.first().map_or(0, Vec::len) || world.height != self.last.len() {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "the world changed size mid-recording"));
+        }
+        let mut changes = Vec::new();
+        for y in 0..world.height {
+            for x in 0..world.width {
+                if world.tiles[y][x] != self.last[y][x] {
+                    changes.push((x, y, world.tiles[y][x]));
+                    self.last[y][x] = world.tiles[y][x];
+                }
+            }
+        }
+        
+        write_u64(&mut self.writer, world.tick)?;
+        write_u32(&mut self.writer, changes.len() as u32)?;
+        for (x, y, tile) in changes {
+            write_u32(&mut self.writer, x as u32)?;
+            write_u32(&mut self.writer, y as u32)?;
+            write_tile(&mut self.writer, tile)?;
+        }
+        self.writer.flush()
+    }
+}
+
+impl World {
+    /// Start recording this world to a file, beginning with a snapshot of it as it is now
+    pub fn record_to(&self, path: impl AsRef<Path>) -> io::Result<Recording<BufWriter<File>>> {
+        self.record_to_writer(BufWriter::new(File::create(path)?))
+    }
+    
+    /// Start recording this world to any writer; see `Recording` for the format
+    pub fn record_to_writer<W: Write>(&self, mut writer: W) -> io::Result<Recording<W>> {
+        writer.write_all(b"PBPR")?;
+        writer.write_all(&[REPLAY_VERSION])?;
+        self.save_to_writer(&mut writer)?;
+        writer.flush()?;
+        Ok(Recording { writer, last: self.tiles.clone() })
+    }
+}
+
+struct Frame {
+    tick: u64,
+    changes: Vec<TileChange>,
+}
+
+fn read_frame(r: &mut impl Read, world: &World) -> io::Result<Frame> {
+    let tick = read_u64(r)?;
+    let count = read_u32(r)?;
+    let mut changes = Vec::with_capacity(count.min(1 << 16) as usize);
+    for _ in 0..count {
+        let (x, y) = (read_u32(r)? as usize, read_u32(r)? as usize);
+        if x >= world.width || y >= world.height {
+            return Err(invalid_data(format!("Change at ({}, {}) is outside the {}x{} world", x, y, world.width, world.height)));
+        }
+        changes.push(TileChange { x, y, new_tile: read_tile(r)? });
+    }
+    Ok(Frame { tick, changes })
+}
+
+/// A recorded run, stepped forward and back over the world it was loaded with.
+///
+/// Only the tiles are recorded, so weather, seasons, and the rest of the world keep the
+/// starting snapshot's values; the tick follows the frames.
+pub struct Replay {
+    frames: Vec<Frame>,
+    start_tick: u64,
+    position: usize,           // Frames applied so far
+    undo: Vec<Vec<TileChange>>, // Tiles each applied frame overwrote, to step back over it
+}
+
+impl Replay {
+    /// Read a recording, returning the world at its start and the replay of its frames.
+    /// A recording cut off partway, e.g. by a crash, plays up to its last whole frame.
+    pub fn load_from_reader(r: &mut impl Read) -> io::Result<(World, Replay)> {
+        if &read_bytes::<4>(r)? != b"PBPR" {
+            return Err(invalid_data("Not a pillbugplants recording".to_string()));
+        }
+        let version = read_u8(r)?;
+        if version != REPLAY_VERSION {
+            return Err(invalid_data(format!("Unsupported recording version: {}", version)));
+        }
+        let world = World::load_from_reader(r)?;
+        
+        let mut frames = Vec::new();
+        loop {
+            match read_frame(r, &world) {
+                Ok(frame) => frames.push(frame),
+                Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => break,
+                Err(err) => return Err(err),
+            }
+        }
+        
+        let replay = Replay { frames, start_tick: world.tick, position: 0, undo: Vec::new() };
+        Ok((world, replay))
+    }
+    
+    /// Read a recording from a file
+    pub fn load(path: impl AsRef<Path>) -> io::Result<(World, Replay)> {
+        Replay::load_from_reader(&mut BufReader::new(File::open(path)?))
+    }
+    
+    /// Frames in the recording
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+    
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+    
+    /// Frames applied so far, from 0 at the snapshot to `len` at the end
+    pub fn position(&self) -> usize {
+        self.position
+    }
+    
+    /// Apply the next frame to the world; false at the end of the recording
+    pub fn step_forward(&mut self, world: &mut World) -> bool {
+        let Some(frame) = self.frames.get(self.position) else {
+            return false;
+        };
+        let mut overwritten = Vec::with_capacity(frame.changes.len());
+        for change in &frame.changes {
+            overwritten.push(TileChange { x: change.x, y: change.y, new_tile: world.tiles[change.y][change.x] });
+            world.tiles[change.y][change.x] = change.new_tile;
+        }
+        world.tick = frame.tick;
+        self.undo.push(overwritten);
+        self.position += 1;
+        true
+    }
+    
+    /// Undo the last frame applied; false at the start of the recording
+    pub fn step_back(&mut self, world: &mut World) -> bool {
+        let Some(overwritten) = self.undo.pop() else {
+            return false;
+        };
+        for change in overwritten.iter().rev() {
+            world.tiles[change.y][change.x] = change.new_tile;
+        }
+        self.position -= 1;
+        world.tick = match self.position {
+            0 => self.start_tick,
+            position => self.frames[position - 1].tick,
+        };
+        true
+    }
+}
@@ -0,0 +1,431 @@
+use std::collections::HashMap;
+use std::io::{self, Read, Write};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use crate::config::{PhysicsConfig, DroughtConfig, GrowthConfig, InteractionConfig, ActivityConfig, DiseaseConfig, PillbugConfig, RespawnConfig};
+use crate::export::{BIOMES, SIZES, tile_size};
+use crate::types::{TileType, Size, WindMode, SoilLayer, EdgeBehavior, PlantGenome, PlantSpecies, SystemFlags};
+use crate::world::{World, SeedProjectile, PillbugState};
+
+/// Version byte written by `save_to_writer`; saves in any other version are rejected
+pub(crate) const SAVE_VERSION: u8 = 1;
+
+/// Largest side and cell count a save may claim, so a corrupt or hostile header can't ask
+/// for a huge allocation before any cells are read
+const MAX_SAVE_SIDE: usize = 4096;
+const MAX_SAVE_CELLS: usize = 4_194_304;
+
+const SOIL_LAYERS: [SoilLayer; 3] = [SoilLayer::Topsoil, SoilLayer::Subsoil, SoilLayer::ParentRock];
+const EDGE_BEHAVIORS: [EdgeBehavior; 3] = [EdgeBehavior::Absorb, EdgeBehavior::Reflect, EdgeBehavior::Wrap];
+
+pub(crate) fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+pub(crate) fn write_tile(w: &mut impl Write, tile: TileType) -> io::Result<()> {
+    w.write_all(&[tile.tile_id(), tile.value(), tile_size(tile).map_or(0, |s| s as u8 + 1)])?;
+    match tile.get_water_quality() {
+        Some(quality) => w.write_all(&[quality]),
+        None => Ok(()),
+    }
+}
+
+fn write_f32(w: &mut impl Write, value: f32) -> io::Result<()> {
+    w.write_all(&value.to_be_bytes())
+}
+
+pub(crate) fn write_u32(w: &mut impl Write, value: u32) -> io::Result<()> {
+    w.write_all(&value.to_be_bytes())
+}
+
+pub(crate) fn write_u64(w: &mut impl Write, value: u64) -> io::Result<()> {
+    w.write_all(&value.to_be_bytes())
+}
+
+fn write_genome(w: &mut impl Write, genome: PlantGenome) -> io::Result<()> {
+    for value in [genome.growth_speed, genome.drought_tolerance, genome.disease_resistance] {
+        write_f32(w, value)?;
+    }
+    Ok(())
+}
+
+pub(crate) fn read_bytes<const N: usize>(r: &mut impl Read) -> io::Result<[u8; N]> {
+    let mut bytes = [0; N];
+    r.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+pub(crate) fn read_u8(r: &mut impl Read) -> io::Result<u8> {
+    Ok(read_bytes::<1>(r)?[0])
+}
+
+fn read_f32(r: &mut impl Read) -> io::Result<f32> {
+    Ok(f32::from_be_bytes(read_bytes(r)?))
+}
+
+pub(crate) fn read_u32(r: &mut impl Read) -> io::Result<u32> {
+    Ok(u32::from_be_bytes(read_bytes(r)?))
+}
+
+pub(crate) fn read_u64(r: &mut impl Read) -> io::Result<u64> {
+    Ok(u64::from_be_bytes(read_bytes(r)?))
+}
+
+/// Look up an enum variant by its saved index
+fn from_index<T: Copy>(table: &[T], index: u8, what: &str) -> io::Result<T> {
+    table.get(index as usize).copied().ok_or_else(|| invalid_data(format!("Invalid {} id: {}", what, index)))
+}
+
+fn read_size(r: &mut impl Read) -> io::Result<Option<Size>> {
+    match read_u8(r)? {
+        0 => Ok(None),
+        id => from_index(&SIZES, id - 1, "size").map(Some),
+    }
+}
+
+fn read_genome(r: &mut impl Read) -> io::Result<PlantGenome> {
+    Ok(PlantGenome {
+        growth_speed: read_f32(r)?,
+        drought_tolerance: read_f32(r)?,
+        disease_resistance: read_f32(r)?,
+    })
+}
+
+pub(crate) fn read_tile(r: &mut impl Read) -> io::Result<TileType> {
+    let id = read_u8(r)?;
+    let value = read_u8(r)?;
+    let size = read_size(r)?;
+    match TileType::from_parts(id, value, size) {
+        Some(TileType::Water(depth, _)) => Ok(TileType::Water(depth, read_u8(r)?)),
+        Some(tile) => Ok(tile),
+        None => Err(invalid_data(format!("Invalid tile id {} with size {:?}", id, size))),
+    }
+}
+
+impl World {
+    /// Save the full simulation state so `load_from_reader` can resume it. Everything is
+    /// big-endian; a tile is its id, value, and size id + 1 or 0 (`u8` each), plus a quality
+    /// `u8` for water, and a genome is its three traits as `f32`s. The sections, in order:
+    ///
+    /// 1. Header: magic `b"PBPS"`, version `u8` (`1`), `width: u32`, `height: u32`, `tick: u64`,
+    ///    `seed: u64`, and a `u64` seed for the resumed random stream.
+    /// 2. Weather and season: day cycle, rain, season cycle, temperature, humidity, and wind
+    ///    (`f32` each), ticks since rain `u32`, wind mode `u8` (plus direction and strength `f32`
+    ///    when deterministic), gust flag `u8` (plus extra strength `f32` and ticks left `u8`),
+    ///    edge behavior `u8`, horizontal wrap `u8`, year and day lengths and season offset (`u64`
+    ///    ticks each), and the depth evaporated last tick `u32`.
+    /// 3. Configuration: physics config (five `f32` rates and chances), drought config (rain
+    ///    threshold `f32`, stress thresholds `u32` each), growth config (density cap `f32`, radius
+    ///    `u32`), activity config (nocturnal `u8`, move chances `f32` each), interaction config
+    ///    (germination table row by row and nitrogen fixing chances as `f32`s, then
+    ///    `radius: u32`), respawn flag `u8` and respawn config (`u32` each), enabled systems as
+    ///    `SystemFlags` bits `u8`, disease config (`f32` chances, lethal age `u8`, recovery chance
+    ///    `f32`), and pillbug config (maturity and breeding ages `u8` each, breed chance and
+    ///    litter variation `f32` each).
+    /// 4. Cells, `width * height` row-major: tile, biome id `u8`, soil layer id + 1 or 0 `u8`,
+    ///    disturbance `f32`, genome flag `u8` (plus genome), elevation `u8`, species id + 1 or 0
+    ///    `u8`, biome pressure `u8`, pheromone `u8`.
+    /// 5. Seeds in flight: count `u32`, then per seed position and velocity (`f32` each), seed
+    ///    tile, bounce count `u8`, and genome.
+    /// 6. Pillbug states, sorted by position: count `u32`, then per pillbug `x: u32`, `y: u32`,
+    ///    meals `u16`, ticks left rolled up `u8`, energy `u8`, carried seed flag `u8` (plus seed
+    ///    tile, genome, and moves carried `u8`), and ticks left soft-shelled `u8`.
+    ///
+    /// Loading rejects any other version, and worlds over 4096 tiles a side or 4096x1024 tiles
+    /// in all.
+    ///
+    /// Not saved: the performance metrics, the weather controller, and the pillbug index, which
+    /// every tick rebuilds; the population history, event feed, and seed drift, which start
+    /// over; and the `WorldConfig`, which only shapes generation. A loaded world starts with
+    /// fresh metrics and the default `SeasonalWeather`, so install a custom controller again
+    /// after loading.
+    pub fn save_to_writer(&self, w: &mut impl Write) -> io::Result<()> {
+        w.write_all(b"PBPS")?;
+        w.write_all(&[SAVE_VERSION])?;
+        write_u32(w, self.width as u32)?;
+        write_u32(w, self.height as u32)?;
+        write_u64(w, self.tick)?;
+        write_u64(w, self.seed)?;
+        // The generator itself can't be serialized, so the loaded world continues from a
+        // seed drawn off a copy of it; the same save always resumes the same way
+        write_u64(w, self.rng.clone().gen())?;
+        
+        // Weather and season
+        for value in [self.day_cycle, self.rain_intensity, self.season_cycle, self.temperature,
+                      self.humidity, self.wind_direction, self.wind_strength] {
+            write_f32(w, value)?;
+        }
+        write_u32(w, self.ticks_since_rain)?;
+        match self.wind_mode {
+            WindMode::Seasonal => w.write_all(&[0])?,
+            WindMode::Deterministic { direction, strength } => {
+                w.write_all(&[1])?;
+                write_f32(w, direction)?;
+                write_f32(w, strength)?;
+            }
+        }
+        match self.gust {
+            Some((extra, ticks)) => {
+                w.write_all(&[1])?;
+                write_f32(w, extra)?;
+                w.write_all(&[ticks])?;
+            }
+            None => w.write_all(&[0])?,
+        }
+        w.write_all(&[self.edge_behavior as u8])?;
+        w.write_all(&[self.wrap_horizontal as u8])?;
+        write_u64(w, self.ticks_per_year)?;
+        write_u64(w, self.ticks_per_day)?;
+        write_u64(w, self.season_offset)?;
+        write_u32(w, self.evaporated)?;
+        
+        // Configuration
+        write_f32(w, self.physics_config.evaporation_rate)?;
+        write_f32(w, self.physics_config.absorption_rate)?;
+        write_f32(w, self.physics_config.seed_fall_chance)?;
+        write_f32(w, self.physics_config.spore_fall_chance)?;
+        write_f32(w, self.physics_config.nutrient_fall_chance)?;
+        write_f32(w, self.drought_config.rain_threshold)?;
+        write_u32(w, self.drought_config.stress_after)?;
+        write_u32(w, self.drought_config.full_stress_after)?;
+        write_f32(w, self.growth_config.density_cap)?;
+        write_u32(w, self.growth_config.density_radius as u32)?;
+        w.write_all(&[self.activity_config.nocturnal as u8])?;
+        write_f32(w, self.activity_config.active_move_chance)?;
+        write_f32(w, self.activity_config.resting_move_chance)?;
+        for value in self.interaction_config.germination.iter().flatten().chain(&self.interaction_config.nitrogen_fixing) {
+            write_f32(w, *value)?;
+        }
+        write_u32(w, self.interaction_config.radius as u32)?;
+        w.write_all(&[self.allow_respawn as u8])?;
+        let respawn = self.respawn_config;
+        for value in [respawn.min_plants, respawn.plant_target, respawn.min_pillbugs, respawn.pillbug_target] {
+            write_u32(w, value as u32)?;
+        }
+        w.write_all(&[self.systems.bits()])?;
+        let disease = self.disease_config;
+        for value in [disease.outbreak_chance, disease.spread_chance, disease.spore_chance, disease.spore_infection_chance, disease.foul_water_chance] {
+            write_f32(w, value)?;
+        }
+        w.write_all(&[disease.lethal_age])?;
+        write_f32(w, disease.recovery_chance)?;
+        let pillbugs = self.pillbug_config;
+        w.write_all(&[pillbugs.maturity_age, pillbugs.max_breeding_age])?;
+        write_f32(w, pillbugs.breed_chance)?;
+        write_f32(w, pillbugs.litter_variation)?;
+        
+        // Cells
+        for y in 0..self.height {
+            for x in 0..self.width {
+                write_tile(w, self.tiles[y][x])?;
+                w.write_all(&[self.biome_map[y][x] as u8, self.soil_layers[y][x].map_or(0, |l| l as u8 + 1)])?;
+                write_f32(w, self.disturbance[y][x])?;
+                match self.genomes[y][x] {
+                    Some(genome) => {
+                        w.write_all(&[1])?;
+                        write_genome(w, genome)?;
+                    }
+                    None => w.write_all(&[0])?,
+                }
+                w.write_all(&[self.elevation[y][x], self.species[y][x].map_or(0, |s| s as u8 + 1), self.biome_pressure[y][x], self.pheromone[y][x]])?;
+            }
+        }
+        
+        // Seeds in flight
+        write_u32(w, self.seed_projectiles.len() as u32)?;
+        for projectile in &self.seed_projectiles {
+            for value in [projectile.x, projectile.y, projectile.velocity_x, projectile.velocity_y] {
+                write_f32(w, value)?;
+            }
+            write_tile(w, projectile.seed_type)?;
+            w.write_all(&[projectile.bounce_count])?;
+            write_genome(w, projectile.genome)?;
+        }
+        
+        // Pillbug states, sorted so the same world always saves the same bytes
+        let mut states: Vec<_> = self.pillbug_states.iter().collect();
+        states.sort_by_key(|(pos, _)| **pos);
+        write_u32(w, states.len() as u32)?;
+        for ((x, y), state) in states {
+            write_u32(w, *x as u32)?;
+            write_u32(w, *y as u32)?;
+            w.write_all(&state.meals.to_be_bytes())?;
+            w.write_all(&[state.rolled])?;
+            w.write_all(&[state.energy])?;
+            match state.seed {
+                Some((seed, genome)) => {
+                    w.write_all(&[1])?;
+                    write_tile(w, seed)?;
+                    write_genome(w, genome)?;
+                    w.write_all(&[state.carried_for])?;
+                }
+                None => w.write_all(&[0])?,
+            }
+            w.write_all(&[state.soft])?;
+        }
+        
+        Ok(())
+    }
+    
+    /// Restore a world written by `save_to_writer`
+    pub fn load_from_reader(r: &mut impl Read) -> io::Result<World> {
+        if &read_bytes::<4>(r)? != b"PBPS" {
+            return Err(invalid_data("Not a pillbugplants save".to_string()));
+        }
+        let version = read_u8(r)?;
+        if version != SAVE_VERSION {
+            return Err(invalid_data(format!("Unsupported save version: {}", version)));
+        }
+        let width = read_u32(r)? as usize;
+        let height = read_u32(r)? as usize;
+        if !(1..=MAX_SAVE_SIDE).contains(&width) || !(1..=MAX_SAVE_SIDE).contains(&height) || width * height > MAX_SAVE_CELLS {
+            return Err(invalid_data(format!("Unsupported world size: {}x{}", width, height)));
+        }
+        let tick = read_u64(r)?;
+        let seed = read_u64(r)?;
+        let resume_seed = read_u64(r)?;
+        
+        let mut world = World::blank(width, height, seed);
+        world.tick = tick;
+        world.rng = StdRng::seed_from_u64(resume_seed);
+        
+        // Weather and season
+        world.day_cycle = read_f32(r)?;
+        world.rain_intensity = read_f32(r)?;
+        world.season_cycle = read_f32(r)?;
+        world.temperature = read_f32(r)?;
+        world.humidity = read_f32(r)?;
+        world.wind_direction = read_f32(r)?;
+        world.wind_strength = read_f32(r)?;
+        world.ticks_since_rain = read_u32(r)?;
+        world.wind_mode = match read_u8(r)? {
+            0 => WindMode::Seasonal,
+            1 => WindMode::Deterministic { direction: read_f32(r)?, strength: read_f32(r)? },
+            id => return Err(invalid_data(format!("Invalid wind mode id: {}", id))),
+        };
+        world.gust = match read_u8(r)? {
+            0 => None,
+            1 => Some((read_f32(r)?, read_u8(r)?)),
+            flag => return Err(invalid_data(format!("Invalid gust flag: {}", flag))),
+        };
+        world.edge_behavior = from_index(&EDGE_BEHAVIORS, read_u8(r)?, "edge behavior")?;
+        world.wrap_horizontal = read_u8(r)? != 0;
+        world.set_year_length(read_u64(r)?);
+        world.ticks_per_day = read_u64(r)?.max(1);
+        world.season_offset = read_u64(r)?;
+        world.evaporated = read_u32(r)?;
+        
+        // Configuration
+        world.physics_config = PhysicsConfig {
+            evaporation_rate: read_f32(r)?,
+            absorption_rate: read_f32(r)?,
+            seed_fall_chance: read_f32(r)?,
+            spore_fall_chance: read_f32(r)?,
+            nutrient_fall_chance: read_f32(r)?,
+        };
+        world.drought_config = DroughtConfig {
+            rain_threshold: read_f32(r)?,
+            stress_after: read_u32(r)?,
+            full_stress_after: read_u32(r)?,
+        };
+        world.growth_config = GrowthConfig {
+            density_cap: read_f32(r)?,
+            density_radius: read_u32(r)? as usize,
+        };
+        world.activity_config = ActivityConfig {
+            nocturnal: read_u8(r)? != 0,
+            active_move_chance: read_f32(r)?,
+            resting_move_chance: read_f32(r)?,
+        };
+        let mut interactions = InteractionConfig::default();
+        for value in interactions.germination.iter_mut().flatten().chain(&mut interactions.nitrogen_fixing) {
+            *value = read_f32(r)?;
+        }
+        interactions.radius = read_u32(r)? as usize;
+        world.interaction_config = interactions;
+        world.allow_respawn = read_u8(r)? != 0;
+        world.respawn_config = RespawnConfig {
+            min_plants: read_u32(r)? as usize,
+            plant_target: read_u32(r)? as usize,
+            min_pillbugs: read_u32(r)? as usize,
+            pillbug_target: read_u32(r)? as usize,
+        };
+        world.systems = SystemFlags::from_bits_truncate(read_u8(r)?);
+        world.disease_config = DiseaseConfig {
+            outbreak_chance: read_f32(r)?,
+            spread_chance: read_f32(r)?,
+            spore_chance: read_f32(r)?,
+            spore_infection_chance: read_f32(r)?,
+            foul_water_chance: read_f32(r)?,
+            lethal_age: read_u8(r)?,
+            recovery_chance: read_f32(r)?,
+        };
+        world.pillbug_config = PillbugConfig {
+            maturity_age: read_u8(r)?,
+            max_breeding_age: read_u8(r)?,
+            breed_chance: read_f32(r)?,
+            litter_variation: read_f32(r)?,
+        };
+        
+        // Cells
+        for y in 0..height {
+            for x in 0..width {
+                world.tiles[y][x] = read_tile(r)?;
+                world.biome_map[y][x] = from_index(&BIOMES, read_u8(r)?, "biome")?;
+                world.soil_layers[y][x] = match read_u8(r)? {
+                    0 => None,
+                    id => Some(from_index(&SOIL_LAYERS, id - 1, "soil layer")?),
+                };
+                world.disturbance[y][x] = read_f32(r)?;
+                world.genomes[y][x] = match read_u8(r)? {
+                    0 => None,
+                    1 => Some(read_genome(r)?),
+                    flag => return Err(invalid_data(format!("Invalid genome flag: {}", flag))),
+                };
+                world.elevation[y][x] = read_u8(r)?;
+                world.species[y][x] = match read_u8(r)? {
+                    0 => None,
+                    id => Some(from_index(&PlantSpecies::ALL, id - 1, "species")?),
+                };
+                world.biome_pressure[y][x] = read_u8(r)?;
+                world.pheromone[y][x] = read_u8(r)?;
+            }
+        }
+        
+        // Seeds in flight
+        let projectile_count = read_u32(r)?;
+        for _ in 0..projectile_count {
+            world.seed_projectiles.push(SeedProjectile {
+                x: read_f32(r)?,
+                y: read_f32(r)?,
+                velocity_x: read_f32(r)?,
+                velocity_y: read_f32(r)?,
+                seed_type: read_tile(r)?,
+                bounce_count: read_u8(r)?,
+                genome: read_genome(r)?,
+            });
+        }
+        
+        // Pillbug states
+        let state_count = read_u32(r)?;
+        let mut pillbug_states = HashMap::with_capacity(state_count as usize);
+        for _ in 0..state_count {
+            let pos = (read_u32(r)? as usize, read_u32(r)? as usize);
+            if pos.0 >= width || pos.1 >= height {
+                return Err(invalid_data(format!("Pillbug state at {:?} is outside the {}x{} world", pos, width, height)));
+            }
+            let meals = u16::from_be_bytes(read_bytes(r)?);
+            let rolled = read_u8(r)?;
+            let energy = read_u8(r)?;
+            let (seed, carried_for) = match read_u8(r)? {
+                0 => (None, 0),
+                1 => (Some((read_tile(r)?, read_genome(r)?)), read_u8(r)?),
+                flag => return Err(invalid_data(format!("Invalid carried seed flag: {}", flag))),
+            };
+            let soft = read_u8(r)?;
+            pillbug_states.insert(pos, PillbugState { meals, rolled, seed, carried_for, energy, soft });
+        }
+        world.pillbug_states = pillbug_states;
+        
+        Ok(world)
+    }
+}
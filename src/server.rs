@@ -0,0 +1,139 @@
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener, ToSocketAddrs};
+use std::thread;
+use std::time::Duration;
+use crate::world::World;
+
+/// How each frame is serialized
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FrameFormat {
+    Json,   // `World::to_tilemap_json`
+    Binary, // `World::to_frame_bytes`
+}
+
+/// When the server advances the simulation and sends a frame
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FramePacing {
+    OnDemand,           // Wait for client commands
+    Interval(Duration), // Tick and send a frame on a timer
+}
+
+/// What `FrameServer::listen` reports to its caller while it runs
+#[derive(Debug)]
+pub enum ListenEvent {
+    Listening(SocketAddr),               // Bound and waiting for clients
+    ClientFailed(SocketAddr, io::Error), // A client's session ended with an error
+    AcceptFailed(io::Error),             // A connection couldn't be accepted, e.g. out of file handles
+}
+
+/// Runs a world headless and streams its frames to a client.
+///
+/// Every frame is a 4-byte big-endian length followed by the payload in the chosen
+/// `FrameFormat`. In `OnDemand` pacing the client sends one command per line:
+/// - `next [n]`: advance `n` ticks (default 1), then send a frame
+/// - `frame`: send the current frame without advancing
+/// - `quit`: end the session
+///
+/// In `Interval` pacing the server sends the current frame, waits, ticks, and repeats
+/// until the client goes away.
+///
+/// ```
+/// use pillbugplants::builder::WorldBuilder;
+/// use pillbugplants::server::{FrameServer, FrameFormat, FramePacing};
+///
+/// let world = WorldBuilder::new(10, 5).fill_soil(2).build();
+/// let mut server = FrameServer::new(world, FrameFormat::Binary);
+/// let mut output = Vec::new();
+/// server.serve(&b"next 3\nquit\n"[..], &mut output, FramePacing::OnDemand).unwrap();
+/// assert_eq!(server.world().tick, 3);
+/// assert_eq!(&output[4..8], b"PBPF");
+/// ```
+pub struct FrameServer {
+    world: World,
+    format: FrameFormat,
+}
+
+impl FrameServer {
+    pub fn new(world: World, format: FrameFormat) -> Self {
+        FrameServer { world, format }
+    }
+    
+    pub fn world(&self) -> &World {
+        &self.world
+    }
+    
+    /// The current world serialized in the server's format, without the length prefix
+    pub fn encode_frame(&self) -> Vec<u8> {
+        match self.format {
+            FrameFormat::Json => self.world.to_tilemap_json().into_bytes(),
+            FrameFormat::Binary => self.world.to_frame_bytes(),
+        }
+    }
+    
+    fn send_frame(&self, writer: &mut impl Write) -> io::Result<()> {
+        let frame = self.encode_frame();
+        writer.write_all(&(frame.len() as u32).to_be_bytes())?;
+        writer.write_all(&frame)?;
+        writer.flush()
+    }
+    
+    /// Serve one client over any reader/writer pair, e.g. stdin/stdout or a socket.
+    /// Returns when the client quits or disconnects.
+    pub fn serve(&mut self, reader: impl BufRead, mut writer: impl Write, pacing: FramePacing) -> io::Result<()> {
+        match pacing {
+            FramePacing::OnDemand => {
+                for line in reader.lines() {
+                    let line = line?;
+                    let mut words = line.split_whitespace();
+                    match words.next() {
+                        Some("next") => {
+                            let ticks: u64 = match words.next() {
+                                Some(count) => count.parse().map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, format!("Invalid tick count: {}", count)))?,
+                                None => 1,
+                            };
+                            for _ in 0..ticks {
+                                self.world.update();
+                            }
+                            self.send_frame(&mut writer)?;
+                        }
+                        Some("frame") => self.send_frame(&mut writer)?,
+                        Some("quit") => return Ok(()),
+                        Some(command) => return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("Unknown command: {}", command))),
+                        None => {} // Blank lines are ignored
+                    }
+                }
+                Ok(())
+            }
+            FramePacing::Interval(interval) => loop {
+                match self.send_frame(&mut writer) {
+                    Err(err) if matches!(err.kind(), io::ErrorKind::BrokenPipe | io::ErrorKind::ConnectionReset) => return Ok(()),
+                    result => result?,
+                }
+                thread::sleep(interval);
+                self.world.update();
+            },
+        }
+    }
+    
+    /// Accept TCP clients one at a time, serving each until it disconnects.
+    /// The world keeps its state between clients. `on_event` hears the bound address
+    /// once, then every connection that can't be accepted and every client session that fails;
+    /// listening carries on past those.
+    pub fn listen(&mut self, addr: impl ToSocketAddrs, pacing: FramePacing, mut on_event: impl FnMut(ListenEvent)) -> io::Result<()> {
+        let listener = TcpListener::bind(addr)?;
+        on_event(ListenEvent::Listening(listener.local_addr()?));
+        loop {
+            let (stream, peer) = match listener.accept() {
+                Ok(client) => client,
+                Err(err) => {
+                    on_event(ListenEvent::AcceptFailed(err));
+                    continue;
+                }
+            };
+            let session = stream.try_clone().and_then(|reader| self.serve(BufReader::new(reader), stream, pacing));
+            if let Err(err) = session {
+                on_event(ListenEvent::ClientFailed(peer, err));
+            }
+        }
+    }
+}
@@ -1,5 +1,8 @@
+use std::collections::HashMap;
+use std::io;
 use rand::Rng;
 use ratatui::style::Color;
+use crate::save::invalid_data;
 
 #[derive(Clone, Copy, PartialEq)]
 pub enum Season {
@@ -9,7 +12,7 @@ pub enum Season {
     Winter = 3, // Cold season - low temperature, variable humidity
 }
 
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Biome {
     Wetland,    // High moisture retention, frequent pools, lush plant growth
     Grassland,  // Balanced moisture, moderate plant density
@@ -17,11 +20,327 @@ pub enum Biome {
     Woodland,   // Dense plant growth, high nutrient content, mixed terrain
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SoilLayer {
+    Topsoil,    // Loose, nutrient-rich, soaks water quickly
+    Subsoil,    // Denser dirt and clay, fewer nutrients
+    ParentRock, // Rock and clay at depth, nearly impermeable
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WindMode {
+    Seasonal,                                       // Wind follows season, weather, and slow natural variation
+    Deterministic { direction: f32, strength: f32 }, // Wind pinned to a constant, for reproducible runs
+}
+
+/// What happens at the east and west borders; the floor and ceiling are solid either way
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum EdgeBehavior {
+    Absorb,  // Anything crossing the border leaves the world
+    #[default]
+    Reflect, // Bounce back off the border
+    Wrap,    // Reappear on the opposite side
+}
+
+/// Which of the simulation's systems `World::update` runs, all of them by default. Switching
+/// one off isolates the rest, e.g. plants without wind dispersal, or water settling with no life
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SystemFlags(u8);
+
+impl SystemFlags {
+    pub const RAIN: SystemFlags = SystemFlags(1);           // Rain and snowfall
+    pub const PHYSICS: SystemFlags = SystemFlags(1 << 1);   // Water, sand, snow, ice, and fire
+    pub const GRAVITY: SystemFlags = SystemFlags(1 << 2);   // Falling particles and seeds in flight
+    pub const WIND: SystemFlags = SystemFlags(1 << 3);      // Particles and seeds blown about
+    pub const NUTRIENTS: SystemFlags = SystemFlags(1 << 4); // Nutrients spreading through soil and water
+    pub const LIFE: SystemFlags = SystemFlags(1 << 5);      // Plants, pillbugs, beetles, and the respawner
+    pub const NONE: SystemFlags = SystemFlags(0);
+    pub const ALL: SystemFlags = SystemFlags((1 << 6) - 1);
+    
+    /// Every system with its name, in the order `update` runs them
+    pub const SYSTEMS: [(SystemFlags, &'static str); 6] = [
+        (SystemFlags::RAIN, "rain"),
+        (SystemFlags::PHYSICS, "physics"),
+        (SystemFlags::GRAVITY, "gravity"),
+        (SystemFlags::WIND, "wind"),
+        (SystemFlags::NUTRIENTS, "nutrients"),
+        (SystemFlags::LIFE, "life"),
+    ];
+    
+    /// Whether every system in `other` is on
+    pub fn contains(self, other: SystemFlags) -> bool {
+        self.0 & other.0 == other.0
+    }
+    
+    /// Switch the systems in `other` on or off
+    pub fn set(&mut self, other: SystemFlags, enabled: bool) {
+        if enabled {
+            self.0 |= other.0;
+        } else {
+            self.0 &= !other.0;
+        }
+    }
+    
+    /// Flip the systems in `other`
+    pub fn toggle(&mut self, other: SystemFlags) {
+        self.0 ^= other.0;
+    }
+    
+    pub fn bits(self) -> u8 {
+        self.0
+    }
+    
+    /// Flags from their bits, dropping any that name no system
+    pub fn from_bits_truncate(bits: u8) -> SystemFlags {
+        SystemFlags(bits & SystemFlags::ALL.0)
+    }
+    
+    /// Names of the systems that are off, in update order
+    pub fn disabled_names(self) -> Vec<&'static str> {
+        SystemFlags::SYSTEMS.iter().filter(|(flag, _)| !self.contains(*flag)).map(|&(_, name)| name).collect()
+    }
+}
+
+impl Default for SystemFlags {
+    fn default() -> Self {
+        SystemFlags::ALL
+    }
+}
+
+impl std::ops::BitOr for SystemFlags {
+    type Output = SystemFlags;
+    
+    fn bitor(self, other: SystemFlags) -> SystemFlags {
+        SystemFlags(self.0 | other.0)
+    }
+}
+
+// How tiles are colored on screen
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum RenderPalette {
+    #[default]
+    Default,      // Natural shades that fade with age and vary with size
+    HighContrast, // One flat, strongly distinct color per kind of tile
+    Monochrome,   // Four shades of gray, for terminals with few colors
+}
+
+impl RenderPalette {
+    /// The palette after this one, wrapping back to the first
+    pub fn next(self) -> RenderPalette {
+        match self {
+            RenderPalette::Default => RenderPalette::HighContrast,
+            RenderPalette::HighContrast => RenderPalette::Monochrome,
+            RenderPalette::Monochrome => RenderPalette::Default,
+        }
+    }
+    
+    pub fn name(self) -> &'static str {
+        match self {
+            RenderPalette::Default => "Default",
+            RenderPalette::HighContrast => "High contrast",
+            RenderPalette::Monochrome => "Monochrome",
+        }
+    }
+}
+
+/// Tile colors chosen by the user, keyed by the tile kind's name in `TileType::NAMES`
+pub type ColorTheme = HashMap<&'static str, Color>;
+
+/// Read a color theme, one `name=R,G,B` per line naming a tile kind as `TileType::NAMES` does,
+/// e.g. `plant_leaf=40,180,60`. Blank lines and lines starting with `#` are skipped
+pub fn parse_color_theme(text: &str) -> io::Result<ColorTheme> {
+    let mut theme = ColorTheme::new();
+    for (index, line) in text.lines().map(str::trim).enumerate() {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (name, rgb) = line.split_once('=')
+            .ok_or_else(|| invalid_data(format!("Line {} is not name=R,G,B", index + 1)))?;
+        let name = TileType::NAMES.iter().find(|&&known| known == name.trim())
+            .ok_or_else(|| invalid_data(format!("Unknown tile '{}' on line {}", name.trim(), index + 1)))?;
+        let channels: Vec<u8> = rgb.split(',').map(|channel| channel.trim().parse()).collect::<Result<_, _>>()
+            .ok()
+            .filter(|channels: &Vec<u8>| channels.len() == 3)
+            .ok_or_else(|| invalid_data(format!("Color '{}' on line {} is not R,G,B from 0 to 255", rgb.trim(), index + 1)))?;
+        theme.insert(name, Color::Rgb(channels[0], channels[1], channels[2]));
+    }
+    Ok(theme)
+}
+
+/// Red, green, and blue of a tile color, reading named colors as their full-strength RGB
+fn rgb_of(color: Color) -> (u8, u8, u8) {
+    match color {
+        Color::Rgb(red, green, blue) => (red, green, blue),
+        Color::Black => (0, 0, 0),
+        Color::Yellow => (255, 255, 0),
+        Color::Magenta => (255, 0, 255),
+        Color::Blue => (0, 0, 255),
+        Color::Green => (0, 255, 0),
+        _ => (255, 255, 255),
+    }
+}
+
+/// The nearest of black, dark gray, gray, and white to a color's brightness
+fn grayscale(color: Color) -> Color {
+    let (red, green, blue) = rgb_of(color);
+    let luma = 0.299 * red as f32 + 0.587 * green as f32 + 0.114 * blue as f32;
+    match luma as u8 {
+        0..=39 => Color::Black,
+        40..=99 => Color::DarkGray,
+        100..=169 => Color::Gray,
+        _ => Color::White,
+    }
+}
+
+/// Mix `amount` (0.0 to 1.0) of `toward` into `color`, as an RGB color
+pub fn blend(color: Color, toward: Color, amount: f32) -> Color {
+    let amount = amount.clamp(0.0, 1.0);
+    let ((red, green, blue), (to_red, to_green, to_blue)) = (rgb_of(color), rgb_of(toward));
+    let mix = |from: u8, to: u8| (from as f32 + (to as f32 - from as f32) * amount).round() as u8;
+    Color::Rgb(mix(red, to_red), mix(green, to_green), mix(blue, to_blue))
+}
+
+// Heritable plant traits, passed from flower to seed with a little mutation each generation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlantGenome {
+    pub growth_speed: f32,       // 0.5-1.5 multiplier on growth
+    pub drought_tolerance: f32,  // 0.0-1.0, thrives in dry ground but grows slower in wet ground
+    pub disease_resistance: f32, // 0.0-1.0, chance to shrug off infection at a small cost to growth
+}
+
+impl Default for PlantGenome {
+    fn default() -> Self {
+        PlantGenome {
+            growth_speed: 1.0,
+            drought_tolerance: 0.0,
+            disease_resistance: 0.0,
+        }
+    }
+}
+
+impl PlantGenome {
+    /// Copy of this genome with each trait nudged a little at random, as passed to a seed
+    pub fn mutate(&self, rng: &mut impl Rng) -> PlantGenome {
+        let step = 0.05;
+        PlantGenome {
+            growth_speed: (self.growth_speed + rng.gen_range(-step..=step)).clamp(0.5, 1.5),
+            drought_tolerance: (self.drought_tolerance + rng.gen_range(-step..=step)).clamp(0.0, 1.0),
+            disease_resistance: (self.disease_resistance + rng.gen_range(-step..=step)).clamp(0.0, 1.0),
+        }
+    }
+}
+
+// Growth form of a plant, fixed when its seed germinates by the biome it sprouts in
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PlantSpecies {
+    Grass,  // Grassland: the baseline form
+    Shrub,  // Wetland: low and leafy
+    Tree,   // Woodland: tall trunk that branches widely
+    Cactus, // Drylands: tall, narrow, few leaves
+}
+
+impl PlantSpecies {
+    pub const ALL: [PlantSpecies; 4] = [PlantSpecies::Grass, PlantSpecies::Shrub, PlantSpecies::Tree, PlantSpecies::Cactus];
+    
+    /// Species a seed germinating in the biome grows into
+    pub fn for_biome(biome: Biome) -> PlantSpecies {
+        match biome {
+            Biome::Grassland => PlantSpecies::Grass,
+            Biome::Wetland => PlantSpecies::Shrub,
+            Biome::Woodland => PlantSpecies::Tree,
+            Biome::Drylands => PlantSpecies::Cactus,
+        }
+    }
+    
+    pub fn name(self) -> &'static str {
+        match self {
+            PlantSpecies::Grass => "grass",
+            PlantSpecies::Shrub => "shrub",
+            PlantSpecies::Tree => "tree",
+            PlantSpecies::Cactus => "cactus",
+        }
+    }
+    
+    /// Chance a growing stem extends upward
+    pub fn stem_extend_chance(self) -> f64 {
+        match self {
+            PlantSpecies::Grass => 0.3,
+            PlantSpecies::Shrub => 0.15, // Stays low
+            PlantSpecies::Tree => 0.4,
+            PlantSpecies::Cactus => 0.6, // Shoots straight up
+        }
+    }
+    
+    /// Chance a growing stem puts out a leaf on a free side
+    pub fn leaf_chance(self) -> f64 {
+        match self {
+            PlantSpecies::Grass => 0.4,
+            PlantSpecies::Shrub => 0.6,   // Bushy
+            PlantSpecies::Tree => 0.3,
+            PlantSpecies::Cactus => 0.05, // Narrow column
+        }
+    }
+    
+    /// Share of maturing buds that become branches rather than flowers
+    pub fn branch_share(self) -> f64 {
+        match self {
+            PlantSpecies::Grass => 0.6,
+            PlantSpecies::Shrub => 0.5,
+            PlantSpecies::Tree => 0.85,  // Spreading crown
+            PlantSpecies::Cactus => 0.1, // Mostly flowers at the top
+        }
+    }
+    
+    /// Multiplier on how often branches extend
+    pub fn branch_spread(self) -> f32 {
+        match self {
+            PlantSpecies::Grass => 1.0,
+            PlantSpecies::Shrub => 0.8,
+            PlantSpecies::Tree => 2.0,
+            PlantSpecies::Cactus => 0.3,
+        }
+    }
+    
+    /// Species glyph for a stem or leaf, other parts keep the tile's own character
+    pub fn to_char_modifier(self, tile: TileType, base_char: char) -> char {
+        match (self, tile) {
+            (PlantSpecies::Shrub, TileType::PlantStem(_, _)) => '}',
+            (PlantSpecies::Shrub, TileType::PlantLeaf(_, _)) => '♣',
+            (PlantSpecies::Tree, TileType::PlantStem(_, _)) => 'T',
+            (PlantSpecies::Tree, TileType::PlantLeaf(_, _)) => '♠',
+            (PlantSpecies::Cactus, TileType::PlantStem(_, _)) => '‡',
+            (PlantSpecies::Cactus, TileType::PlantLeaf(_, _)) => '†',
+            _ => base_char,
+        }
+    }
+    
+    /// Species tint over the tile's color: shrubs bluish, trees deep green, cacti pale sage
+    pub fn tint(self, color: Color) -> Color {
+        let (red, green, blue) = match self {
+            PlantSpecies::Grass => return color,
+            PlantSpecies::Shrub => (0.8, 0.9, 1.6),
+            PlantSpecies::Tree => (1.2, 0.75, 0.8),
+            PlantSpecies::Cactus => (2.0, 0.9, 1.8),
+        };
+        match color {
+            Color::Rgb(r, g, b) => Color::Rgb(
+                (r as f32 * red).min(255.0) as u8,
+                (g as f32 * green).min(255.0) as u8,
+                (b as f32 * blue).min(255.0) as u8,
+            ),
+            other => other,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum MovementStrategy {
     SeekFood((i32, i32)),    // Direction to food
     Social((i32, i32)),      // Direction to other pillbugs
     Avoid((i32, i32)),       // Direction away from danger
+    Roll,                    // Curl up in place against a predator
+    Hunt((i32, i32)),        // Direction to prey
+    Trail((i32, i32)),       // Along the pheromone trail other pillbugs laid
     Explore,                 // Random exploration
     Rest,                    // Stay put or minimal movement
 }
@@ -32,11 +351,13 @@ impl MovementStrategy {
             MovementStrategy::SeekFood(direction) => *direction,
             MovementStrategy::Social(direction) => *direction,
             MovementStrategy::Avoid(direction) => *direction,
+            MovementStrategy::Hunt(direction) => *direction,
+            MovementStrategy::Trail(direction) => *direction,
             MovementStrategy::Explore => {
                 let moves = [(-1, 0), (1, 0), (0, -1), (0, 1)];
                 *moves.get(rng.gen_range(0..4)).unwrap()
             },
-            MovementStrategy::Rest => (0, 0),
+            MovementStrategy::Rest | MovementStrategy::Roll => (0, 0),
         }
     }
     
@@ -45,13 +366,16 @@ impl MovementStrategy {
             MovementStrategy::SeekFood(_) => rng.gen_bool(0.8), // High urgency for food
             MovementStrategy::Social(_) => rng.gen_bool(0.4),   // Moderate social movement
             MovementStrategy::Avoid(_) => rng.gen_bool(0.9),    // Very high urgency to avoid
+            MovementStrategy::Hunt(_) => rng.gen_bool(0.85),    // Predators press the chase
+            MovementStrategy::Trail(_) => rng.gen_bool(0.5),    // Purposeful, but no hurry
             MovementStrategy::Explore => rng.gen_bool(0.3),     // Casual exploration
             MovementStrategy::Rest => rng.gen_bool(0.1),        // Very low movement when resting
+            MovementStrategy::Roll => false,                    // A rolled-up bug stays put
         }
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Size {
     Small = 0,   // Faster growth, shorter life, weaker
     Medium = 1,  // Normal values  
@@ -75,6 +399,51 @@ impl Size {
         }
     }
     
+    /// Chance a dormant seed of this size skips aging on a cold tick; big seeds carry enough
+    /// reserves to wait out a whole winter
+    pub fn dormancy_chance(self) -> f32 {
+        match self {
+            Size::Small => 0.5,
+            Size::Medium => 0.8,
+            Size::Large => 1.0,
+        }
+    }
+    
+    /// Size a pillbug molts into next, if it can still grow
+    pub fn molt_size(self) -> Option<Size> {
+        match self {
+            Size::Small => Some(Size::Medium),
+            Size::Medium => Some(Size::Large),
+            Size::Large => None,
+        }
+    }
+    
+    /// Minimum pillbug age before molting out of this size
+    pub fn molt_age(self) -> u8 {
+        match self {
+            Size::Small => 40,
+            Size::Medium => 80,
+            Size::Large => u8::MAX,
+        }
+    }
+    
+    /// Meals a pillbug needs since its last molt before molting out of this size
+    pub fn molt_meals(self) -> u16 {
+        match self {
+            Size::Small => 6,
+            Size::Medium => 12,
+            Size::Large => u16::MAX,
+        }
+    }
+    
+    pub fn name(self) -> &'static str {
+        match self {
+            Size::Small => "small",
+            Size::Medium => "medium",
+            Size::Large => "large",
+        }
+    }
+    
     pub fn to_char_modifier(self, base_char: char) -> char {
         match (self, base_char) {
             (Size::Small, '|') => 'i',    // Small stem
@@ -87,6 +456,8 @@ impl Size {
             (Size::Small, 'w') => 'v',    // Small legs
             (Size::Small, 'r') => '·',    // Small root
             (Size::Small, '?') => '¿',    // Small diseased
+            (Size::Small, '◎') => '◦',    // Small rolled pillbug
+            (Size::Small, '⌙') => 'ɭ',    // Small wilting leaf
             (Size::Large, '|') => '║',    // Large stem
             (Size::Large, 'L') => 'Ł',    // Large leaf
             (Size::Large, 'o') => 'O',    // Large bud
@@ -97,33 +468,52 @@ impl Size {
             (Size::Large, 'w') => 'W',    // Large legs
             (Size::Large, 'r') => 'R',    // Large root
             (Size::Large, '?') => '‽',    // Large diseased
+            (Size::Large, '◎') => '◉',    // Large rolled pillbug
+            (Size::Large, '⌙') => 'Ľ',    // Large wilting leaf
             _ => base_char, // Medium size keeps original char
         }
     }
 }
 
+/// Water quality of fresh rain and snowmelt; decay nearby fouls it toward 0
+pub const CLEAN_WATER: u8 = 255;
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum TileType {
     Empty,
     Dirt,
     NutrientDirt(u8), // Dirt with absorbed nutrients (0-255 nutrient level)
     Sand,
-    Water(u8),        // Water with depth/pressure (0-255), affects flow behavior
+    Clay,             // Dense soil, soaks water slowly and resists roots
+    Rock,             // Parent rock, impermeable and unrootable
+    Water(u8, u8),    // Water with depth/pressure (0-255), affects flow behavior, and quality (0 foul - 255 clean)
     PlantStem(u8, Size),   // Main structural support, age 0-255 (dies at ~100*lifespan_8x), size
     PlantLeaf(u8, Size),   // Photosynthesis organs, age 0-255 (dies at ~50*lifespan_8x), size
     PlantBud(u8, Size),    // Growth points that become branches/flowers, age 0-255 (dies at 50), size
     PlantBranch(u8, Size), // Diagonal growth branches, age 0-255 (dies at ~100*lifespan_8x), size
     PlantFlower(u8, Size), // Reproductive organs, age 0-255 (dies at ~80*lifespan_8x), size
     PlantWithered(u8, Size), // Dying plant part, age 0-30 before becoming nutrient, size
+    PlantWilting(u8, Size),  // Leaf drooping from thirst, ticks wilted so far; recovers with water or withers
     PlantDiseased(u8, Size), // Diseased plant part, spreads to nearby plants, age 0-60, size
     PlantRoot(u8, Size),     // Underground root system for nutrient absorption, age 0-255 (dies at ~200*lifespan_8x), size
     PillbugHead(u8, Size),    // Head segment of pillbug, age 0-255 (dies at ~150*lifespan_8x), size
     PillbugBody(u8, Size),    // Body segment of pillbug, age 0-255 (dies at ~150*lifespan_8x), size
     PillbugLegs(u8, Size),    // Leg segment of pillbug, age 0-255 (dies at ~150*lifespan_8x), size
     PillbugDecaying(u8, Size), // Dying pillbug part, age 0-20 before becoming nutrient, size
+    PillbugRolled(u8, Size),  // Pillbug head curled into a ball, age 0-255, won't move, eat, or be caught until it uncurls
     Nutrient,
     Seed(u8, Size),           // Plant seed that can be dispersed by wind, age 0-255 (dies at 100), size
     Spore(u8),                // Fungal/bacterial spores, age 0-255 (dies at 50), carried by wind
+    Fire(u8),                 // Burning tile, age 0-255 (burns out at FIRE_BURN_TICKS), spreads to dry plant matter
+    Snow(u8),                 // Winter precipitation, holds the water depth (0-255) it melts back into
+    Ice(u8),                  // Frozen water surface, solid to walk on, holds the water depth (0-255) it melts back into
+    BeetleHead(u8, Size),     // Head of a predatory beetle, age 0-255 (starves at 20*lifespan_multiplier), kills set it back, hunts pillbugs
+    BeetleBody(u8, Size),     // Body segment of a beetle, dies with its head, size
+    DepletedDirt,             // Dirt exhausted by roots, grows plants poorly until nutrients diffuse back in
+    Mycelium(u8),             // Fungal threads through the soil, carrying a nutrient load (0-255) between the roots they touch
+    Seedling(u8, Size),       // Freshly sprouted seed, age 0-255 (becomes a stem at SEEDLING_ESTABLISH_TICKS), killed by frost, thirst, or trampling
+    Sediment(u8),             // Sand lifted by flowing water, ticks 0-255 in suspension, settles back into sand where the flow slackens
+    Algae(u8),                // Bloom on the surface of rich water, age 0-255 (dies back into a nutrient at ALGAE_LIFESPAN)
 }
 
 impl TileType {
@@ -133,7 +523,9 @@ impl TileType {
             TileType::Dirt => '#',
             TileType::NutrientDirt(_) => '▓', // Nutrient-rich dirt
             TileType::Sand => '.',
-            TileType::Water(depth) => {
+            TileType::Clay => '%',
+            TileType::Rock => '■',
+            TileType::Water(depth, _) => {
                 match depth {
                     0..=50 => '·',     // Light water/droplets
                     51..=120 => '~',   // Normal water
@@ -147,18 +539,74 @@ impl TileType {
             TileType::PlantBranch(_, size) => size.to_char_modifier('/'), // Diagonal branches
             TileType::PlantFlower(_, size) => size.to_char_modifier('*'),
             TileType::PlantWithered(_, size) => size.to_char_modifier('x'), // Withered plants
+            TileType::PlantWilting(_, size) => size.to_char_modifier('⌙'), // Drooping leaves
             TileType::PlantDiseased(_, size) => size.to_char_modifier('?'), // Diseased plants
             TileType::PlantRoot(_, size) => size.to_char_modifier('r'), // Underground roots
             TileType::PillbugHead(_, size) => size.to_char_modifier('@'),
             TileType::PillbugBody(_, size) => size.to_char_modifier('O'),
             TileType::PillbugLegs(_, size) => size.to_char_modifier('w'),
             TileType::PillbugDecaying(_, size) => size.to_char_modifier('░'), // Decaying pillbugs
+            TileType::PillbugRolled(_, size) => size.to_char_modifier('◎'), // Pillbugs rolled into a ball
             TileType::Nutrient => '+',
             TileType::Seed(_, size) => size.to_char_modifier('o'), // Seeds look like small buds
             TileType::Spore(_) => '∘', // Small spores
+            TileType::Fire(_) => '^',
+            TileType::Snow(_) => '❄',
+            TileType::Ice(_) => '▬',
+            TileType::Mycelium(_) => '┼',
+            TileType::Seedling(_, _) => 'ɩ', // Young sprouts
+            TileType::Sediment(_) => '∴', // Grains adrift in the current
+            TileType::Algae(_) => '≋', // Scum on the water
+            TileType::BeetleHead(_, _) => '&',
+            TileType::BeetleBody(_, _) => '8',
+            TileType::DepletedDirt => ',',
         }
     }
     
+    /// The tile's color in a render palette; `Default` is `to_color`
+    pub fn to_color_with(self, palette: RenderPalette) -> Color {
+        match palette {
+            RenderPalette::Default => self.to_color(),
+            RenderPalette::Monochrome => grayscale(self.to_color()),
+            // Colorblind-safe hues, with plant parts and creatures each on a color of their own
+            // and the ground in muted earth and gray so they stand out against it
+            RenderPalette::HighContrast => match self {
+                TileType::Empty => Color::Black,
+                TileType::Dirt | TileType::DepletedDirt => Color::Rgb(90, 70, 50),
+                TileType::NutrientDirt(_) => Color::Rgb(120, 100, 40),
+                TileType::Clay => Color::Rgb(130, 90, 80),
+                TileType::Sand | TileType::Sediment(_) => Color::Rgb(160, 150, 100),
+                TileType::Rock => Color::Rgb(128, 128, 128),
+                TileType::Water(_, quality) if quality < 128 => Color::Rgb(120, 120, 60), // Foul water
+                TileType::Water(_, _) | TileType::Ice(_) => Color::Rgb(0, 114, 178),
+                TileType::Snow(_) => Color::White,
+                TileType::PlantStem(_, _) => Color::Rgb(240, 240, 240),
+                TileType::PlantBranch(_, _) => Color::Rgb(230, 159, 0),
+                TileType::PlantLeaf(_, _) | TileType::Seedling(_, _) => Color::Rgb(0, 158, 115),
+                TileType::PlantBud(_, _) => Color::Rgb(240, 228, 66),
+                TileType::PlantFlower(_, _) => Color::Rgb(204, 121, 167),
+                TileType::PlantRoot(_, _) => Color::Rgb(220, 190, 150),
+                TileType::PlantWilting(_, _) => Color::Rgb(150, 150, 90),
+                TileType::PlantWithered(_, _) => Color::Rgb(110, 90, 70),
+                TileType::PlantDiseased(_, _) => Color::Rgb(130, 40, 20),
+                TileType::PillbugHead(_, _) | TileType::PillbugRolled(_, _) => Color::Rgb(86, 180, 233),
+                TileType::PillbugBody(_, _) | TileType::PillbugLegs(_, _) => Color::Rgb(50, 130, 200),
+                TileType::PillbugDecaying(_, _) => Color::Rgb(100, 80, 110),
+                TileType::BeetleHead(_, _) | TileType::BeetleBody(_, _) => Color::Rgb(255, 40, 40),
+                TileType::Nutrient | TileType::Mycelium(_) => Color::Magenta,
+                TileType::Seed(_, _) => Color::Rgb(255, 200, 120),
+                TileType::Spore(_) => Color::Rgb(170, 170, 170),
+                TileType::Fire(_) => Color::Rgb(255, 120, 0),
+                TileType::Algae(_) => Color::Rgb(150, 210, 40),
+            },
+        }
+    }
+    
+    /// The tile's color in a theme, falling back to `to_color` for kinds the theme leaves out
+    pub fn to_color_themed(self, theme: &ColorTheme) -> Color {
+        theme.get(self.name()).copied().unwrap_or_else(|| self.to_color())
+    }
+    
     pub fn to_color(self) -> Color {
         match self {
             TileType::Empty => Color::Black,
@@ -172,14 +620,22 @@ impl TileType {
                 Color::Rgb(red, green, blue)
             },
             TileType::Sand => Color::Yellow,
-            TileType::Water(depth) => {
-                let _intensity = (depth as u16 * 255 / 255).min(255) as u8;
-                match depth {
-                    0..=50 => Color::Rgb(180, 220, 255),      // Light blue droplets
-                    51..=120 => Color::Rgb(64, 164, 255),     // Normal blue water
-                    121..=200 => Color::Rgb(0, 100, 200),     // Deep blue water
-                    _ => Color::Rgb(0, 50, 150),              // Very deep dark blue
-                }
+            TileType::Sediment(_) => Color::Rgb(190, 170, 90), // Sand clouding the water
+            TileType::Algae(_) => Color::Rgb(80, 160, 50), // Bright green scum
+            TileType::Clay => Color::Rgb(160, 95, 60),
+            TileType::DepletedDirt => Color::Rgb(140, 120, 95), // Pale, washed-out dirt
+            TileType::Rock => Color::Rgb(110, 110, 115),
+            TileType::Water(depth, quality) => {
+                let (red, green, blue) = match depth {
+                    0..=50 => (180, 220, 255),      // Light blue droplets
+                    51..=120 => (64, 164, 255),     // Normal blue water
+                    121..=200 => (0, 100, 200),     // Deep blue water
+                    _ => (0, 50, 150),              // Very deep dark blue
+                };
+                // Foul water turns murky green-brown
+                let foul = 1.0 - quality as f32 / 255.0;
+                let blend = |clean: u8, murky: u8| (clean as f32 + (murky as f32 - clean as f32) * foul) as u8;
+                Color::Rgb(blend(red, 90), blend(green, 100), blend(blue, 40))
             },
             TileType::PlantStem(age, size) => {
                 let base_intensity = (255u16.saturating_sub(age as u16)).max(80) as u8;
@@ -247,6 +703,17 @@ impl TileType {
                 let intensity = (base_intensity as f32 * size_boost).min(255.0) as u8;
                 Color::Rgb(intensity, intensity / 2, 0) // Brown withered color
             },
+            TileType::PlantWilting(ticks, size) => {
+                let wilt_progress = (ticks as f32 / 40.0).min(1.0); // 0.0 = just drooping, 1.0 = about to wither
+                let green = 130.0 - wilt_progress * 50.0; // Green drains toward straw
+                let size_boost = match size {
+                    Size::Small => 0.85,
+                    Size::Medium => 1.0,
+                    Size::Large => 1.15,
+                };
+                let green = (green * size_boost).min(255.0) as u8;
+                Color::Rgb(green / 2 + 40, green, green / 4) // Dull olive wilting leaves
+            },
             TileType::PlantDiseased(age, size) => {
                 let disease_progress = age as f32 / 60.0; // 0.0 = fresh infection, 1.0 = full disease
                 let base_red = (100.0 + disease_progress * 155.0) as u8; // Red intensifies with disease
@@ -311,6 +778,16 @@ impl TileType {
                 let intensity = (base_intensity as f32 * size_boost).min(255.0) as u8;
                 Color::Rgb(intensity, intensity / 3, intensity / 2) // Dark brownish-red decay color
             },
+            TileType::PillbugRolled(age, size) => {
+                let base_intensity = (160u16.saturating_sub(age as u16)).max(60) as u8;
+                let size_boost = match size {
+                    Size::Small => 0.8,
+                    Size::Medium => 1.0,
+                    Size::Large => 1.2,
+                };
+                let intensity = (base_intensity as f32 * size_boost).min(255.0) as u8;
+                Color::Rgb(intensity, intensity.saturating_sub(10), intensity.saturating_sub(30)) // Dull armored shell
+            },
             TileType::Nutrient => Color::Magenta,
             TileType::Seed(age, size) => {
                 let vitality = (100u16.saturating_sub(age as u16)).max(50) as u8;
@@ -328,33 +805,72 @@ impl TileType {
                 let vitality = (50u16.saturating_sub(age as u16)).max(20) as u8;
                 Color::Rgb(vitality, vitality / 2, vitality / 3) // Fading brownish spores
             },
+            TileType::Fire(age) => {
+                let heat = 255u8.saturating_sub(age.saturating_mul(12));
+                Color::Rgb(255, heat.max(60) / 2 + 40, 0) // Bright orange cooling to deep red
+            },
+            TileType::Snow(_) => Color::White,
+            TileType::Ice(_) => Color::Rgb(170, 220, 240), // Pale blue sheet
+            TileType::Mycelium(load) => {
+                let fed = load / 4;
+                Color::Rgb(150 + fed / 2, 130 + fed / 2, 100 + fed / 3) // Pale threads, creamier as they carry more
+            },
+            TileType::Seedling(age, _) => {
+                let pale = 90u8.saturating_sub(age.saturating_mul(3));
+                Color::Rgb(60 + pale, 200, 60 + pale / 2) // Pale green, deepening as it establishes
+            },
+            TileType::BeetleHead(_, size) | TileType::BeetleBody(_, size) => {
+                let shade = match size {
+                    Size::Small => 150,
+                    Size::Medium => 180,
+                    Size::Large => 210,
+                };
+                Color::Rgb(shade, 30, 40) // Crimson shell, brighter for bigger beetles
+            },
         }
     }
     
     pub fn is_plant(self) -> bool {
-        matches!(self, TileType::PlantStem(_, _) | TileType::PlantLeaf(_, _) | TileType::PlantBud(_, _) | TileType::PlantBranch(_, _) | TileType::PlantFlower(_, _) | TileType::PlantWithered(_, _) | TileType::PlantDiseased(_, _) | TileType::PlantRoot(_, _))
+        matches!(self, TileType::PlantStem(_, _) | TileType::PlantLeaf(_, _) | TileType::PlantBud(_, _) | TileType::PlantBranch(_, _) | TileType::PlantFlower(_, _) | TileType::PlantWithered(_, _) | TileType::PlantDiseased(_, _) | TileType::PlantRoot(_, _) | TileType::PlantWilting(_, _) | TileType::Seedling(_, _))
     }
     
     pub fn is_pillbug(self) -> bool {
-        matches!(self, TileType::PillbugHead(_, _) | TileType::PillbugBody(_, _) | TileType::PillbugLegs(_, _) | TileType::PillbugDecaying(_, _))
+        matches!(self, TileType::PillbugHead(_, _) | TileType::PillbugBody(_, _) | TileType::PillbugLegs(_, _) | TileType::PillbugDecaying(_, _) | TileType::PillbugRolled(_, _))
+    }
+    
+    pub fn is_beetle(self) -> bool {
+        matches!(self, TileType::BeetleHead(_, _) | TileType::BeetleBody(_, _))
     }
     
     pub fn get_size(self) -> Option<Size> {
         match self {
             TileType::PlantStem(_, size) | TileType::PlantLeaf(_, size) | 
             TileType::PlantBud(_, size) | TileType::PlantBranch(_, size) | TileType::PlantFlower(_, size) | TileType::PlantWithered(_, size) | TileType::PlantDiseased(_, size) | TileType::PlantRoot(_, size) |
-            TileType::PillbugHead(_, size) | TileType::PillbugBody(_, size) | TileType::PillbugLegs(_, size) | TileType::PillbugDecaying(_, size) => Some(size),
+            TileType::PlantWilting(_, size) | TileType::Seedling(_, size) | TileType::PillbugHead(_, size) | TileType::PillbugBody(_, size) | TileType::PillbugLegs(_, size) | TileType::PillbugDecaying(_, size) |
+            TileType::PillbugRolled(_, size) | TileType::BeetleHead(_, size) | TileType::BeetleBody(_, size) => Some(size),
             _ => None,
         }
     }
     
+    /// Dry plant matter and spores that catch fire from a neighbouring flame
+    pub fn is_flammable(self) -> bool {
+        matches!(self, TileType::PlantStem(_, _) | TileType::PlantLeaf(_, _) | TileType::PlantBranch(_, _) | TileType::PlantWithered(_, _) | TileType::PlantWilting(_, _) | TileType::Seedling(_, _) | TileType::Seed(_, _) | TileType::Spore(_))
+    }
+    
     pub fn is_water(self) -> bool {
-        matches!(self, TileType::Water(_))
+        matches!(self, TileType::Water(_, _))
     }
     
     pub fn get_water_depth(self) -> Option<u8> {
         match self {
-            TileType::Water(depth) => Some(depth),
+            TileType::Water(depth, _) => Some(depth),
+            _ => None,
+        }
+    }
+    
+    pub fn get_water_quality(self) -> Option<u8> {
+        match self {
+            TileType::Water(_, quality) => Some(quality),
             _ => None,
         }
     }
@@ -364,7 +880,7 @@ impl TileType {
     }
     
     pub fn blocks_water(self) -> bool {
-        !matches!(self, TileType::Empty | TileType::Water(_))
+        !matches!(self, TileType::Empty | TileType::Water(_, _))
     }
     
     pub fn is_wind_dispersible(self) -> bool {
@@ -372,19 +888,250 @@ impl TileType {
     }
     
     pub fn is_light_particle(self) -> bool {
-        matches!(self, TileType::Seed(_, Size::Small) | TileType::Spore(_) | TileType::Nutrient | TileType::Water(0..=30))
+        matches!(self, TileType::Seed(_, Size::Small) | TileType::Spore(_) | TileType::Nutrient | TileType::Water(0..=30, _))
     }
     
     pub fn is_soil(self) -> bool {
-        matches!(self, TileType::Dirt | TileType::NutrientDirt(_))
+        matches!(self, TileType::Dirt | TileType::NutrientDirt(_) | TileType::DepletedDirt | TileType::Mycelium(_))
     }
     
+    /// Footing a plant or creature can rest on without falling: loose earth, plus ice
     pub fn can_support_plants(self) -> bool {
-        matches!(self, TileType::Dirt | TileType::NutrientDirt(_) | TileType::Sand)
+        self.is_earth() || matches!(self, TileType::Ice(_))
+    }
+    
+    /// Loose earth that soaks up standing water, takes roots, and lets seeds sprout
+    pub fn is_earth(self) -> bool {
+        matches!(self, TileType::Dirt | TileType::NutrientDirt(_) | TileType::DepletedDirt | TileType::Mycelium(_) | TileType::Sand | TileType::Clay)
+    }
+    
+    /// Ground material that belongs to a soil stratum
+    pub fn is_ground(self) -> bool {
+        matches!(self, TileType::Dirt | TileType::NutrientDirt(_) | TileType::DepletedDirt | TileType::Mycelium(_) | TileType::Sand | TileType::Clay | TileType::Rock)
+    }
+    
+    /// Names of the tile kinds, indexed by `tile_id`
+    pub const NAMES: [&'static str; 34] = [
+        "empty", "dirt", "nutrient_dirt", "sand", "water",
+        "plant_stem", "plant_leaf", "plant_bud", "plant_branch", "plant_flower",
+        "plant_withered", "plant_diseased", "plant_root",
+        "pillbug_head", "pillbug_body", "pillbug_legs", "pillbug_decaying",
+        "nutrient", "seed", "spore", "clay", "rock", "fire", "snow",
+        "beetle_head", "beetle_body", "depleted_dirt", "pillbug_rolled", "plant_wilting", "ice", "mycelium", "seedling", "sediment", "algae",
+    ];
+    
+    /// Name of the tile kind, as listed in `NAMES`
+    pub fn name(self) -> &'static str {
+        Self::NAMES[self.tile_id() as usize]
+    }
+    
+    /// Stable numeric id of the tile kind, ignoring age/size/depth
+    pub fn tile_id(self) -> u8 {
+        match self {
+            TileType::Empty => 0,
+            TileType::Dirt => 1,
+            TileType::NutrientDirt(_) => 2,
+            TileType::Sand => 3,
+            TileType::Water(_, _) => 4,
+            TileType::PlantStem(_, _) => 5,
+            TileType::PlantLeaf(_, _) => 6,
+            TileType::PlantBud(_, _) => 7,
+            TileType::PlantBranch(_, _) => 8,
+            TileType::PlantFlower(_, _) => 9,
+            TileType::PlantWithered(_, _) => 10,
+            TileType::PlantDiseased(_, _) => 11,
+            TileType::PlantRoot(_, _) => 12,
+            TileType::PillbugHead(_, _) => 13,
+            TileType::PillbugBody(_, _) => 14,
+            TileType::PillbugLegs(_, _) => 15,
+            TileType::PillbugDecaying(_, _) => 16,
+            TileType::Nutrient => 17,
+            TileType::Seed(_, _) => 18,
+            TileType::Spore(_) => 19,
+            TileType::Clay => 20,
+            TileType::Rock => 21,
+            TileType::Fire(_) => 22,
+            TileType::Snow(_) => 23,
+            TileType::BeetleHead(_, _) => 24,
+            TileType::BeetleBody(_, _) => 25,
+            TileType::DepletedDirt => 26,
+            TileType::PillbugRolled(_, _) => 27,
+            TileType::PlantWilting(_, _) => 28,
+            TileType::Ice(_) => 29,
+            TileType::Mycelium(_) => 30,
+            TileType::Seedling(_, _) => 31,
+            TileType::Sediment(_) => 32,
+            TileType::Algae(_) => 33,
+        }
+    }
+    
+    /// The tile's byte of state: age, water depth, or nutrient level
+    pub fn value(self) -> u8 {
+        match self {
+            TileType::NutrientDirt(v) | TileType::Water(v, _) | TileType::Spore(v) |
+            TileType::PlantStem(v, _) | TileType::PlantLeaf(v, _) | TileType::PlantBud(v, _) | TileType::PlantBranch(v, _) |
+            TileType::PlantFlower(v, _) | TileType::PlantWithered(v, _) | TileType::PlantDiseased(v, _) | TileType::PlantRoot(v, _) |
+            TileType::PillbugHead(v, _) | TileType::PillbugBody(v, _) | TileType::PillbugLegs(v, _) | TileType::PillbugDecaying(v, _) |
+            TileType::PillbugRolled(v, _) | TileType::PlantWilting(v, _) | TileType::Seed(v, _) | TileType::Fire(v) | TileType::Snow(v) | TileType::Ice(v) | TileType::Mycelium(v) | TileType::Seedling(v, _) | TileType::Sediment(v) | TileType::Algae(v) | TileType::BeetleHead(v, _) | TileType::BeetleBody(v, _) => v,
+            TileType::Empty | TileType::Dirt | TileType::Sand | TileType::Clay | TileType::Rock | TileType::Nutrient | TileType::DepletedDirt => 0,
+        }
+    }
+    
+    /// Rebuild a tile from its id, value byte, and size; `None` if the id is unknown
+    /// or the tile kind needs a size and none was given. Water comes back as `CLEAN_WATER`
+    pub fn from_parts(id: u8, value: u8, size: Option<Size>) -> Option<TileType> {
+        let tile = match (id, size) {
+            (0, _) => TileType::Empty,
+            (1, _) => TileType::Dirt,
+            (2, _) => TileType::NutrientDirt(value),
+            (3, _) => TileType::Sand,
+            (4, _) => TileType::Water(value, CLEAN_WATER),
+            (5, Some(size)) => TileType::PlantStem(value, size),
+            (6, Some(size)) => TileType::PlantLeaf(value, size),
+            (7, Some(size)) => TileType::PlantBud(value, size),
+            (8, Some(size)) => TileType::PlantBranch(value, size),
+            (9, Some(size)) => TileType::PlantFlower(value, size),
+            (10, Some(size)) => TileType::PlantWithered(value, size),
+            (11, Some(size)) => TileType::PlantDiseased(value, size),
+            (12, Some(size)) => TileType::PlantRoot(value, size),
+            (13, Some(size)) => TileType::PillbugHead(value, size),
+            (14, Some(size)) => TileType::PillbugBody(value, size),
+            (15, Some(size)) => TileType::PillbugLegs(value, size),
+            (16, Some(size)) => TileType::PillbugDecaying(value, size),
+            (17, _) => TileType::Nutrient,
+            (18, Some(size)) => TileType::Seed(value, size),
+            (19, _) => TileType::Spore(value),
+            (20, _) => TileType::Clay,
+            (21, _) => TileType::Rock,
+            (22, _) => TileType::Fire(value),
+            (23, _) => TileType::Snow(value),
+            (24, Some(size)) => TileType::BeetleHead(value, size),
+            (25, Some(size)) => TileType::BeetleBody(value, size),
+            (26, _) => TileType::DepletedDirt,
+            (27, Some(size)) => TileType::PillbugRolled(value, size),
+            (28, Some(size)) => TileType::PlantWilting(value, size),
+            (29, _) => TileType::Ice(value),
+            (30, _) => TileType::Mycelium(value),
+            (31, Some(size)) => TileType::Seedling(value, size),
+            (32, _) => TileType::Sediment(value),
+            (33, _) => TileType::Algae(value),
+            _ => return None,
+        };
+        Some(tile)
+    }
+    
+    /// One tile of every kind, in `tile_id` order, as the legend shows them: young, medium-sized,
+    /// and, for water, clean and of ordinary depth
+    pub fn all_variants() -> Vec<TileType> {
+        (0..Self::NAMES.len() as u8)
+            .filter_map(|id| TileType::from_parts(id, 20, Some(Size::Medium)))
+            .map(|tile| match tile {
+                TileType::Water(_, quality) => TileType::Water(100, quality),
+                tile => tile,
+            })
+            .collect()
+    }
+    
+    /// The tile's character and color, as the world view draws it, and what it is
+    pub fn legend_entry(self) -> (char, Color, &'static str) {
+        let description = match self {
+            TileType::Empty => "Empty space",
+            TileType::Dirt => "Dirt (solid ground)",
+            TileType::NutrientDirt(_) => "Nutrient-rich dirt (feeds roots)",
+            TileType::DepletedDirt => "Depleted dirt (exhausted by roots)",
+            TileType::Sand => "Sand (falls)",
+            TileType::Sediment(_) => "Sediment (sand carried by flowing water)",
+            TileType::Algae(_) => "Algae (blooms on rich water, chokes it)",
+            TileType::Clay => "Clay (slow to soak and root)",
+            TileType::Rock => "Rock (parent rock)",
+            TileType::Water(_, _) => "Water (flows, fouls near decay)",
+            TileType::PlantStem(_, _) => "Plant Stem",
+            TileType::PlantLeaf(_, _) => "Plant Leaf (photosynthesizes)",
+            TileType::PlantBud(_, _) => "Plant Bud (grows into branch or flower)",
+            TileType::PlantBranch(_, _) => "Plant Branch (Y-shaped growth)",
+            TileType::PlantFlower(_, _) => "Plant Flower (sets seed once pollinated)",
+            TileType::PlantWithered(_, _) => "Plant Withered (gradual decay)",
+            TileType::PlantWilting(_, _) => "Plant Wilting (thirsty, revives with water)",
+            TileType::Seedling(_, _) => "Seedling (fragile, roots into a stem)",
+            TileType::PlantDiseased(_, _) => "Plant Diseased (spreads, may recover)",
+            TileType::PlantRoot(_, _) => "Plant Root (absorbs nutrients and water)",
+            TileType::PillbugHead(_, _) => "Pillbug Head",
+            TileType::PillbugBody(_, _) => "Pillbug Body",
+            TileType::PillbugLegs(_, _) => "Pillbug Legs",
+            TileType::PillbugDecaying(_, _) => "Pillbug Decaying (gradual decay)",
+            TileType::PillbugRolled(_, _) => "Pillbug Rolled (curled up against a threat)",
+            TileType::BeetleHead(_, _) => "Beetle Head (hunts pillbugs)",
+            TileType::BeetleBody(_, _) => "Beetle Body",
+            TileType::Nutrient => "Nutrient (diffuses, feeds plants)",
+            TileType::Seed(_, _) => "Seed (drifts, sprouts in soil)",
+            TileType::Spore(_) => "Spore (carries disease on the wind)",
+            TileType::Fire(_) => "Fire (spreads, water puts it out)",
+            TileType::Snow(_) => "Snow (piles, thaws into water)",
+            TileType::Ice(_) => "Ice (solid, melts in the thaw)",
+            TileType::Mycelium(_) => "Mycelium (feeds starving roots)",
+        };
+        (self.to_char(), self.to_color(), description)
+    }
+}
+
+impl SoilLayer {
+    pub fn name(self) -> &'static str {
+        match self {
+            SoilLayer::Topsoil => "topsoil",
+            SoilLayer::Subsoil => "subsoil",
+            SoilLayer::ParentRock => "parent rock",
+        }
+    }
+    
+    /// How readily the layer soaks up water (multiplier on absorption chance)
+    pub fn water_absorption(self) -> f32 {
+        match self {
+            SoilLayer::Topsoil => 1.0,
+            SoilLayer::Subsoil => 0.6,
+            SoilLayer::ParentRock => 0.2,
+        }
+    }
+    
+    /// How easily roots push into the layer (multiplier on root growth chance)
+    pub fn root_penetration(self) -> f32 {
+        match self {
+            SoilLayer::Topsoil => 1.0,
+            SoilLayer::Subsoil => 0.5,
+            SoilLayer::ParentRock => 0.1,
+        }
+    }
+    
+    /// How well the layer holds nutrients (multiplier on nutrient absorption)
+    pub fn nutrient_modifier(self) -> f32 {
+        match self {
+            SoilLayer::Topsoil => 1.5,
+            SoilLayer::Subsoil => 0.8,
+            SoilLayer::ParentRock => 0.3,
+        }
     }
 }
 
 impl Biome {
+    pub fn name(self) -> &'static str {
+        match self {
+            Biome::Wetland => "wetland",
+            Biome::Grassland => "grassland",
+            Biome::Drylands => "drylands",
+            Biome::Woodland => "woodland",
+        }
+    }
+    
+    /// Biome for a letter of a biome map file: `W`etland, `G`rassland, `D`rylands, or `F`orest for woodland
+    pub fn from_letter(letter: char) -> Option<Biome> {
+        match letter.to_ascii_uppercase() {
+            'W' => Some(Biome::Wetland),
+            'G' => Some(Biome::Grassland),
+            'D' => Some(Biome::Drylands),
+            'F' => Some(Biome::Woodland),
+            _ => None,
+        }
+    }
+    
     /// Moisture retention factor - affects water pooling and evaporation
     pub fn moisture_retention(self) -> f32 {
         match self {
@@ -0,0 +1,49 @@
+use crate::world::World;
+
+/// Per-tick weather policy consulted by `World::update` before the rest of the simulation.
+///
+/// Implementations set `temperature`, `humidity`, `rain_intensity`, and the wind
+/// fields on the world however they like: scripted timelines, recorded climate
+/// data, or the built-in seasonal model. Any `FnMut(&mut World)` closure works too.
+///
+/// Controllers have to be `Send` and `Sync`, like everything else a `World` owns, so a world
+/// can run on a background thread; share state with one through `Arc` and a `Mutex` or an
+/// atomic rather than `Rc` and `Cell`.
+///
+/// ```
+/// use pillbugplants::world::World;
+///
+/// let mut world = World::new(40, 20);
+/// // Drought for 1000 ticks, then a monsoon
+/// world.set_weather_controller(Box::new(|world: &mut World| {
+///     if world.tick <= 1000 {
+///         world.humidity = 0.1;
+///         world.rain_intensity = 0.0;
+///     } else {
+///         world.humidity = 1.0;
+///         world.rain_intensity = 0.8;
+///     }
+/// }));
+/// world.update();
+/// assert_eq!(world.rain_intensity, 0.0);
+/// ```
+pub trait WeatherController: Send + Sync {
+    fn update_weather(&mut self, world: &mut World);
+}
+
+/// The built-in model: seasonal temperature/humidity drift, seasonal wind, and night rain
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SeasonalWeather;
+
+impl WeatherController for SeasonalWeather {
+    fn update_weather(&mut self, world: &mut World) {
+        world.update_seasonal_weather();
+        world.update_rain_cycle();
+    }
+}
+
+impl<F: FnMut(&mut World) + Send + Sync> WeatherController for F {
+    fn update_weather(&mut self, world: &mut World) {
+        self(world)
+    }
+}
@@ -1,49 +1,411 @@
 use std::fmt;
-use std::collections::HashSet;
+use std::io;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 use std::time::{Duration, Instant};
-use rand::{Rng, seq::SliceRandom, prelude::IteratorRandom};
-use crate::types::{TileType, Size, random_size, MovementStrategy, Season, Biome, random_biome};
+use rand::{Rng, SeedableRng, rngs::StdRng, seq::SliceRandom, prelude::IteratorRandom};
+use ratatui::style::Color;
+use crate::config::{PhysicsConfig, DroughtConfig, GrowthConfig, InteractionConfig, ActivityConfig, DiseaseConfig, PillbugConfig, RespawnConfig, WorldConfig, WorldSettings};
+use crate::weather::{WeatherController, SeasonalWeather};
+use crate::save::invalid_data;
+use crate::types::{CLEAN_WATER, TileType, Size, random_size, MovementStrategy, Season, Biome, random_biome, WindMode, SoilLayer, EdgeBehavior, PlantGenome, PlantSpecies, SystemFlags};
 
-// Optimization: Track tile changes without full array clones
-#[derive(Debug)]
-struct TileChange {
-    x: usize,
-    y: usize,
-    old_tile: TileType,
-    new_tile: TileType,
+// Default lengths of a year (spring to spring) and a day (noon to noon) in ticks
+pub const DEFAULT_TICKS_PER_YEAR: u64 = 1000;
+pub const DEFAULT_TICKS_PER_DAY: u64 = 628;
+
+// Batches of sandbox edits kept for undo; the oldest are forgotten past this
+pub const MAX_EDIT_HISTORY: usize = 50;
+// Events kept in the feed; the oldest are forgotten past this
+pub const MAX_EVENTS: usize = 100;
+// Smallest world the simulation is laid out for, with room for sand dunes across it and soil,
+// sky, and starting life down it. Smaller worlds still generate and run, leaving out what
+// doesn't fit, down to a single tile; a world asked for with no width or height gets one
+pub const MIN_WORLD_WIDTH: usize = 11;
+pub const MIN_WORLD_HEIGHT: usize = 15;
+
+// Disturbance left by deaths, trampling, and floods, and how fast it fades per tick
+const DEATH_DISTURBANCE: f32 = 0.5;
+const TRAMPLE_DISTURBANCE: f32 = 0.05;
+const FLOOD_DISTURBANCE: f32 = 0.2;
+const FLOOD_DEPTH: u8 = 150;
+const DISTURBANCE_DECAY: f32 = 0.995;
+// Climax (large) plants only establish on ground calmer than this
+const CLIMAX_MAX_DISTURBANCE: f32 = 0.1;
+
+// Biomes follow the ground they cover: every interval each cell weighs up the water, sand, and
+// plants around it, and changes once its surroundings have favoured another biome for enough checks in a row
+const BIOME_UPDATE_INTERVAL: u64 = 100;
+const BIOME_RADIUS: usize = 3;
+const BIOME_SHIFT_CHECKS: u8 = 5;
+// Shares of the occupied cells around a cell: waterlogged grassland above the first becomes
+// wetland, wetland below the second dries out to grassland, and sand above the third where
+// dead plants outnumber living ones turns the ground to drylands
+const WATERLOGGED_SHARE: f32 = 0.3;
+const DRIED_OUT_SHARE: f32 = 0.05;
+const DRYLANDS_SAND_SHARE: f32 = 0.3;
+
+// Neighbouring water within this many depth units of each other counts as level and doesn't flow
+const WATER_LEVEL_TOLERANCE: u8 = 20;
+// Water deeper than this presses on a one-tile wall of dirt or sand with open air beyond, wearing
+// it down at up to this chance per tick, the deeper the likelier: dirt washes to sand, and sand
+// bursts, letting the water surge through
+const DAM_BURST_DEPTH: u8 = 200;
+const DAM_BURST_CHANCE: f64 = 0.02;
+
+// Water at least this deep that moves at least this much sideways in a tick lifts the sand under
+// it at this chance, sinking into the channel it leaves while the grains ride the current
+const EROSION_DEPTH: u8 = 60;
+const EROSION_FLOW: u8 = 15;
+const EROSION_CHANCE: f64 = 0.1;
+
+// Sediment drifts toward the lower of the water surfaces either side of it at this chance while they
+// differ by more than WATER_LEVEL_TOLERANCE; in slack water it sinks, and on the bottom it settles
+// back into sand at this chance per tick
+const SEDIMENT_CARRY_CHANCE: f64 = 0.6;
+const SEDIMENT_SETTLE_CHANCE: f64 = 0.2;
+// Evaporated water humidifies the air: each depth unit that evaporated last tick, averaged over
+// the columns, adds this much humidity on top of the seasonal drift
+const EVAPORATION_HUMIDITY: f32 = 0.003;
+// The seasonal humidity target shifts by this much per unit of surface water share above or
+// below a typical world's, so lakes keep the air moist and dry ground keeps it dry
+const SURFACE_WATER_HUMIDITY: f32 = 0.3;
+const TYPICAL_SURFACE_WATER: f32 = 0.4;
+// Water touching at least this many decaying tiles is fouled by each of them every tick,
+// and water with no decay around slowly clears
+const POLLUTION_CLUSTER: usize = 2;
+const POLLUTION_PER_DECAY: u8 = 3;
+const WATER_RECOVERY: u8 = 1;
+// Nutrients touching water leach into it at RUNOFF_CHANCE a tick, enriching it, which lowers
+// its quality: a loose nutrient dissolves whole, taking DISSOLVED_NUTRIENT of quality, and dirt
+// richer than RUNOFF_LEVEL gives up NUTRIENT_RUNOFF of its level, taking as much. Water being
+// fed this way doesn't clear
+const RUNOFF_CHANCE: f64 = 0.05;
+const DISSOLVED_NUTRIENT: u8 = 60;
+const RUNOFF_LEVEL: u8 = 100;
+const NUTRIENT_RUNOFF: u8 = 8;
+// Warm standing water enriched below this quality grows algae on its surface at this chance a
+// tick, and the algae spreads across water that rich. Each alga takes ALGAE_UPTAKE of richness
+// back out of the water beneath it a tick, suffocates the roots around that water at
+// ALGAE_SUFFOCATE_CHANCE, and at ALGAE_LIFESPAN, or when the water goes, dies, leaving a
+// nutrient behind at ALGAE_NUTRIENT_RETURN to feed the next bloom
+const ALGAE_BLOOM_QUALITY: u8 = 160;
+const ALGAE_BLOOM_CHANCE: f64 = 0.05;
+const ALGAE_SPREAD_CHANCE: f64 = 0.2;
+const ALGAE_UPTAKE: u8 = 1;
+const ALGAE_SUFFOCATE_CHANCE: f64 = 0.05;
+const ALGAE_LIFESPAN: u8 = 60;
+const ALGAE_NUTRIENT_RETURN: f64 = 0.5;
+// Roots next to water fouler than this absorb poorly and can sicken
+const FOUL_WATER_QUALITY: u8 = 128;
+// A sick plant part needs at least this many healthy parts around it to have a chance of recovering
+const RECOVERY_MIN_HEALTHY: usize = 2;
+// Nutrients roots draw from plain dirt before it's exhausted, and how fast plants grow in exhausted soil
+const DIRT_NUTRIENTS: u8 = 10;
+const DEPLETED_SOIL_GROWTH: f32 = 0.3;
+// Nutrients a nitrogen-fixing root adds to the dirt it enriches
+const NITROGEN_FIX_AMOUNT: u8 = 30;
+// Mycelium creeps into neighbouring dirt this rarely per tick, from its own threads and from the
+// roots that anchor it, and dies back this fast once no living root touches its network
+const MYCELIUM_SPREAD_CHANCE: f64 = 0.01;
+const MYCELIUM_DIEBACK_CHANCE: f64 = 0.05;
+// Mycelium draws this much, this often, from dirt richer than the surplus level, and hands a
+// starving root this much at a time, which sets its age back as absorbing it would
+const MYCELIUM_SURPLUS_LEVEL: u8 = 100;
+const MYCELIUM_UPTAKE: u8 = 10;
+const MYCELIUM_UPTAKE_CHANCE: f64 = 0.1;
+const MYCELIUM_FEED: u32 = 20;
+// Cells around each tile averaged into the nutrient field
+const NUTRIENT_FIELD_RADIUS: usize = 2;
+// Cells around a tile whose plants, pillbugs, beetles, and water `describe_tile` counts
+const DESCRIBE_RADIUS: usize = 3;
+// Roots that drink absorb nutrients this much faster; roots with no water to drink age
+// twice as fast once drought stress passes this level
+const ROOT_DRINK_GROWTH_BONUS: f32 = 1.2;
+const ROOT_DROUGHT_AGING_STRESS: f32 = 0.5;
+// Leaves only wilt below this humidity with no water or nutrient-rich dirt this many cells away,
+// and wither after this many ticks wilted unless water comes back
+const WILT_HUMIDITY: f32 = 0.45;
+const WILT_WATER_RANGE: i32 = 3;
+const WILT_TICKS: u8 = 40;
+// Each leaf, branch, bud, or flower in the few rows straight above a cell blocks this much of the
+// light reaching it; a leaf by day withers at up to this chance per tick as the light fails it
+const SHADE_ROWS: usize = 4;
+const SHADE_PER_CANOPY: f32 = 0.3;
+const SHADE_WITHER_CHANCE: f32 = 0.002;
+// Ticks a flame burns before leaving ash or bare ground
+const FIRE_BURN_TICKS: u8 = 6;
+// Chance per tick for a flame to ignite each flammable neighbour, before weather
+const FIRE_SPREAD_CHANCE: f32 = 0.12;
+// Pillbugs a beetle needs around before one wanders in, and pillbugs it takes to feed each beetle
+const BEETLE_MIN_PREY: usize = 2;
+const PREY_PER_BEETLE: usize = 2;
+// Winter precipitation falls as snow below this temperature
+const SNOW_TEMPERATURE: f32 = -0.2;
+// Seeds lie dormant below this temperature, neither sprouting nor (mostly) aging
+const SEED_DORMANCY_TEMPERATURE: f32 = 0.0;
+// A sprouted seed is a fragile seedling for this many ticks before it roots and grows into a stem;
+// until then each tick frost, parched ground, or a bug beside it can kill it at these chances
+const SEEDLING_ESTABLISH_TICKS: u8 = 30;
+const SEEDLING_FROST_CHANCE: f64 = 0.05;
+const SEEDLING_THIRST_CHANCE: f32 = 0.05;
+const SEEDLING_TRAMPLE_CHANCE: f64 = 0.2;
+// Snow starts melting above this temperature, faster the warmer it gets
+const SNOW_MELT_TEMPERATURE: f32 = 0.0;
+// Standing water open to the air freezes below this temperature and ice melts above the
+// other, each faster the further past it; per tick chance per degree of difference
+const FREEZE_TEMPERATURE: f32 = -0.3;
+const ICE_MELT_TEMPERATURE: f32 = 0.0;
+const FREEZE_RATE: f32 = 0.1;
+const ICE_MELT_RATE: f32 = 0.1;
+// Chance per tick of a gust starting in the autumn, the gustiest season, and the most wind a gust can whip up
+const GUST_CHANCE: f64 = 0.02;
+const MAX_GUST_WIND: f32 = 1.5;
+// Share of the seed drift tallied for `net_dispersal_direction` still counted a tick later, so the
+// tally follows the last hundred or so ticks of flights
+const DISPERSAL_MEMORY: f32 = 0.99;
+// Chance a pillbug fleeing a predator or bigger pillbug rolls into a ball instead, and the ticks it stays rolled
+const ROLL_CHANCE: f64 = 0.5;
+const ROLL_TICKS: u8 = 8;
+// Ticks a freshly molted pillbug's new shell stays soft, unable to roll up, and how many times
+// likelier a beetle is to catch it meanwhile
+const SOFT_SHELL_TICKS: u8 = 20;
+const SOFT_SHELL_CATCH_MULTIPLIER: f64 = 2.0;
+// Chance a moving pillbug picks up a seed beside it, the moves it carries one before it can drop
+// it, and the chance per move after that of dropping it
+const SEED_PICKUP_CHANCE: f64 = 0.5;
+const SEED_CARRY_MOVES: u8 = 6;
+const SEED_DROP_CHANCE: f64 = 0.25;
+// A pillbug with this many others within this many cells is in a cluster: it huddles instead of
+// wandering this often, and keeps moist enough to skip aging this often; a lone pillbug heads for
+// the same-size pillbugs it can see this often
+const CLUSTER_RADIUS: usize = 2;
+const CLUSTER_SIZE: usize = 2;
+const CLUSTER_HUDDLE_CHANCE: f64 = 0.7;
+const CLUSTER_AGING_RELIEF: f64 = 0.3;
+const CLUSTER_SEEK_CHANCE: f64 = 0.8;
+// Pheromone a moving pillbug leaves on the cell it steps into, more with at least this much energy
+// (a bug that has been eating), fading by the decay every tick; an exploring pillbug beside a trail
+// follows it this often, picking among the cells around it in proportion to their pheromone
+const PHEROMONE_DEPOSIT: u8 = 30;
+const PHEROMONE_FED_DEPOSIT: u8 = 90;
+const PHEROMONE_FED_ENERGY: u8 = 75;
+const PHEROMONE_DECAY: u8 = 1;
+const PHEROMONE_FOLLOW_CHANCE: f64 = 0.7;
+// Populations are sampled for `ecosystem_phase` every this many ticks, keeping this many samples;
+// a population down to this fraction of its peak in the window has collapsed, and one whose recent
+// half of samples averages below this fraction of the earlier half is declining
+const PHASE_SAMPLE_INTERVAL: u64 = 10;
+const PHASE_HISTORY_SAMPLES: usize = 50;
+const COLLAPSE_FRACTION: f32 = 0.1;
+const DECLINE_FRACTION: f32 = 0.75;
+// Connected water tiles that make a lake worth reporting in the event feed; one that shrinks
+// below half of it is gone, and the next to grow past it is reported anew
+const LAKE_TILES: usize = 20;
+// Energy a pillbug hatches with and can store, and the energy each point of a meal's nutrition
+// restores; pillbugs burn one energy per tick at their usual metabolism and starve at none
+const PILLBUG_MAX_ENERGY: u8 = 100;
+const PILLBUG_ENERGY_PER_NUTRITION: u8 = 5;
+// Temperature at which pillbugs age, eat, and move at their usual pace (faster when warmer, slower
+// when colder), and below which they hibernate at a crawl
+const PILLBUG_COMFORT_TEMPERATURE: f32 = 0.3;
+const HIBERNATION_TEMPERATURE: f32 = -0.5;
+const HIBERNATION_METABOLISM: f32 = 0.05;
+// Cells a flower looks for another flower's pollen in calm air, and the extra cells each unit of wind carries it
+const POLLINATION_RADIUS: f32 = 3.0;
+const POLLINATION_WIND_RADIUS: f32 = 4.0;
+
+// Quality of two bodies of water poured together, weighted by how much of each there is
+fn mix_quality(depth: u8, quality: u8, other_depth: u8, other_quality: u8) -> u8 {
+    let total = depth as u32 + other_depth as u32;
+    if total == 0 {
+        return quality.min(other_quality);
+    }
+    ((depth as u32 * quality as u32 + other_depth as u32 * other_quality as u32) / total) as u8
+}
+
+// Summed depth of the liquid water among some tiles
+fn total_water_in(tiles: &[Vec<TileType>]) -> u64 {
+    tiles.iter().flatten().filter_map(|tile| tile.get_water_depth()).map(u64::from).sum()
+}
+
+// Water a physics pass took out of the world or brought into it, so the rest is known to be conserved
+#[derive(Default)]
+struct WaterBudget {
+    evaporated: u64,
+    absorbed: u64,
+    melted: u64, // Snow turned to water
+}
+
+/// Compass point (`N`, `NE`, `E`, ... `NW`) nearest a direction in radians, measured like
+/// `wind_direction`: 0 points east and angles turn toward the bottom of the world, which is south
+pub fn compass_point(direction: f32) -> &'static str {
+    const POINTS: [&str; 8] = ["E", "SE", "S", "SW", "W", "NW", "N", "NE"];
+    let eighths = (direction.rem_euclid(2.0 * std::f32::consts::PI) / std::f32::consts::FRAC_PI_4).round() as usize;
+    POINTS[eighths % 8]
+}
+
+/// Read a biome layout, one row of the world per line and one letter per tile as
+/// `Biome::from_letter` reads them, e.g. `WWWGGDDD`. Blank lines are skipped, and every
+/// row has to be as wide as the first
+pub fn parse_biome_map(text: &str) -> io::Result<Vec<Vec<Biome>>> {
+    let mut map: Vec<Vec<Biome>> = Vec::new();
+    for line in text.lines().map(str::trim_end).filter(|line| !line.is_empty()) {
+        let row = line.chars().enumerate()
+            .map(|(x, letter)| Biome::from_letter(letter)
+                .ok_or_else(|| invalid_data(format!("Unknown biome '{}' at row {}, column {}", letter, map.len() + 1, x + 1))))
+            .collect::<io::Result<Vec<Biome>>>()?;
+        if let Some(first) = map.first().filter(|first| first.len() != row.len()) {
+            return Err(invalid_data(format!("Row {} is {} tiles wide but row 1 is {}", map.len() + 1, row.len(), first.len())));
+        }
+        map.push(row);
+    }
+    if map.is_empty() {
+        return Err(invalid_data("The biome map is empty".to_string()));
+    }
+    Ok(map)
+}
+
+// Optimization: Track tile changes without full array clones. Also reported by `World::tick_and_diff`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TileChange {
+    pub x: usize,
+    pub y: usize,
+    pub new_tile: TileType,
 }
 
 impl TileChange {
-    fn new(x: usize, y: usize, old_tile: TileType, new_tile: TileType) -> Self {
-        TileChange { x, y, old_tile, new_tile }
+    fn new(x: usize, y: usize, new_tile: TileType) -> Self {
+        TileChange { x, y, new_tile }
+    }
+}
+
+// A sandbox edit as `World::undo_edit` sees it: the tile the edit overwrote
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TileEdit {
+    pub x: usize,
+    pub y: usize,
+    pub old_tile: TileType,
+}
+
+// Something notable that happened in the world, kept in `World::events`
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorldEvent {
+    pub tick: u64,
+    pub message: String,
+}
+
+impl fmt::Display for WorldEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Tick {}: {}", self.tick, self.message)
     }
 }
 
+// What the last population sample saw, so events for crossing a threshold fire once
+struct EventWatch {
+    outbreak: bool,
+    lake: bool,
+    phase: EcosystemPhase,
+}
+
 // Ecosystem health and diversity statistics
 #[derive(Debug)]
 pub struct EcosystemStats {
+    pub tick: u64,
     pub total_plants: usize,
     pub total_pillbugs: usize,
+    pub total_beetles: usize,
     pub water_coverage: usize,
     pub nutrient_count: usize,
     pub plant_health_ratio: f32,  // 0.0-1.0, higher means more healthy plants
     pub biome_diversity: usize,   // Number of different biomes present
+    pub ticks_since_rain: u32,    // Length of the current dry spell
+    pub drought_stress: f32,      // 0.0-1.0, how hard the dry spell is hitting
+    // Breakdowns listing every biome or size, even when nothing is there
+    pub plants_by_biome: BTreeMap<Biome, usize>,
+    pub pillbugs_by_biome: BTreeMap<Biome, usize>,
+    pub plants_by_size: BTreeMap<Size, usize>,
+    pub pillbugs_by_size: BTreeMap<Size, usize>,
+    pub beetles_by_size: BTreeMap<Size, usize>,
+}
+
+// Where the ecosystem is headed, judged from the populations sampled over the last few hundred ticks,
+// in order of severity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum EcosystemPhase {
+    Thriving,  // Plants and pillbugs holding steady or growing
+    Declining, // A population shrinking across the window
+    Collapsed, // A population down to a sliver of its peak in the window
+    Extinct,   // No plants or no pillbugs left at all
+}
+
+impl EcosystemPhase {
+    pub fn name(self) -> &'static str {
+        match self {
+            EcosystemPhase::Thriving => "Thriving",
+            EcosystemPhase::Declining => "Declining",
+            EcosystemPhase::Collapsed => "Collapsed",
+            EcosystemPhase::Extinct => "Extinct",
+        }
+    }
+    
+    /// Phase of one population from its samples, oldest first
+    fn of_population(samples: &[usize]) -> EcosystemPhase {
+        let (Some(&latest), Some(&peak)) = (samples.last(), samples.iter().max()) else {
+            return EcosystemPhase::Thriving;
+        };
+        let mean = |samples: &[usize]| samples.iter().sum::<usize>() as f32 / samples.len().max(1) as f32;
+        let (older, recent) = samples.split_at(samples.len() / 2);
+        if latest == 0 {
+            EcosystemPhase::Extinct
+        } else if latest as f32 <= peak as f32 * COLLAPSE_FRACTION {
+            EcosystemPhase::Collapsed
+        } else if !older.is_empty() && mean(recent) < mean(older) * DECLINE_FRACTION {
+            EcosystemPhase::Declining
+        } else {
+            EcosystemPhase::Thriving
+        }
+    }
 }
 
 // Seed with velocity for projectile motion
 #[derive(Debug, Clone)]
-struct SeedProjectile {
-    x: f32,
-    y: f32,
-    velocity_x: f32,
-    velocity_y: f32,
-    seed_type: TileType, // The actual seed tile type
-    age: u8,
-    bounce_count: u8,    // How many times it has bounced
+pub(crate) struct SeedProjectile {
+    pub(crate) x: f32,
+    pub(crate) y: f32,
+    pub(crate) velocity_x: f32,
+    pub(crate) velocity_y: f32,
+    pub(crate) seed_type: TileType, // The actual seed tile type
+    pub(crate) bounce_count: u8,    // How many times it has bounced
+    pub(crate) genome: PlantGenome, // Traits inherited from the parent flower
 }
 
-// Performance monitoring
+// Per-pillbug state that doesn't fit in the tiles, keyed by head position
 #[derive(Debug, Clone)]
+pub struct PillbugState {
+    pub meals: u16, // Meals eaten since the last molt
+    pub rolled: u8, // Ticks left rolled up, 0 when uncurled
+    pub seed: Option<(TileType, PlantGenome)>, // Seed caught on the shell, with its genome
+    pub carried_for: u8, // Moves made since picking up the seed
+    pub energy: u8, // Food in reserve, burned with every tick; the pillbug starves when it runs out
+    pub soft: u8, // Ticks left with the soft shell of a fresh molt, 0 once it has hardened
+}
+
+impl Default for PillbugState {
+    fn default() -> Self {
+        PillbugState { meals: 0, rolled: 0, seed: None, carried_for: 0, energy: PILLBUG_MAX_ENERGY, soft: 0 }
+    }
+}
+
+impl PillbugState {
+    /// Count a meal toward molting and restore energy by its nutrition
+    fn eat(&mut self, nutrition: u8) {
+        self.meals = self.meals.saturating_add(1);
+        self.energy = self.energy.saturating_add(nutrition.saturating_mul(PILLBUG_ENERGY_PER_NUTRITION)).min(PILLBUG_MAX_ENERGY);
+    }
+}
+
+// Performance monitoring
+#[derive(Debug, Clone, Default)]
 pub struct PerformanceMetrics {
     pub total_update_time: Duration,
     pub physics_time: Duration,
@@ -52,52 +414,228 @@ pub struct PerformanceMetrics {
     pub wind_time: Duration,
     pub plant_support_time: Duration,
     pub nutrient_diffusion_time: Duration,
+    pub fire_time: Duration,
     pub life_update_time: Duration,
     pub spawn_entities_time: Duration,
     pub ticks_per_second: f64,
     pub frame_times: Vec<Duration>, // Last 60 frame times for averaging
 }
 
+impl PerformanceMetrics {
+    /// Each subsystem's name with the time it took, in the order `update` runs them
+    pub fn system_times(&self) -> [(&'static str, Duration); 9] {
+        [
+            ("physics", self.physics_time),
+            ("gravity", self.gravity_time),
+            ("projectiles", self.projectiles_time),
+            ("wind", self.wind_time),
+            ("plant_support", self.plant_support_time),
+            ("nutrient_diffusion", self.nutrient_diffusion_time),
+            ("fire", self.fire_time),
+            ("life_update", self.life_update_time),
+            ("spawn_entities", self.spawn_entities_time),
+        ]
+    }
+    
+    /// Name of the slowest subsystem; the first one run wins a tie
+    pub fn bottleneck(&self) -> &'static str {
+        self.system_times()
+            .into_iter()
+            .rev()
+            .max_by_key(|&(_, time)| time)
+            .map_or("physics", |(name, _)| name)
+    }
+    
+    /// Add another tick's timings to these, to average over a run with `averaged`
+    pub fn accumulate(&mut self, other: &PerformanceMetrics) {
+        self.total_update_time += other.total_update_time;
+        self.physics_time += other.physics_time;
+        self.gravity_time += other.gravity_time;
+        self.projectiles_time += other.projectiles_time;
+        self.wind_time += other.wind_time;
+        self.plant_support_time += other.plant_support_time;
+        self.nutrient_diffusion_time += other.nutrient_diffusion_time;
+        self.fire_time += other.fire_time;
+        self.life_update_time += other.life_update_time;
+        self.spawn_entities_time += other.spawn_entities_time;
+    }
+    
+    /// Timings accumulated over `ticks` ticks, divided back down to a per-tick average
+    pub fn averaged(&self, ticks: u64) -> PerformanceMetrics {
+        let ticks = ticks.max(1) as f64;
+        let total_update_time = self.total_update_time.div_f64(ticks);
+        PerformanceMetrics {
+            total_update_time,
+            physics_time: self.physics_time.div_f64(ticks),
+            gravity_time: self.gravity_time.div_f64(ticks),
+            projectiles_time: self.projectiles_time.div_f64(ticks),
+            wind_time: self.wind_time.div_f64(ticks),
+            plant_support_time: self.plant_support_time.div_f64(ticks),
+            nutrient_diffusion_time: self.nutrient_diffusion_time.div_f64(ticks),
+            fire_time: self.fire_time.div_f64(ticks),
+            life_update_time: self.life_update_time.div_f64(ticks),
+            spawn_entities_time: self.spawn_entities_time.div_f64(ticks),
+            ticks_per_second: if total_update_time.as_secs_f64() > 0.0 { 1.0 / total_update_time.as_secs_f64() } else { 0.0 },
+            frame_times: Vec::new(),
+        }
+    }
+}
+
+/// The simulation: the grid of tiles, the side grids kept alongside it, the weather, and the
+/// creatures' state, advanced one tick at a time by `update`.
+///
+/// A world is `Send` and `Sync`. All of its randomness comes from its own seeded generator and
+/// its weather controller must be `Send + Sync`, so it can be moved onto a background thread
+/// and stepped there; hand the state to other threads with `clone_snapshot` rather than
+/// sharing the world itself behind a lock the simulation would have to wait on.
 pub struct World {
     pub tiles: Vec<Vec<TileType>>,
     pub biome_map: Vec<Vec<Biome>>, // Biome information for each region
+    pub soil_layers: Vec<Vec<Option<SoilLayer>>>, // Stratum each ground tile came from, moves with the material
+    pub disturbance: Vec<Vec<f32>>, // 0.0-1.0 per cell: recent deaths, trampling, and floods, fading over time
+    pub biome_pressure: Vec<Vec<u8>>, // Checks in a row each cell's surroundings have favoured another biome, see `update_biomes`
+    pub pheromone: Vec<Vec<u8>>, // Invisible trail pillbugs lay where they walk, 0 (none) to 255, fading over time
+    pub genomes: Vec<Vec<Option<PlantGenome>>>, // Traits of the plant or seed in each cell, None for the default genome
+    pub species: Vec<Vec<Option<PlantSpecies>>>, // Growth form of the plant in each cell, None for its biome's native species
+    pub elevation: Vec<Vec<u8>>, // Lie of the land at each cell in water-depth units; water runs toward lower ground
     pub width: usize,
     pub height: usize,
     pub tick: u64,
     pub day_cycle: f32,
     pub rain_intensity: f32,
     pub season_cycle: f32,     // 0.0 = Spring, 0.25 = Summer, 0.5 = Fall, 0.75 = Winter
+    pub ticks_per_year: u64,   // Ticks for `season_cycle` to come round, see `set_year_length`
+    pub ticks_per_day: u64,    // Ticks for `day_cycle` to come round
+    pub season_offset: u64,    // Ticks added to `tick` when working out the season, see `set_season`
     pub temperature: f32,      // -1.0 to 1.0, affects growth rates
-    pub humidity: f32,         // 0.0 to 1.0, affects rain and plant growth
+    pub humidity: f32,         // 0.1 to 1.0, affects rain and plant growth
     pub wind_direction: f32,   // 0.0 to 2π, direction of wind in radians
     pub wind_strength: f32,    // 0.0 to 1.0, strength of wind
+    pub wind_mode: WindMode,   // Seasonal model or pinned wind
+    pub gust: Option<(f32, u8)>, // Gust in progress: extra wind strength and ticks it has left
+    pub edge_behavior: EdgeBehavior, // What happens to particles, seeds, and pillbugs at the east and west borders
+    pub wrap_horizontal: bool, // East and west borders join up, whatever the edge behavior
+    pub physics_config: PhysicsConfig,
+    pub drought_config: DroughtConfig,
+    pub growth_config: GrowthConfig,
+    pub interaction_config: InteractionConfig, // Which species nurse, suppress, or feed their neighbours
+    pub activity_config: ActivityConfig,
+    pub disease_config: DiseaseConfig,
+    pub pillbug_config: PillbugConfig,
+    // Top up dying populations and let beetles wander in; off, the world is a closed ecosystem
+    // whose populations can genuinely crash
+    pub allow_respawn: bool,
+    pub respawn_config: RespawnConfig,
+    pub systems: SystemFlags, // Which systems each update runs; switch some off to isolate the rest
+    pub world_config: WorldConfig, // Starting conditions used when terrain is (re)generated
+    pub ticks_since_rain: u32, // Consecutive ticks without meaningful rain
+    pub evaporated: u32, // Water depth that evaporated last tick, humidifying the air the next
+    // Sandbox edit batches, oldest first, each holding the tiles its edits overwrote
+    pub history: Vec<Vec<TileEdit>>,
     // Performance optimization: reuse buffers to reduce allocations
     tile_changes: Vec<TileChange>,
     // Seed projectiles in flight
-    seed_projectiles: Vec<SeedProjectile>,
+    pub(crate) seed_projectiles: Vec<SeedProjectile>,
+    // How far flying seeds have drifted lately, summed across every seed and fading with time
+    dispersal_drift: (f32, f32),
+    // Pillbug state keyed by head position, moved along with the head
+    pub pillbug_states: HashMap<(usize, usize), PillbugState>,
+    // Pillbug heads as they stood at the start of the life update, sorted by row then column,
+    // so pillbugs find each other without scanning tiles
+    pub pillbug_positions: Vec<(usize, usize, Size)>,
     // Performance monitoring
     pub performance: PerformanceMetrics,
+    // Ecosystem stats sampled every few ticks, oldest first, for `ecosystem_phase`
+    pub population_history: Vec<EcosystemStats>,
+    // Notable moments, oldest first, the latest `MAX_EVENTS` of them: flowering, births,
+    // disease outbreaks, lakes forming, and the ecosystem collapsing
+    pub events: VecDeque<WorldEvent>,
+    event_watch: EventWatch,
+    // Weather policy consulted every tick, taken out while it runs
+    weather_controller: Option<Box<dyn WeatherController>>,
+    // Seed the world was created from, so a good one can be reproduced
+    pub seed: u64,
+    // Single source of randomness so a seeded world replays identically
+    pub(crate) rng: StdRng,
 }
 
 impl World {
     pub fn new(width: usize, height: usize) -> Self {
+        World::new_with_config(width, height, WorldConfig::default())
+    }
+    
+    /// Generate a world from a seed; the same seed and size always give the same world
+    pub fn new_seeded(width: usize, height: usize, seed: u64) -> Self {
+        World::new_seeded_with_config(width, height, seed, WorldConfig::default())
+    }
+    
+    /// Generate a world with custom starting conditions from a random seed
+    pub fn new_with_config(width: usize, height: usize, config: WorldConfig) -> Self {
+        World::new_seeded_with_config(width, height, rand::random(), config)
+    }
+    
+    /// Generate a world with custom starting conditions; the same seed, size, and config always give the same world
+    pub fn new_seeded_with_config(width: usize, height: usize, seed: u64, config: WorldConfig) -> Self {
+        let mut world = World::blank(width, height, seed);
+        world.world_config = config;
+        world.generate();
+        world
+    }
+    
+    /// Create a world with default weather but no terrain, biomes, or life
+    pub(crate) fn blank(width: usize, height: usize, seed: u64) -> Self {
+        let (width, height) = (width.max(1), height.max(1));
         let tiles = vec![vec![TileType::Empty; width]; height];
         let biome_map = vec![vec![Biome::Grassland; width]; height]; // Initialize with default biome
-        let mut world = World {
+        World {
             tiles,
             biome_map,
+            soil_layers: vec![vec![None; width]; height],
+            disturbance: vec![vec![0.0; width]; height],
+            biome_pressure: vec![vec![0; width]; height],
+            pheromone: vec![vec![0; width]; height],
+            genomes: vec![vec![None; width]; height],
+            species: vec![vec![None; width]; height],
+            elevation: vec![vec![0; width]; height],
             width,
             height,
             tick: 0,
             day_cycle: 0.0,
             rain_intensity: 0.0,
             season_cycle: 0.0,   // Start in spring
+            ticks_per_year: DEFAULT_TICKS_PER_YEAR,
+            ticks_per_day: DEFAULT_TICKS_PER_DAY,
+            season_offset: 0,
             temperature: 0.3,    // Mild spring temperature
             humidity: 0.5,       // Moderate humidity
             wind_direction: 0.0, // Start with easterly wind
             wind_strength: 0.3,  // Moderate wind strength
+            wind_mode: WindMode::Seasonal,
+            gust: None,
+            edge_behavior: EdgeBehavior::default(),
+            wrap_horizontal: false,
+            physics_config: PhysicsConfig::default(),
+            drought_config: DroughtConfig::default(),
+            growth_config: GrowthConfig::default(),
+            interaction_config: InteractionConfig::default(),
+            activity_config: ActivityConfig::default(),
+            disease_config: DiseaseConfig::default(),
+            pillbug_config: PillbugConfig::default(),
+            allow_respawn: true,
+            respawn_config: RespawnConfig::default(),
+            systems: SystemFlags::ALL,
+            world_config: WorldConfig::default(),
+            ticks_since_rain: 0,
+            evaporated: 0,
+            history: Vec::new(),
             tile_changes: Vec::with_capacity(1000), // Pre-allocate for common case
             seed_projectiles: Vec::new(), // Start with no flying seeds
+            dispersal_drift: (0.0, 0.0),
+            pillbug_states: HashMap::new(),
+            pillbug_positions: Vec::new(),
+            population_history: Vec::new(),
+            events: VecDeque::new(),
+            event_watch: EventWatch { outbreak: false, lake: false, phase: EcosystemPhase::Thriving },
             performance: PerformanceMetrics {
                 total_update_time: Duration::new(0, 0),
                 physics_time: Duration::new(0, 0),
@@ -106,81 +644,304 @@ impl World {
                 wind_time: Duration::new(0, 0),
                 plant_support_time: Duration::new(0, 0),
                 nutrient_diffusion_time: Duration::new(0, 0),
+                fire_time: Duration::new(0, 0),
                 life_update_time: Duration::new(0, 0),
                 spawn_entities_time: Duration::new(0, 0),
                 ticks_per_second: 0.0,
                 frame_times: Vec::with_capacity(60),
             },
-        };
+            weather_controller: Some(Box::new(SeasonalWeather)),
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+    
+    /// Randomly generate biomes, terrain, and the starting population
+    pub(crate) fn generate(&mut self) {
+        let mut rng = self.rng.clone();
+        self.generate_biome_map(&mut rng);
+        self.generate_elevation(&mut rng);
+        self.rng = rng;
+        self.generate_terrain();
+    }
+    
+    /// Roll the land into hills and generate terrain and the starting population on a biome
+    /// map laid out in advance
+    pub(crate) fn generate_on_biome_map(&mut self) {
+        let mut rng = self.rng.clone();
+        self.generate_elevation(&mut rng);
+        self.rng = rng;
+        self.generate_terrain();
+    }
+    
+    /// Randomly generate terrain and the starting population on the current biome map
+    pub(crate) fn generate_terrain(&mut self) {
+        let mut rng = self.rng.clone();
+        self.generate_initial_world(&mut rng);
+        self.rng = rng;
+    }
+    
+    /// Advance exactly one tick, the same as `update`; the entry point benchmarks time, with
+    /// nothing of the terminal UI around it
+    pub fn step_once(&mut self) {
+        self.update();
+    }
+    
+    /// Crowd the world for benchmarking the heavy path: fill random empty cells until `density`
+    /// (0.0 to 1.0) of all cells are occupied, with a mix of water, plants, seeds, nutrients,
+    /// sand, and young pillbugs. Plants stand on ground or other plants where they can and hang
+    /// unsupported elsewhere, so the first ticks also exercise support checks and falling.
+    /// Draws from the world's own generator, so a seeded world fills the same way every time
+    pub fn populate_stress_test(&mut self, density: f32) {
+        let mut rng = self.rng.clone();
+        let target = (density.clamp(0.0, 1.0) * (self.width * self.height) as f32) as usize;
+        let mut empty: Vec<(usize, usize)> = (0..self.height)
+            .flat_map(|y| (0..self.width).map(move |x| (x, y)))
+            .filter(|&(x, y)| self.tiles[y][x] == TileType::Empty)
+            .collect();
+        empty.shuffle(&mut rng);
+        let occupied = self.width * self.height - empty.len();
         
-        world.generate_biome_map();
-        world.generate_initial_world();
-        world
+        for (x, y) in empty.into_iter().take(target.saturating_sub(occupied)) {
+            let size = random_size(&mut rng);
+            let grounded = y + 1 < self.height && (self.tiles[y + 1][x].is_earth() || self.tiles[y + 1][x].is_plant());
+            self.tiles[y][x] = match rng.gen_range(0..100) {
+                0..=29 => TileType::Water(120, CLEAN_WATER),
+                30..=54 if grounded => TileType::PlantStem(20, size),
+                30..=54 => TileType::PlantLeaf(20, size),
+                55..=69 => TileType::Seed(0, size),
+                70..=79 => TileType::Nutrient,
+                80..=89 => TileType::Sand,
+                _ => TileType::PillbugHead(20, size),
+            };
+        }
+        self.rng = rng;
+    }
+    
+    /// Run one `update` and return every tile that differs from before it, row by row,
+    /// so a renderer can redraw only the dirty cells
+    pub fn tick_and_diff(&mut self) -> Vec<TileChange> {
+        let before = self.tiles.clone();
+        self.update();
+        
+        let mut changes = Vec::new();
+        for (y, (row, old_row)) in self.tiles.iter().zip(&before).enumerate() {
+            for (x, (tile, old)) in row.iter().zip(old_row).enumerate() {
+                if tile != old {
+                    changes.push(TileChange::new(x, y, *tile));
+                }
+            }
+        }
+        changes
+    }
+    
+    /// Start a new batch of edits for `undo_edit` to revert together, forgetting the oldest
+    /// batch past `MAX_EDIT_HISTORY`
+    pub fn begin_edit(&mut self) {
+        if self.history.last().is_some_and(Vec::is_empty) {
+            return;
+        }
+        if self.history.len() >= MAX_EDIT_HISTORY {
+            self.history.remove(0);
+        }
+        self.history.push(Vec::new());
+    }
+    
+    /// Set a tile as a sandbox edit rather than a simulation step, remembering what it
+    /// overwrote in the current batch
+    pub fn edit_tile(&mut self, x: usize, y: usize, tile: TileType) {
+        let old = self.tiles[y][x];
+        if old == tile {
+            return;
+        }
+        if self.history.is_empty() {
+            self.begin_edit();
+        }
+        let batch = self.history.last_mut().unwrap();
+        // Only the first overwrite of a cell matters to undo, which keeps a batch within the world's size
+        if !batch.iter().any(|edit| (edit.x, edit.y) == (x, y)) {
+            batch.push(TileEdit { x, y, old_tile: old });
+        }
+        self.tiles[y][x] = tile;
+    }
+    
+    /// Revert the last batch of edits that changed anything, returning how many tiles it restored.
+    /// Only tiles come back; anything the simulation did since stays.
+    pub fn undo_edit(&mut self) -> usize {
+        while let Some(batch) = self.history.pop() {
+            if batch.is_empty() {
+                continue;
+            }
+            for edit in batch.iter().rev() {
+                self.tiles[edit.y][edit.x] = edit.old_tile;
+            }
+            return batch.len();
+        }
+        0
+    }
+    
+    /// The rules and tuning this world runs by, without any of its state
+    pub fn settings(&self) -> WorldSettings {
+        WorldSettings {
+            wind_mode: self.wind_mode,
+            ticks_per_year: self.ticks_per_year,
+            ticks_per_day: self.ticks_per_day,
+            edge_behavior: self.edge_behavior,
+            wrap_horizontal: self.wrap_horizontal,
+            physics: self.physics_config,
+            drought: self.drought_config,
+            growth: self.growth_config,
+            interaction: self.interaction_config,
+            activity: self.activity_config,
+            disease: self.disease_config,
+            pillbug: self.pillbug_config,
+            allow_respawn: self.allow_respawn,
+            respawn: self.respawn_config,
+            systems: self.systems,
+            world_config: self.world_config,
+        }
+    }
+    
+    /// Run by the rules and tuning of `settings`, keeping the tiles, weather, and everything
+    /// else already going on
+    pub fn apply_settings(&mut self, settings: WorldSettings) {
+        self.wind_mode = settings.wind_mode;
+        self.ticks_per_year = settings.ticks_per_year;
+        self.ticks_per_day = settings.ticks_per_day;
+        self.edge_behavior = settings.edge_behavior;
+        self.wrap_horizontal = settings.wrap_horizontal;
+        self.physics_config = settings.physics;
+        self.drought_config = settings.drought;
+        self.growth_config = settings.growth;
+        self.interaction_config = settings.interaction;
+        self.activity_config = settings.activity;
+        self.disease_config = settings.disease;
+        self.pillbug_config = settings.pillbug;
+        self.allow_respawn = settings.allow_respawn;
+        self.respawn_config = settings.respawn;
+        self.systems = settings.systems;
+        self.world_config = settings.world_config;
+    }
+    
+    /// Make a year last `ticks` ticks, at least 1. The season is worked out from the tick, so
+    /// changing the length mid-run jumps to wherever the current tick falls in the new year.
+    pub fn set_year_length(&mut self, ticks: u64) {
+        self.ticks_per_year = ticks.max(1);
     }
     
     pub fn update(&mut self) {
         self.tick += 1;
-        self.day_cycle = (self.tick as f32 * 0.01) % (2.0 * std::f32::consts::PI);
+        let day_length = self.ticks_per_day.max(1);
+        self.day_cycle = (self.tick % day_length) as f32 / day_length as f32 * (2.0 * std::f32::consts::PI);
         
-        // Seasonal cycle - complete season change every ~1600 ticks
-        self.season_cycle = (self.tick as f32 * 0.001) % 1.0;
+        // Seasonal cycle - one spring-to-spring year every `ticks_per_year` ticks
+        let year_length = self.ticks_per_year.max(1);
+        self.season_cycle = (self.tick.wrapping_add(self.season_offset) % year_length) as f32 / year_length as f32;
         
-        // Update seasonal weather parameters
-        self.update_seasonal_weather();
+        // Gusts blow themselves out
+        self.gust = self.gust.and_then(|(extra, ticks)| (ticks > 1).then_some((extra, ticks - 1)));
         
-        // Rain cycle - affected by season and humidity
-        let mut rng = rand::thread_rng();
-        let base_rain_chance = 0.05 * self.humidity;
-        let seasonal_rain_modifier = match self.get_current_season() {
-            Season::Spring => 1.5,  // Rainy season
-            Season::Summer => 0.7,  // Drier season
-            Season::Fall => 1.3,    // Return of rains
-            Season::Winter => 0.5,  // Cold, less rain
-        };
+        // Update weather parameters from the active controller
+        if let Some(mut controller) = self.weather_controller.take() {
+            controller.update_weather(self);
+            self.weather_controller = Some(controller);
+        }
         
-        // Rain more likely during night and based on seasonal patterns
-        if self.day_cycle.sin() < -0.3 && rng.gen_bool((base_rain_chance * seasonal_rain_modifier).min(1.0) as f64) {
-            self.rain_intensity = rng.gen_range(0.1..(0.8 * self.humidity));
-        } else if rng.gen_bool(0.02) {
-            self.rain_intensity *= 0.95; // Rain gradually stops
+        // Dry spells accumulate until it properly rains again
+        if self.rain_intensity < self.drought_config.rain_threshold {
+            self.ticks_since_rain = self.ticks_since_rain.saturating_add(1);
+        } else {
+            self.ticks_since_rain = 0;
         }
         
+        let mut rng = self.rng.clone();
+        
         // Timed system updates with performance profiling
         let update_start = Instant::now();
         
-        self.spawn_rain();
+        // Each system runs only while its flag is on
+        let systems = self.systems;
+        if systems.contains(SystemFlags::RAIN) {
+            self.spawn_rain(&mut rng);
+        }
         
         let physics_start = Instant::now();
-        self.update_physics();
+        if systems.contains(SystemFlags::PHYSICS) {
+            self.update_physics(&mut rng);
+            self.update_ice(&mut rng);
+        }
         self.performance.physics_time = physics_start.elapsed();
         
         let gravity_start = Instant::now();
-        self.apply_gravity();
+        if systems.contains(SystemFlags::GRAVITY) {
+            self.apply_gravity(&mut rng);
+        }
         self.performance.gravity_time = gravity_start.elapsed();
         
         let projectiles_start = Instant::now();
-        self.update_seed_projectiles();
+        if systems.contains(SystemFlags::GRAVITY) {
+            self.update_seed_projectiles();
+        }
         self.performance.projectiles_time = projectiles_start.elapsed();
         
         let wind_start = Instant::now();
-        self.process_wind_effects();
+        if systems.contains(SystemFlags::WIND) {
+            self.process_wind_effects(&mut rng);
+        }
         self.performance.wind_time = wind_start.elapsed();
         
         let support_start = Instant::now();
-        self.check_plant_support();
+        if systems.contains(SystemFlags::LIFE) {
+            self.check_plant_support(&mut rng);
+        }
         self.performance.plant_support_time = support_start.elapsed();
         
         let diffusion_start = Instant::now();
-        self.diffuse_nutrients();
+        if systems.contains(SystemFlags::NUTRIENTS) {
+            self.diffuse_nutrients(&mut rng);
+        }
         self.performance.nutrient_diffusion_time = diffusion_start.elapsed();
         
+        let fire_start = Instant::now();
+        if systems.contains(SystemFlags::PHYSICS) {
+            self.update_fire(&mut rng);
+        }
+        self.performance.fire_time = fire_start.elapsed();
+        
         let life_start = Instant::now();
-        self.update_life();
+        if systems.contains(SystemFlags::LIFE) {
+            self.update_life(&mut rng);
+        }
         self.performance.life_update_time = life_start.elapsed();
         
+        // Disturbed ground gradually recovers
+        for value in self.disturbance.iter_mut().flatten() {
+            *value *= DISTURBANCE_DECAY;
+        }
+        // Pheromone trails fade unless walked again
+        for value in self.pheromone.iter_mut().flatten() {
+            *value = value.saturating_sub(PHEROMONE_DECAY);
+        }
+        
+        if self.tick.is_multiple_of(BIOME_UPDATE_INTERVAL) {
+            self.update_biomes();
+        }
+        
         let spawn_start = Instant::now();
-        self.spawn_entities();
+        if systems.contains(SystemFlags::LIFE) {
+            self.spawn_entities(&mut rng);
+        }
         self.performance.spawn_entities_time = spawn_start.elapsed();
+        self.rng = rng;
+        
+        // Sample the populations for judging the ecosystem's phase, keeping a window of samples
+        if self.tick.is_multiple_of(PHASE_SAMPLE_INTERVAL) {
+            if self.population_history.len() >= PHASE_HISTORY_SAMPLES {
+                self.population_history.remove(0);
+            }
+            self.population_history.push(self.calculate_ecosystem_stats());
+            self.watch_for_events();
+        }
         
         // Calculate total update time and performance metrics
         self.performance.total_update_time = update_start.elapsed();
@@ -202,14 +963,88 @@ impl World {
         }
     }
     
+    /// Run `ticks` updates, handing `on_tick` the number run so far (1 to `ticks`) and the
+    /// world after each, so an embedder can collect data or draw without its own loop
+    pub fn run(&mut self, ticks: u64, mut on_tick: impl FnMut(u64, &World)) {
+        self.run_while(ticks, |step, world| {
+            on_tick(step, world);
+            true
+        });
+    }
+    
+    /// `run`, stopping early once `on_tick` returns false; returns the ticks actually run
+    pub fn run_while(&mut self, ticks: u64, mut on_tick: impl FnMut(u64, &World) -> bool) -> u64 {
+        for step in 1..=ticks {
+            self.update();
+            if !on_tick(step, self) {
+                return step;
+            }
+        }
+        ticks
+    }
+    
+    /// Soil layer property at a position, treating unlabeled ground as topsoil
+    fn soil_factor_at(&self, x: usize, y: usize, property: fn(SoilLayer) -> f32) -> f32 {
+        property(self.soil_layer_at(x, y).unwrap_or(SoilLayer::Topsoil))
+    }
+    
     pub fn is_day(&self) -> bool {
         self.day_cycle.sin() > 0.0
     }
     
+    /// Whether it's the pillbugs' active time of day (night unless `activity_config` says otherwise)
+    pub fn pillbugs_active(&self) -> bool {
+        self.is_day() != self.activity_config.nocturnal
+    }
+    
+    /// How fast pillbugs age, eat, and move relative to their usual pace: 1.0 at a mild
+    /// temperature, faster in the heat, slower in the cold, and nearly stopped in hibernation
+    pub fn pillbug_metabolism(&self) -> f32 {
+        if self.temperature < HIBERNATION_TEMPERATURE {
+            HIBERNATION_METABOLISM
+        } else {
+            1.0 + self.temperature - PILLBUG_COMFORT_TEMPERATURE
+        }
+    }
+    
+    /// Ticks pillbugs age this tick: the metabolism's fractional ticks of aging are spread evenly
+    /// over the ticks, so a bug at half pace ages every other tick
+    fn pillbug_aging(&self) -> u8 {
+        let metabolism = self.pillbug_metabolism() as f64;
+        let aged_by = |tick: u64| (tick as f64 * metabolism).floor() as u64;
+        (aged_by(self.tick + 1) - aged_by(self.tick)) as u8
+    }
+    
+    /// A copy of the last tick's timings, for benchmarks and tools outside the UI
+    pub fn performance_snapshot(&self) -> PerformanceMetrics {
+        self.performance.clone()
+    }
+    
     pub fn get_projectile_count(&self) -> usize {
         self.seed_projectiles.len()
     }
     
+    /// Plant tiles in the world, as counted in the ecosystem stats
+    pub fn plant_count(&self) -> usize {
+        self.calculate_ecosystem_stats().total_plants
+    }
+    
+    /// Pillbugs in the world, one per head (rolled up or not), where the ecosystem stats count
+    /// every segment
+    pub fn pillbug_count(&self) -> usize {
+        self.tiles.iter().flatten().filter(|tile| matches!(tile, TileType::PillbugHead(_, _) | TileType::PillbugRolled(_, _))).count()
+    }
+    
+    /// Tiles holding water, as counted in the ecosystem stats
+    pub fn water_tiles(&self) -> usize {
+        self.calculate_ecosystem_stats().water_coverage
+    }
+    
+    /// The tile at a position; panics outside the world, like indexing `tiles`
+    pub fn tile_at(&self, x: usize, y: usize) -> TileType {
+        self.tiles[y][x]
+    }
+    
     pub fn get_current_season(&self) -> Season {
         match (self.season_cycle * 4.0) as u32 % 4 {
             0 => Season::Spring,
@@ -219,6 +1054,22 @@ impl World {
         }
     }
     
+    /// Color the whole scene leans toward: fresh green in spring, warm gold in summer, amber in
+    /// autumn, and icy blue in winter, dimmed as the night deepens. Blend tile colors toward it
+    /// with `types::blend`.
+    pub fn ambient_tint(&self) -> Color {
+        let (red, green, blue) = match self.get_current_season() {
+            Season::Spring => (190, 240, 170),
+            Season::Summer => (255, 210, 130),
+            Season::Fall => (230, 150, 80),
+            Season::Winter => (150, 180, 255),
+        };
+        // Full brightness at noon, down to 0.4 at midnight
+        let light = 0.7 + 0.3 * self.day_cycle.sin();
+        let dim = |channel: u8| (channel as f32 * light) as u8;
+        Color::Rgb(dim(red), dim(green), dim(blue))
+    }
+    
     pub fn get_season_name(&self) -> &'static str {
         match self.get_current_season() {
             Season::Spring => "Spring",
@@ -228,7 +1079,235 @@ impl World {
         }
     }
     
-    fn update_seasonal_weather(&mut self) {
+    /// Drought stress from the current dry spell: 0.0 until the spell outlasts
+    /// `drought_config.stress_after`, then rising to 1.0 over `full_stress_after` more ticks
+    pub fn drought_stress(&self) -> f32 {
+        let config = &self.drought_config;
+        let excess = self.ticks_since_rain.saturating_sub(config.stress_after);
+        if excess == 0 {
+            return 0.0;
+        }
+        (excess as f32 / config.full_stress_after.max(1) as f32).min(1.0)
+    }
+    
+    /// Total depth of liquid water in the world. Only rain and melting snow and ice add to it,
+    /// and only evaporation, soaking into the ground, freezing, and thirsty roots take it away
+    pub fn total_water(&self) -> u64 {
+        total_water_in(&self.tiles)
+    }
+    
+    /// Share of columns holding standing water, i.e. water resting on something rather than
+    /// falling as rain; 0.0 for a bone-dry world, 1.0 when every column has a puddle or lake
+    pub fn surface_water_fraction(&self) -> f32 {
+        let wet = (0..self.width)
+            .filter(|&x| (0..self.height).any(|y| {
+                matches!(self.tiles[y][x], TileType::Water(_, _))
+                    && self.tiles.get(y + 1).is_none_or(|row| row[x] != TileType::Empty)
+            }))
+            .count();
+        wet as f32 / self.width as f32
+    }
+    
+    /// Fraction of the square neighbourhood (`growth_config.density_radius`) around a position
+    /// taken up by above-ground plant parts; roots don't count
+    pub fn plant_density_around(&self, x: usize, y: usize) -> f32 {
+        self.plant_density_in(&self.tiles, x, y)
+    }
+    
+    fn plant_density_in(&self, tiles: &[Vec<TileType>], x: usize, y: usize) -> f32 {
+        let radius = self.growth_config.density_radius;
+        let (x_start, x_end) = (x.saturating_sub(radius), (x + radius).min(self.width - 1));
+        let (y_start, y_end) = (y.saturating_sub(radius), (y + radius).min(self.height - 1));
+        let mut plants = 0;
+        for row in &tiles[y_start..=y_end] {
+            plants += row[x_start..=x_end].iter()
+                .filter(|tile| tile.is_plant() && !matches!(tile, TileType::PlantRoot(_, _)))
+                .count();
+        }
+        plants as f32 / ((x_end - x_start + 1) * (y_end - y_start + 1)) as f32
+    }
+    
+    /// True when plants already crowd this spot past `growth_config.density_cap`, counting
+    /// growth earlier in the same tick, so nothing new should sprout or spread here
+    fn is_overcrowded(&self, tiles: &[Vec<TileType>], x: usize, y: usize) -> bool {
+        self.plant_density_in(tiles, x, y) >= self.growth_config.density_cap
+    }
+    
+    /// Recent disturbance at a position, 0.0 (long undisturbed) to 1.0
+    pub fn disturbance_at(&self, x: usize, y: usize) -> f32 {
+        self.disturbance[y][x]
+    }
+    
+    /// Nutrients available around each tile, blurred over the square neighbourhood
+    /// (`NUTRIENT_FIELD_RADIUS`) so it reads as a density: a loose nutrient counts 1.0, and
+    /// nutrient-rich dirt and mycelium count their level out of 255, plain dirt the little
+    /// roots can draw from it
+    pub fn nutrient_field(&self) -> Vec<Vec<f32>> {
+        let source = |tile: TileType| match tile {
+            TileType::Nutrient => 1.0,
+            TileType::NutrientDirt(level) | TileType::Mycelium(level) => level as f32 / 255.0,
+            TileType::Dirt => DIRT_NUTRIENTS as f32 / 255.0,
+            _ => 0.0,
+        };
+        
+        // Box blur one axis at a time, averaging over the part of the window inside the world
+        let blur = |values: &[f32], i: usize| {
+            let (start, end) = (i.saturating_sub(NUTRIENT_FIELD_RADIUS), (i + NUTRIENT_FIELD_RADIUS).min(values.len() - 1));
+            values[start..=end].iter().sum::<f32>() / (end - start + 1) as f32
+        };
+        let rows: Vec<Vec<f32>> = self.tiles.iter()
+            .map(|row| {
+                let sources: Vec<f32> = row.iter().map(|tile| source(*tile)).collect();
+                (0..self.width).map(|x| blur(&sources, x)).collect()
+            })
+            .collect();
+        let mut field = vec![vec![0.0; self.width]; self.height];
+        for x in 0..self.width {
+            let column: Vec<f32> = rows.iter().map(|row| row[x]).collect();
+            for (y, field_row) in field.iter_mut().enumerate() {
+                field_row[x] = blur(&column, y);
+            }
+        }
+        field
+    }
+    
+    /// Add disturbance to a cell, saturating at 1.0
+    pub fn disturb(&mut self, x: usize, y: usize, amount: f32) {
+        let value = &mut self.disturbance[y][x];
+        *value = (*value + amount).min(1.0);
+    }
+    
+    /// Chance per tick a sick plant part recovers: `disease_config.recovery_chance` scaled by
+    /// how much of its neighbourhood is healthy plant, its resistance, and how dry it's been
+    fn recovery_chance(&self, x: usize, y: usize, drought_stress: f32) -> f32 {
+        let healthy = (y.saturating_sub(1)..=(y + 1).min(self.height - 1))
+            .flat_map(|ny| (x.saturating_sub(1)..=(x + 1).min(self.width - 1)).map(move |nx| (nx, ny)))
+            .filter(|&(nx, ny)| (nx, ny) != (x, y) && self.tiles[ny][nx].is_plant()
+                && !matches!(self.tiles[ny][nx], TileType::PlantWithered(_, _) | TileType::PlantDiseased(_, _) | TileType::PlantWilting(_, _)))
+            .count();
+        if healthy < RECOVERY_MIN_HEALTHY {
+            return 0.0;
+        }
+        let resistance = self.genome_at(x, y).disease_resistance;
+        (self.disease_config.recovery_chance * healthy as f32 / 8.0 * (1.0 + resistance) * (1.0 - drought_stress)).clamp(0.0, 1.0)
+    }
+    
+    /// Germination multiplier from the plants around a seed, by `interaction_config`: one
+    /// factor for each species growing within its radius, toward the species the seed will
+    /// sprout as here
+    fn companion_modifier(&self, x: usize, y: usize) -> f32 {
+        let config = &self.interaction_config;
+        let seedling = PlantSpecies::for_biome(self.get_biome_at(x, y)) as usize;
+        let mut present = [false; 4];
+        for ny in y.saturating_sub(config.radius)..=(y + config.radius).min(self.height - 1) {
+            for nx in x.saturating_sub(config.radius)..=(x + config.radius).min(self.width - 1) {
+                if self.tiles[ny][nx].is_plant() && !matches!(self.tiles[ny][nx], TileType::PlantWithered(_, _)) {
+                    present[self.species_at(nx, ny) as usize] = true;
+                }
+            }
+        }
+        (0..4).filter(|&neighbour| present[neighbour]).map(|neighbour| config.germination[neighbour][seedling]).product()
+    }
+    
+    /// Germination multiplier for succession: small pioneer plants thrive on freshly
+    /// disturbed ground, large climax plants only take hold where it's been calm
+    fn succession_modifier(&self, x: usize, y: usize, size: Size) -> f32 {
+        let disturbance = self.disturbance[y][x];
+        match size {
+            Size::Small => 1.0 + 2.0 * disturbance,
+            Size::Medium => 1.0 - 0.5 * disturbance,
+            Size::Large => if disturbance < CLIMAX_MAX_DISTURBANCE { 1.0 } else { 0.0 },
+        }
+    }
+    
+    /// True when nothing sits above this tile, so it takes the full sun
+    pub fn has_open_sky(&self, x: usize, y: usize) -> bool {
+        (0..y).all(|sky_y| self.tiles[sky_y][x] == TileType::Empty)
+    }
+    
+    /// Share of the sun the canopy above lets through to a tile, day or night: 1.0 with no
+    /// leaves, branches, buds, or flowers in the `SHADE_ROWS` rows above it, less for each one
+    fn canopy_light(&self, x: usize, y: usize) -> f32 {
+        let canopy = (y.saturating_sub(SHADE_ROWS)..y)
+            .filter(|&above| matches!(self.tiles[above][x],
+                TileType::PlantLeaf(_, _) | TileType::PlantWilting(_, _) | TileType::PlantBranch(_, _) | TileType::PlantBud(_, _) | TileType::PlantFlower(_, _)))
+            .count();
+        (1.0 - SHADE_PER_CANOPY * canopy as f32).max(0.0)
+    }
+    
+    /// Light reaching a tile, 0.0 (night, or deep shade) to 1.0 (the open sky by day)
+    pub fn light_at(&self, x: usize, y: usize) -> f32 {
+        if self.is_day() { self.canopy_light(x, y) } else { 0.0 }
+    }
+    
+    /// Whether a leaf starved of daylight under the canopy withers this tick; leaves in full
+    /// sun never do
+    fn shaded_leaf_starves(&self, x: usize, y: usize, rng: &mut impl Rng) -> bool {
+        let light = self.canopy_light(x, y);
+        light < 1.0 && rng.gen_bool((SHADE_WITHER_CHANCE * (1.0 - light)) as f64)
+    }
+    
+    /// Replace the weather policy consulted at the start of every tick
+    pub fn set_weather_controller(&mut self, controller: Box<dyn WeatherController>) {
+        self.weather_controller = Some(controller);
+    }
+    
+    /// Set the temperature (-1.0 to 1.0) and humidity (0.1 to 1.0) now. The weather
+    /// controller carries on from these next tick, so the seasonal model drifts back toward the
+    /// season's weather; install a controller that holds them to keep them.
+    pub fn set_weather(&mut self, temperature: f32, humidity: f32) {
+        self.temperature = temperature.clamp(-1.0, 1.0);
+        self.humidity = humidity.clamp(0.1, 1.0);
+    }
+    
+    /// Turn the wind to `direction` (radians) at `strength` (0.0 to 1.0). Seasonal wind drifts
+    /// on from there; pinned wind (`WindMode::Deterministic`) stays pinned at the new values.
+    pub fn set_wind(&mut self, direction: f32, strength: f32) {
+        self.wind_direction = direction.rem_euclid(2.0 * std::f32::consts::PI);
+        self.wind_strength = strength.clamp(0.0, 1.0);
+        if let WindMode::Deterministic { .. } = self.wind_mode {
+            self.wind_mode = WindMode::Deterministic { direction: self.wind_direction, strength: self.wind_strength };
+        }
+    }
+    
+    /// Jump to the start of a season. The year carries on from there, so the seasons keep
+    /// turning at the usual length; the tick count and time of day are unchanged.
+    pub fn set_season(&mut self, season: Season) {
+        let year_length = self.ticks_per_year.max(1);
+        let start = season as u64 * year_length / 4;
+        self.season_offset = (start + year_length - self.tick % year_length) % year_length;
+        self.season_cycle = start as f32 / year_length as f32;
+    }
+    
+    /// Start rain at `intensity` (0.0 to 1.0) now; it fades the way natural rain does
+    pub fn trigger_rain(&mut self, intensity: f32) {
+        self.rain_intensity = intensity.clamp(0.0, 1.0);
+    }
+    
+    /// Start or fade rain - more likely at night and in rainy seasons
+    pub(crate) fn update_rain_cycle(&mut self) {
+        let mut rng = self.rng.clone();
+        let base_rain_chance = 0.05 * self.humidity;
+        let seasonal_rain_modifier = match self.get_current_season() {
+            Season::Spring => 1.5,  // Rainy season
+            Season::Summer => 0.7,  // Drier season
+            Season::Fall => 1.3,    // Return of rains
+            Season::Winter => 0.5,  // Cold, less rain
+        };
+        
+        // Rain more likely during night and based on seasonal patterns
+        // Air near its humidity floor is too dry to hold even a light shower
+        let heaviest_rain = 0.8 * self.humidity;
+        if self.day_cycle.sin() < -0.3 && heaviest_rain > 0.1
+            && rng.gen_bool((base_rain_chance * seasonal_rain_modifier).min(1.0) as f64) {
+            self.rain_intensity = rng.gen_range(0.1..heaviest_rain);
+        } else if rng.gen_bool(0.02) {
+            self.rain_intensity *= 0.95; // Rain gradually stops
+        }
+        self.rng = rng;
+    }
+    
+    pub(crate) fn update_seasonal_weather(&mut self) {
         // Calculate target temperature and humidity based on season
         let (target_temp, target_humidity) = match self.get_current_season() {
             Season::Spring => (0.3, 0.7),   // Mild and moist
@@ -244,15 +1323,28 @@ impl World {
         
         // Gradually adjust temperature and humidity toward targets
         let target_temp_with_var = (target_temp + temp_variation).clamp(-1.0, 1.0);
-        let target_humidity_with_var = (target_humidity + humidity_variation).clamp(0.1, 1.0);
+        let surface_water = SURFACE_WATER_HUMIDITY * (self.surface_water_fraction() - TYPICAL_SURFACE_WATER);
+        let target_humidity_with_var = (target_humidity + humidity_variation + surface_water).clamp(0.1, 1.0);
         
         self.temperature += (target_temp_with_var - self.temperature) * 0.02; // Slow change
         self.humidity += (target_humidity_with_var - self.humidity) * 0.03;   // Slightly faster change
+        self.humidity += EVAPORATION_HUMIDITY * self.evaporated as f32 / self.width as f32;
         
         // Clamp values to valid ranges
         self.temperature = self.temperature.clamp(-1.0, 1.0);
         self.humidity = self.humidity.clamp(0.1, 1.0);
         
+        match self.wind_mode {
+            WindMode::Seasonal => self.update_seasonal_wind(),
+            WindMode::Deterministic { direction, strength } => {
+                // Pinned wind for reproducible runs and tests
+                self.wind_direction = direction.rem_euclid(2.0 * std::f32::consts::PI);
+                self.wind_strength = strength.clamp(0.0, 1.0);
+            }
+        }
+    }
+    
+    fn update_seasonal_wind(&mut self) {
         // Update wind patterns - varies by season and has some random variation
         let target_wind_direction = match self.get_current_season() {
             Season::Spring => 0.5,      // Easterly winds (spring breezes)
@@ -279,11 +1371,52 @@ impl World {
         self.wind_direction += (target_dir_with_var - self.wind_direction) * 0.05; // Slow change
         self.wind_strength += (target_str_with_var - self.wind_strength) * 0.08;   // Slightly faster
         
-        self.wind_direction = self.wind_direction % (2.0 * std::f32::consts::PI);
+        self.wind_direction %= 2.0 * std::f32::consts::PI;
         self.wind_strength = self.wind_strength.clamp(0.0, 1.0);
+        
+        // Now and then a gust spikes the wind for a few ticks, most often in the autumn storms
+        let gust_chance = match self.get_current_season() {
+            Season::Spring => GUST_CHANCE * 0.4,
+            Season::Summer => GUST_CHANCE * 0.25,
+            Season::Fall => GUST_CHANCE,
+            Season::Winter => GUST_CHANCE * 0.75,
+        };
+        let mut rng = self.rng.clone();
+        if self.gust.is_none() && rng.gen_bool(gust_chance) {
+            self.gust = Some((rng.gen_range(0.3..0.7), rng.gen_range(3..=8)));
+        }
+        self.rng = rng;
+    }
+    
+    /// Wind strength the wind-driven systems feel this tick: the steady wind plus any gust
+    pub fn effective_wind_strength(&self) -> f32 {
+        (self.wind_strength + self.gust.map_or(0.0, |(extra, _)| extra)).min(MAX_GUST_WIND)
+    }
+    
+    /// The wind as east and south components (x toward the right of the world, y toward the
+    /// bottom), scaled by `effective_wind_strength`
+    pub fn wind_vector(&self) -> (f32, f32) {
+        let strength = self.effective_wind_strength();
+        (self.wind_direction.cos() * strength, self.wind_direction.sin() * strength)
+    }
+    
+    /// Compass point the wind is blowing toward, e.g. `E` for a wind carrying things to the right
+    pub fn wind_compass(&self) -> &'static str {
+        compass_point(self.wind_direction)
+    }
+    
+    /// Direction flying seeds have drifted over the last hundred or so ticks, in radians measured
+    /// like `wind_direction` (pass it to `compass_point` for a compass point), or None when no
+    /// seeds have flown lately. Gravity pulls every seed south, so compare the east-west lean
+    /// with the wind's
+    pub fn net_dispersal_direction(&self) -> Option<f32> {
+        let (x, y) = self.dispersal_drift;
+        (x.hypot(y) > 0.01).then(|| y.atan2(x).rem_euclid(2.0 * std::f32::consts::PI))
     }
     
-    pub fn get_seasonal_growth_modifier(&self) -> f32 {
+    /// Growth multiplier from season, temperature, and humidity for a plant with the given
+    /// genome growing in the given biome (before the biome's own growth modifier)
+    pub fn get_seasonal_growth_modifier(&self, genome: &PlantGenome, biome: Biome) -> f32 {
         // Base seasonal multipliers
         let season_multiplier = match self.get_current_season() {
             Season::Spring => 1.4,  // Peak growth season
@@ -304,19 +1437,25 @@ impl World {
         // Humidity effects (plants need moisture)
         let humidity_multiplier = 0.5 + self.humidity * 0.8; // 0.5 to 1.3 range
         
-        season_multiplier * temp_multiplier * humidity_multiplier
+        // Heritable traits: drought tolerance pays off where the ground is dry and costs growth
+        // where it's wet, and disease resistance costs a little growth everywhere
+        let dryness = (1.0 - self.humidity * biome.moisture_retention() * (1.0 - self.drought_stress())).clamp(0.0, 1.0);
+        let trait_multiplier = genome.growth_speed
+            * (1.0 + genome.drought_tolerance * (dryness - 0.5))
+            * (1.0 - genome.disease_resistance * 0.2);
+        
+        season_multiplier * temp_multiplier * humidity_multiplier * trait_multiplier
     }
     
     /// Generate biome map using regions and noise-like patterns
-    fn generate_biome_map(&mut self) {
-        let mut rng = rand::thread_rng();
+    fn generate_biome_map(&mut self, rng: &mut impl Rng) {
         
         // Divide world into regions and assign biomes
         let region_size = 8; // Each biome region is roughly 8x8 tiles
         
         for ry in 0..(self.height / region_size + 1) {
             for rx in 0..(self.width / region_size + 1) {
-                let biome = random_biome(&mut rng);
+                let biome = random_biome(rng);
                 
                 // Fill region with this biome, with some variation at edges
                 for y in (ry * region_size)..((ry + 1) * region_size).min(self.height) {
@@ -329,14 +1468,104 @@ impl World {
                             self.biome_map[y][x] = biome;
                         } else if rng.gen_bool(0.5) {
                             // Sometimes blend with neighboring biomes
-                            self.biome_map[y][x] = random_biome(&mut rng);
+                            self.biome_map[y][x] = random_biome(rng);
                         }
                     }
                 }
             }
         }
     }
-
+    
+    /// Gentle rolling hills: a few long waves with random phases, each smaller than the last
+    fn generate_elevation(&mut self, rng: &mut impl Rng) {
+        let waves: Vec<(f32, f32, f32)> = (1..=3)
+            .map(|i| (self.width as f32 / i as f32, 24.0 / i as f32, rng.gen_range(0.0..std::f32::consts::TAU)))
+            .collect();
+        for x in 0..self.width {
+            let height: f32 = waves.iter()
+                .map(|(wavelength, amplitude, phase)| amplitude * (1.0 + (std::f32::consts::TAU * x as f32 / wavelength + phase).sin()))
+                .sum();
+            for y in 0..self.height {
+                self.elevation[y][x] = height.round() as u8;
+            }
+        }
+    }
+    
+    /// Elevation at a position, or the highest possible outside the world so water never prefers the border
+    pub fn elevation_at(&self, x: i32, y: usize) -> u8 {
+        if x >= 0 && (x as usize) < self.width && y < self.height {
+            self.elevation[y][x as usize]
+        } else {
+            u8::MAX
+        }
+    }
+    
+    /// Replace the biome map, one biome per tile, row by row. Terrain already generated keeps
+    /// the flavour of the old biomes; `WorldBuilder::biome_map` generates terrain to match.
+    /// Fails with `InvalidInput` unless the map is exactly the world's size
+    pub fn set_biome_map(&mut self, map: Vec<Vec<Biome>>) -> io::Result<()> {
+        let width = map.first().map_or(0, Vec::len);
+        if map.len() != self.height || map.iter().any(|row| row.len() != self.width) {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, format!(
+                "the biome map is {}x{} but the world is {}x{}", width, map.len(), self.width, self.height)));
+        }
+        self.biome_map = map;
+        self.biome_pressure = vec![vec![0; self.width]; self.height];
+        Ok(())
+    }
+    
+    /// Let biomes follow the ground they cover, as `update` does every `BIOME_UPDATE_INTERVAL`
+    /// ticks: grassland that stays waterlogged becomes wetland, wetland that dries out becomes
+    /// grassland, and ground where sand piles up and plants keep dying becomes drylands. Each
+    /// call is one check, and a cell only changes once `BIOME_SHIFT_CHECKS` checks in a row favour it
+    pub fn update_biomes(&mut self) {
+        for y in 0..self.height {
+            for x in 0..self.width {
+                match self.favoured_biome(x, y) {
+                    Some(biome) => {
+                        self.biome_pressure[y][x] += 1;
+                        if self.biome_pressure[y][x] >= BIOME_SHIFT_CHECKS {
+                            self.biome_map[y][x] = biome;
+                            self.biome_pressure[y][x] = 0;
+                        }
+                    }
+                    None => self.biome_pressure[y][x] = 0,
+                }
+            }
+        }
+    }
+    
+    /// Biome the surroundings of a cell push it toward, if not its own. Only occupied cells
+    /// count, so open sky never changes a biome
+    fn favoured_biome(&self, x: usize, y: usize) -> Option<Biome> {
+        let (mut occupied, mut water, mut sand, mut living, mut dead) = (0, 0, 0, 0, 0);
+        for ny in y.saturating_sub(BIOME_RADIUS)..=(y + BIOME_RADIUS).min(self.height - 1) {
+            for nx in x.saturating_sub(BIOME_RADIUS)..=(x + BIOME_RADIUS).min(self.width - 1) {
+                match self.tiles[ny][nx] {
+                    TileType::Empty => continue,
+                    TileType::Water(_, _) => water += 1,
+                    TileType::Sand => sand += 1,
+                    TileType::PlantWithered(_, _) => dead += 1,
+                    tile if tile.is_plant() => living += 1,
+                    _ => {}
+                }
+                occupied += 1;
+            }
+        }
+        if occupied == 0 {
+            return None;
+        }
+        let share = |count: usize| count as f32 / occupied as f32;
+        
+        match self.biome_map[y][x] {
+            Biome::Grassland if share(water) >= WATERLOGGED_SHARE => Some(Biome::Wetland),
+            Biome::Wetland if share(water) < DRIED_OUT_SHARE => Some(Biome::Grassland),
+            Biome::Drylands => None,
+            _ if share(sand) >= DRYLANDS_SAND_SHARE && dead > living => Some(Biome::Drylands),
+            _ => None,
+        }
+    }
+    
     /// Get biome at a specific coordinate
     pub fn get_biome_at(&self, x: usize, y: usize) -> Biome {
         if x < self.width && y < self.height {
@@ -345,88 +1574,133 @@ impl World {
             Biome::Grassland // Default fallback
         }
     }
-
+    
+    /// Effective plant growth multiplier at a position: season, temperature, and humidity
+    /// combined with the local biome and the genome of the plant there (before the plant's
+    /// own size-based rate)
+    pub fn local_growth_rate(&self, x: usize, y: usize) -> f32 {
+        let biome = self.get_biome_at(x, y);
+        self.get_seasonal_growth_modifier(&self.genome_at(x, y), biome) * biome.plant_growth_modifier() * self.soil_fertility(x, y)
+    }
+    
+    /// Growth multiplier from the ground a plant stands in, found below it through its own stem and roots:
+    /// exhausted soil grows plants poorly
+    pub fn soil_fertility(&self, x: usize, y: usize) -> f32 {
+        let ground = (y + 1..self.height).map(|ny| self.tiles[ny][x]).find(|tile| !tile.is_plant());
+        if ground == Some(TileType::DepletedDirt) { DEPLETED_SOIL_GROWTH } else { 1.0 }
+    }
+    
     // Simplified stub implementations - these would be expanded from the original
-    fn generate_initial_world(&mut self) {
-        let mut rng = rand::thread_rng();
+    fn generate_initial_world(&mut self, rng: &mut impl Rng) {
+        let config = self.world_config;
+        // Leave at least a few rows of sky above the terrain for plants and pillbugs
+        let soil_depth = config.soil_depth.min(self.height.saturating_sub(5)).max(3).min(self.height);
+        let surface = self.height - soil_depth;
+        // Rows starting plants, nutrients, and pillbugs go in; on worlds too small for them they're left out
+        let plant_rows = surface.saturating_sub(2)..self.height.saturating_sub(3);
+        let nutrient_rows = surface.saturating_sub(5)..self.height.saturating_sub(2);
+        let pillbug_rows = surface.saturating_sub(2)..self.height.saturating_sub(2);
+        let plants = if plant_rows.is_empty() { 0 } else { config.initial_plants };
+        let nutrients = if nutrient_rows.is_empty() { 0 } else { config.initial_nutrients };
+        let pillbugs = if pillbug_rows.is_empty() || self.width < 5 { 0 } else { config.initial_pillbugs };
         
-        // Create varied terrain with dirt and sand based on biome preferences
-        for y in (self.height - 10)..self.height {
+        // Create layered terrain: biome-flavored topsoil over subsoil over parent rock
+        for y in surface..self.height {
             for x in 0..self.width {
                 let biome = self.get_biome_at(x, y);
                 let (dirt_pref, sand_pref) = biome.get_terrain_preferences();
                 let depth = self.height - y;
                 
-                if depth <= 2 {
-                    // Top layers influenced by biome
-                    if rng.gen_bool(sand_pref as f64) {
-                        self.tiles[y][x] = TileType::Sand;
-                    } else if rng.gen_bool(dirt_pref as f64) {
-                        self.tiles[y][x] = TileType::Dirt;
-                    }
-                } else if depth <= 5 {
-                    // Middle layers mostly follow biome preferences but favor dirt
-                    let dirt_chance = (dirt_pref * 0.85 + 0.15).min(0.95);
-                    let sand_chance = sand_pref * 0.5;
-                    
-                    if rng.gen_bool(dirt_chance as f64) {
-                        self.tiles[y][x] = TileType::Dirt;
-                    } else if rng.gen_bool(sand_chance as f64) {
-                        self.tiles[y][x] = TileType::Sand;
-                    }
+                let layer = if depth + 2 >= soil_depth {
+                    SoilLayer::Topsoil
+                } else if depth + 5 >= soil_depth {
+                    SoilLayer::Subsoil
                 } else {
-                    // Deep layers mostly dirt but still biome-influenced
-                    let dirt_chance = (dirt_pref * 0.1 + 0.85).min(0.98);
-                    if rng.gen_bool(dirt_chance as f64) {
-                        self.tiles[y][x] = TileType::Dirt;
+                    SoilLayer::ParentRock
+                };
+                
+                let tile = match layer {
+                    SoilLayer::Topsoil => {
+                        // Top layers influenced by biome, with some organic richness
+                        if rng.gen_bool(sand_pref as f64) {
+                            TileType::Sand
+                        } else if rng.gen_bool(dirt_pref as f64) {
+                            if rng.gen_bool((0.15 * biome.nutrient_modifier()).min(1.0) as f64) {
+                                TileType::NutrientDirt(60)
+                            } else {
+                                TileType::Dirt
+                            }
+                        } else {
+                            TileType::Empty
+                        }
+                    }
+                    SoilLayer::Subsoil => {
+                        // Dense dirt mixed with clay, a little sand in sandy biomes
+                        let dirt_chance = (dirt_pref * 0.85 + 0.15).min(0.95);
+                        if rng.gen_bool(0.3) {
+                            TileType::Clay
+                        } else if rng.gen_bool(dirt_chance as f64) {
+                            TileType::Dirt
+                        } else if rng.gen_bool((sand_pref * 0.5) as f64) {
+                            TileType::Sand
+                        } else {
+                            TileType::Empty
+                        }
+                    }
+                    SoilLayer::ParentRock => {
+                        // Mostly rock with clay seams
+                        if rng.gen_bool(0.75) { TileType::Rock } else { TileType::Clay }
                     }
+                };
+                
+                if tile != TileType::Empty {
+                    self.tiles[y][x] = tile;
+                    self.soil_layers[y][x] = Some(layer);
                 }
             }
         }
         
         // Add some sand dunes/piles
-        for _ in 0..3 {
+        let dunes = if self.width > 10 && surface > 0 { 3 } else { 0 };
+        for _ in 0..dunes {
             let x = rng.gen_range(5..self.width - 5);
-            let y = self.height - 11;
+            let y = surface - 1;
             for dx in -2..=2 {
                 for dy in 0..=1 {
                     let nx = (x as i32 + dx) as usize;
                     let ny = y + dy;
                     if nx < self.width && ny < self.height && rng.gen_bool(0.6) {
                         self.tiles[ny][nx] = TileType::Sand;
+                        self.soil_layers[ny][nx] = Some(SoilLayer::Topsoil);
                     }
                 }
             }
         }
         
         // Add initial plants based on biome preferences
-        let base_plant_count = 8; // More plants than before to show biome differences
-        for _ in 0..base_plant_count {
+        for _ in 0..plants {
             let x = rng.gen_range(0..self.width);
-            let y = rng.gen_range(self.height - 12..self.height - 3);
+            let y = rng.gen_range(plant_rows.clone());
             if self.tiles[y][x] == TileType::Empty {
                 let biome = self.get_biome_at(x, y);
-                let plant_chance = biome.plant_growth_modifier() * 0.6; // Base 60% chance
+                let plant_chance = (biome.plant_growth_modifier() * config.plant_density).clamp(0.0, 1.0);
                 
                 if rng.gen_bool(plant_chance as f64) {
-                    let size = random_size(&mut rng);
+                    let size = random_size(rng);
                     self.tiles[y][x] = TileType::PlantStem(10, size);
                     
                     // In Woodland biomes, sometimes add immediate roots
-                    if biome == Biome::Woodland && rng.gen_bool(0.4) {
-                        if y + 1 < self.height && self.tiles[y + 1][x] != TileType::Empty {
-                            self.tiles[y + 1][x] = TileType::PlantRoot(5, size);
-                        }
+                    if biome == Biome::Woodland && rng.gen_bool(0.4) && y + 1 < self.height && self.tiles[y + 1][x] != TileType::Empty {
+                        self.tiles[y + 1][x] = TileType::PlantRoot(5, size);
                     }
                 }
             }
         }
         
         // Add nutrients based on biome richness
-        let base_nutrient_count = 10;
-        for _ in 0..base_nutrient_count {
+        for _ in 0..nutrients {
             let x = rng.gen_range(0..self.width);
-            let y = rng.gen_range(self.height - 15..self.height - 2);
+            let y = rng.gen_range(nutrient_rows.clone());
             if self.tiles[y][x] == TileType::Empty {
                 let biome = self.get_biome_at(x, y);
                 let nutrient_chance = biome.nutrient_modifier() * 0.5; // Base 50% chance
@@ -438,19 +1712,18 @@ impl World {
         }
         
         // Add a few initial pillbugs with full body segments
-        for _ in 0..2 {
+        for _ in 0..pillbugs {
             let x = rng.gen_range(2..self.width - 2);
-            let y = rng.gen_range(self.height - 12..self.height - 2);
+            let y = rng.gen_range(pillbug_rows.clone());
             if self.tiles[y][x] == TileType::Empty {
-                let size = random_size(&mut rng);
+                let size = random_size(rng);
                 self.spawn_pillbug(x, y, size, 20);
             }
         }
     }
     
-    fn spawn_rain(&mut self) {
+    fn spawn_rain(&mut self, rng: &mut impl Rng) {
         if self.rain_intensity > 0.1 {
-            let mut rng = rand::thread_rng();
             let drops = (self.rain_intensity * self.width as f32 * 0.1) as usize;
             for _ in 0..drops {
                 let x = rng.gen_range(0..self.width);
@@ -461,9 +1734,13 @@ impl World {
                     
                     // Higher chance for rain to "stick" in wetlands, lower in drylands
                     if rng.gen_bool((accumulation_bonus * 0.8).min(1.0) as f64) {
-                        // Rain starts with moderate depth
+                        // Rain starts with moderate depth; in a cold winter it falls as snow holding the same water
                         let rain_depth = (50.0 + self.rain_intensity * 100.0) as u8;
-                        self.tiles[0][x] = TileType::Water(rain_depth);
+                        self.tiles[0][x] = if self.get_current_season() == Season::Winter && self.temperature < SNOW_TEMPERATURE {
+                            TileType::Snow(rain_depth)
+                        } else {
+                            TileType::Water(rain_depth, CLEAN_WATER)
+                        };
                     }
                 }
             }
@@ -481,27 +1758,238 @@ impl World {
     
     // Helper to queue a tile change for later application
     fn queue_tile_change(&mut self, x: usize, y: usize, new_tile: TileType) {
-        if x < self.width && y < self.height {
-            let old_tile = self.tiles[y][x];
-            if old_tile != new_tile {
-                self.tile_changes.push(TileChange::new(x, y, old_tile, new_tile));
+        if x < self.width && y < self.height && self.tiles[y][x] != new_tile {
+            self.tile_changes.push(TileChange::new(x, y, new_tile));
+        }
+    }
+    
+    /// Map a possibly out-of-bounds position back into the world: past the east or west border
+    /// according to `edge_behavior`, and onto the floor or ceiling, which are solid whatever the
+    /// edge behavior. Returns None when the position is past a side border and the edges absorb.
+    pub fn resolve_edge(&self, x: i32, y: i32) -> Option<(usize, usize)> {
+        let (width, height) = (self.width as i32, self.height as i32);
+        let x = if self.wrap_horizontal { x.rem_euclid(width) } else { x };
+        let y = y.clamp(0, height - 1) as usize;
+        match self.edge_behavior {
+            EdgeBehavior::Absorb => (0..width).contains(&x).then_some((x as usize, y)),
+            EdgeBehavior::Reflect => {
+                let x = if x < 0 { (-x).min(width - 1) } else if x >= width { (2 * (width - 1) - x).max(0) } else { x };
+                Some((x as usize, y))
+            }
+            EdgeBehavior::Wrap => Some((x.rem_euclid(width) as usize, y)),
+        }
+    }
+    
+    /// The cell `dx, dy` away from a position, or None when that's outside the world. The sum is
+    /// done in signed arithmetic, so a step off the west or north border never wraps around to a
+    /// huge index
+    pub fn offset(&self, x: usize, y: usize, dx: i32, dy: i32) -> Option<(usize, usize)> {
+        let (nx, ny) = (x as i32 + dx, y as i32 + dy);
+        (nx >= 0 && ny >= 0 && (nx as usize) < self.width && (ny as usize) < self.height).then_some((nx as usize, ny as usize))
+    }
+    
+    /// Like `offset`, but wrapped around to the other side when it's past the east or west
+    /// border and `wrap_horizontal` is set
+    pub fn neighbor(&self, x: usize, y: usize, dx: i32, dy: i32) -> Option<(usize, usize)> {
+        if self.wrap_horizontal {
+            let nx = (x as i32 + dx).rem_euclid(self.width as i32) as usize;
+            self.offset(nx, y, 0, dy)
+        } else {
+            self.offset(x, y, dx, dy)
+        }
+    }
+    
+    /// Stratum of the ground at a position, if it is ground that came from a labeled layer
+    pub fn soil_layer_at(&self, x: usize, y: usize) -> Option<SoilLayer> {
+        if x < self.width && y < self.height && self.tiles[y][x].is_ground() {
+            self.soil_layers[y][x]
+        } else {
+            None
+        }
+    }
+    
+    /// Heritable traits of the plant or seed at a position
+    pub fn genome_at(&self, x: usize, y: usize) -> PlantGenome {
+        self.genomes[y][x].unwrap_or_default()
+    }
+    
+    /// Everything known about a cell, one fact per line: the tile and its age, size, or depth,
+    /// the plant's species and genome or the pillbug's state, the ground and light there,
+    /// and what lives within `DESCRIBE_RADIUS` cells of it
+    pub fn describe_tile(&self, x: usize, y: usize) -> String {
+        let tile = self.tiles[y][x];
+        let mut lines = vec![format!("{} at ({}, {})", tile.name(), x, y)];
+        match tile {
+            TileType::Water(depth, quality) => lines.push(format!("Depth: {} | Quality: {}", depth, quality)),
+            TileType::NutrientDirt(level) | TileType::Mycelium(level) => lines.push(format!("Nutrients: {}", level)),
+            TileType::Empty | TileType::Dirt | TileType::DepletedDirt | TileType::Sand | TileType::Clay | TileType::Rock | TileType::Nutrient => {}
+            _ => lines.push(format!("Age: {}", tile.value())),
+        }
+        if let Some(size) = tile.get_size() {
+            lines.push(format!("Size: {:?}", size));
+        }
+        if tile.is_plant() || matches!(tile, TileType::Seed(_, _)) {
+            let genome = self.genome_at(x, y);
+            if tile.is_plant() {
+                lines.push(format!("Species: {}", self.species_at(x, y).name()));
             }
+            lines.push(format!("Genome: speed {:.2} / drought {:.2} / disease {:.2}", genome.growth_speed, genome.drought_tolerance, genome.disease_resistance));
         }
+        if let Some(state) = self.pillbug_states.get(&(x, y)).filter(|_| matches!(tile, TileType::PillbugHead(_, _) | TileType::PillbugRolled(_, _))) {
+            lines.push(format!("Energy: {} | Meals: {}", state.energy, state.meals));
+            if state.rolled > 0 {
+                lines.push(format!("Rolled up for {} more ticks", state.rolled));
+            }
+            if state.soft > 0 {
+                lines.push(format!("Soft-shelled from molting for {} more ticks", state.soft));
+            }
+            if let Some((seed, _)) = state.seed {
+                lines.push(format!("Carrying a {} for {} moves", seed.name(), state.carried_for));
+            }
+        }
+        
+        lines.push(format!("Biome: {} | Soil layer: {}", self.get_biome_at(x, y).name(), self.soil_layer_at(x, y).map_or("-", |layer| layer.name())));
+        lines.push(format!("Elevation: {} | Disturbance: {:.2} | Light: {:.2}", self.elevation[y][x], self.disturbance[y][x], self.light_at(x, y)));
+        
+        let (mut plants, mut pillbugs, mut beetles, mut water) = (0, 0, 0, 0);
+        for ny in y.saturating_sub(DESCRIBE_RADIUS)..=(y + DESCRIBE_RADIUS).min(self.height - 1) {
+            for nx in x.saturating_sub(DESCRIBE_RADIUS)..=(x + DESCRIBE_RADIUS).min(self.width - 1) {
+                match self.tiles[ny][nx] {
+                    tile if tile.is_plant() => plants += 1,
+                    TileType::PillbugHead(_, _) | TileType::PillbugRolled(_, _) => pillbugs += 1,
+                    TileType::BeetleHead(_, _) => beetles += 1,
+                    TileType::Water(_, _) => water += 1,
+                    _ => {}
+                }
+            }
+        }
+        lines.push(format!("Within {}: {} plant tiles, {} pillbugs, {} beetles, {} water tiles", DESCRIBE_RADIUS, plants, pillbugs, beetles, water));
+        lines.join("\n")
+    }
+    
+    /// Growth form of the plant at a position; plants that never germinated from a seed
+    /// (generated or placed) are the native species of their biome
+    pub fn species_at(&self, x: usize, y: usize) -> PlantSpecies {
+        self.species[y][x].unwrap_or_else(|| PlantSpecies::for_biome(self.get_biome_at(x, y)))
+    }
+    
+    /// Character a tile is drawn with, in its species' form for plants
+    pub fn tile_char(&self, x: usize, y: usize) -> char {
+        let tile = self.tiles[y][x];
+        if tile.is_plant() {
+            self.species_at(x, y).to_char_modifier(tile, tile.to_char())
+        } else {
+            tile.to_char()
+        }
+    }
+    
+    /// Color a tile is drawn with, tinted by species for plants
+    pub fn tile_color(&self, x: usize, y: usize) -> Color {
+        let tile = self.tiles[y][x];
+        if tile.is_plant() {
+            self.species_at(x, y).tint(tile.to_color())
+        } else {
+            tile.to_color()
+        }
+    }
+    
+    /// Average traits of the plants (counted by stem) growing in a biome, if there are any
+    pub fn mean_genome(&self, biome: Biome) -> Option<PlantGenome> {
+        let mut total = PlantGenome { growth_speed: 0.0, drought_tolerance: 0.0, disease_resistance: 0.0 };
+        let mut count = 0;
+        for y in 0..self.height {
+            for x in 0..self.width {
+                if matches!(self.tiles[y][x], TileType::PlantStem(_, _)) && self.biome_map[y][x] == biome {
+                    let genome = self.genome_at(x, y);
+                    total.growth_speed += genome.growth_speed;
+                    total.drought_tolerance += genome.drought_tolerance;
+                    total.disease_resistance += genome.disease_resistance;
+                    count += 1;
+                }
+            }
+        }
+        if count == 0 {
+            return None;
+        }
+        let count = count as f32;
+        Some(PlantGenome {
+            growth_speed: total.growth_speed / count,
+            drought_tolerance: total.drought_tolerance / count,
+            disease_resistance: total.disease_resistance / count,
+        })
+    }
+    
+    /// Carry a seed's genome along when the seed is moved (falling, blown by wind)
+    fn move_genome(&mut self, from: (usize, usize), to: (usize, usize)) {
+        let genome = self.genomes[from.1][from.0].take();
+        self.genomes[to.1][to.0] = genome;
     }
     
-    fn update_physics(&mut self) {
+    /// New plant parts take the genome and species of the plant they grew from; cells that no
+    /// longer hold a plant or seed forget theirs (seeds have no species until they germinate)
+    fn update_genomes(&mut self) {
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let tile = self.tiles[y][x];
+                if !tile.is_plant() {
+                    self.species[y][x] = None;
+                } else if self.species[y][x].is_none() {
+                    'parent: for dy in -1i32..=1 {
+                        for dx in -1i32..=1 {
+                            let Some((nx, ny)) = self.neighbor(x, y, dx, dy) else {
+                                continue;
+                            };
+                            if self.tiles[ny][nx].is_plant() && self.species[ny][nx].is_some() {
+                                self.species[y][x] = self.species[ny][nx];
+                                break 'parent;
+                            }
+                        }
+                    }
+                }
+                if !tile.is_plant() && !matches!(tile, TileType::Seed(_, _)) {
+                    self.genomes[y][x] = None;
+                } else if self.genomes[y][x].is_none() {
+                    'parent: for dy in -1i32..=1 {
+                        for dx in -1i32..=1 {
+                            let Some((nx, ny)) = self.neighbor(x, y, dx, dy) else {
+                                continue;
+                            };
+                            if self.tiles[ny][nx].is_plant() && self.genomes[ny][nx].is_some() {
+                                self.genomes[y][x] = self.genomes[ny][nx];
+                                break 'parent;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    
+    /// Carry a stratum label along with ground material that moved (falling, erosion, deposition)
+    pub(crate) fn move_soil_layer(&mut self, from: (usize, usize), to: (usize, usize)) {
+        let layer = self.soil_layers[from.1][from.0].take();
+        self.soil_layers[to.1][to.0] = layer;
+    }
+    
+    fn update_physics(&mut self, rng: &mut impl Rng) {
         let mut new_tiles = self.tiles.clone();
-        let mut rng = rand::thread_rng();
+        let mut budget = WaterBudget::default();
+        let water_before = total_water_in(&self.tiles);
         
-        // Process physics from bottom to top for proper stacking
+        // Process physics from bottom to top for proper stacking. Water that flows sideways
+        // can flow again when the scan reaches it, so each row is swept in a random direction
+        // to keep lakes from drifting toward whichever side is visited last
         for y in (0..self.height - 1).rev() {
-            for x in 0..self.width {
+            let reversed = rng.gen_bool(0.5);
+            for i in 0..self.width {
+                let x = if reversed { self.width - 1 - i } else { i };
                 match self.tiles[y][x] {
                     TileType::Sand => {
                         // Sand falls straight down or diagonally to form piles
                         if new_tiles[y + 1][x] == TileType::Empty {
                             new_tiles[y][x] = TileType::Empty;
                             new_tiles[y + 1][x] = TileType::Sand;
+                            self.move_soil_layer((x, y), (x, y + 1));
                         } else if new_tiles[y + 1][x].blocks_water() {
                             // Try to slide diagonally if blocked
                             // Randomly choose left or right first for natural piling
@@ -511,44 +1999,68 @@ impl World {
                                 vec![(1, 1), (-1, 1)]
                             };
                             
+                            // Sand in an edge column only slides inward, unless the sides wrap
                             for (dx, dy) in directions {
-                                let nx = (x as i32 + dx) as usize;
-                                let ny = y + dy;
-                                if nx < self.width && ny < self.height {
-                                    if new_tiles[ny][nx] == TileType::Empty {
-                                        new_tiles[y][x] = TileType::Empty;
-                                        new_tiles[ny][nx] = TileType::Sand;
-                                        break;
-                                    }
+                                let Some((nx, ny)) = self.neighbor(x, y, dx, dy) else { continue };
+                                if new_tiles[ny][nx] == TileType::Empty {
+                                    new_tiles[y][x] = TileType::Empty;
+                                    new_tiles[ny][nx] = TileType::Sand;
+                                    self.move_soil_layer((x, y), (nx, ny));
+                                    break;
                                 }
                             }
                         }
                     }
-                    TileType::Water(depth) => {
-                        self.process_water_physics(x, y, depth, &mut new_tiles, &mut rng);
+                    TileType::Water(_, _) => {
+                        self.process_water_physics(x, y, &mut new_tiles, &mut budget, rng);
+                    }
+                    TileType::Snow(_) => {
+                        self.process_snow_physics(x, y, &mut new_tiles, &mut budget, rng);
+                    }
+                    TileType::Sediment(_) => {
+                        self.process_sediment(x, y, &mut new_tiles, rng);
                     }
                     _ => {}
                 }
             }
         }
         
+        // Flowing only moves water around; everything else that changed the total is on the books
+        debug_assert_eq!(
+            water_before + budget.melted,
+            total_water_in(&new_tiles) + budget.evaporated + budget.absorbed,
+            "water physics created or destroyed water"
+        );
+        self.evaporated = budget.evaporated as u32;
+        
+        // Deep standing water floods the ground it covers
+        for (y, row) in new_tiles.iter().enumerate() {
+            for (x, tile) in row.iter().enumerate() {
+                if tile.get_water_depth().is_some_and(|depth| depth >= FLOOD_DEPTH) {
+                    self.disturb(x, y, FLOOD_DISTURBANCE);
+                }
+            }
+        }
+        
         self.tiles = new_tiles;
     }
     
-    /// Update seed projectiles flying through the air
+    /// Update seed projectiles flying through the air. Landings are queued and applied together
+    /// at the end, and each cell takes at most one seed per tick
     fn update_seed_projectiles(&mut self) {
-        let mut i = 0;
+        let mut flying = Vec::with_capacity(self.seed_projectiles.len());
+        let mut claimed = HashSet::new();
+        self.dispersal_drift.0 *= DISPERSAL_MEMORY;
+        self.dispersal_drift.1 *= DISPERSAL_MEMORY;
         
         // Process each projectile
-        while i < self.seed_projectiles.len() {
-            let mut projectile = self.seed_projectiles[i].clone();
-            
+        for mut projectile in std::mem::take(&mut self.seed_projectiles) {
             // Apply gravity
             projectile.velocity_y += 0.2; // Gravity acceleration
             
             // Apply wind effects
-            let wind_x = self.wind_direction.cos() * self.wind_strength * 0.3;
-            let wind_y = self.wind_direction.sin() * self.wind_strength * 0.3;
+            let (wind_x, wind_y) = self.wind_vector();
+            let (wind_x, wind_y) = (wind_x * 0.3, wind_y * 0.3);
             
             // Wind affects lighter seeds more
             if let TileType::Seed(_, size) = projectile.seed_type {
@@ -564,80 +2076,116 @@ impl World {
             // Update position
             projectile.x += projectile.velocity_x;
             projectile.y += projectile.velocity_y;
+            self.dispersal_drift.0 += projectile.velocity_x;
+            self.dispersal_drift.1 += projectile.velocity_y;
             
-            // Check bounds
-            if projectile.x < 0.0 || projectile.x >= self.width as f32 || 
-               projectile.y < 0.0 || projectile.y >= self.height as f32 {
-                // Remove projectile that went out of bounds
-                self.seed_projectiles.remove(i);
-                continue;
+            // The ceiling stops a seed thrown up against it, and one that reaches the floor comes
+            // to rest there, whatever the edge behavior
+            let (width, height) = (self.width as f32, self.height as f32);
+            if projectile.y < 0.0 {
+                projectile.y = 0.0;
+                projectile.velocity_y = 0.0;
+            }
+            let on_floor = projectile.y >= height;
+            if on_floor {
+                projectile.y = height - 0.01;
+            }
+            
+            // Check the side borders, after carrying seeds that cross them around
+            if self.wrap_horizontal {
+                projectile.x = projectile.x.rem_euclid(width);
+            }
+            if projectile.x < 0.0 || projectile.x >= width {
+                match self.edge_behavior {
+                    EdgeBehavior::Absorb => {
+                        // Drop projectile that went out of bounds
+                        continue;
+                    }
+                    EdgeBehavior::Reflect => {
+                        // Bounce off the border, losing half the speed across it
+                        projectile.x = if projectile.x < 0.0 { -projectile.x } else { 2.0 * width - projectile.x - 0.01 };
+                        projectile.velocity_x *= -0.5;
+                        projectile.x = projectile.x.clamp(0.0, width - 0.01);
+                    }
+                    EdgeBehavior::Wrap => {
+                        projectile.x = projectile.x.rem_euclid(width);
+                    }
+                }
             }
             
             let tile_x = projectile.x.floor() as usize;
             let tile_y = projectile.y.floor() as usize;
             
+            // A cell another seed landed in this tick is as solid as the seed in it
+            let tile = if claimed.contains(&(tile_x, tile_y)) { projectile.seed_type } else { self.tiles[tile_y][tile_x] };
+            
             // Check for collision
-            match self.tiles[tile_y][tile_x] {
+            let bounces = match tile {
+                TileType::Empty if on_floor => false,
                 TileType::Empty => {
                     // Continue flying
-                    self.seed_projectiles[i] = projectile;
-                    i += 1;
-                }
-                TileType::Water(_) => {
-                    // Seed lands in water, stops moving but stays alive
-                    self.tiles[tile_y][tile_x] = projectile.seed_type;
-                    self.seed_projectiles.remove(i);
-                }
-                _ => {
-                    // Hit solid object - try to bounce or stop
-                    if projectile.bounce_count < 2 && projectile.velocity_y > 1.0 {
-                        // Bounce with reduced velocity
-                        projectile.velocity_y = -projectile.velocity_y * 0.4;
-                        projectile.velocity_x *= 0.7;
-                        projectile.bounce_count += 1;
-                        
-                        // Move slightly away from collision point
-                        if projectile.velocity_y > 0.0 {
-                            projectile.y = tile_y as f32 + 1.1;
-                        } else {
-                            projectile.y = tile_y as f32 - 0.1;
-                        }
-                        
-                        self.seed_projectiles[i] = projectile;
-                        i += 1;
-                    } else {
-                        // Find empty adjacent space to land
-                        let adjacent_positions = [
-                            (tile_x, tile_y.saturating_sub(1)),
-                            (tile_x.saturating_sub(1), tile_y),
-                            (tile_x.saturating_add(1).min(self.width - 1), tile_y),
-                            (tile_x, tile_y.saturating_add(1).min(self.height - 1)),
-                        ];
-                        
-                        let mut landed = false;
-                        for (ax, ay) in adjacent_positions.iter() {
-                            if self.tiles[*ay][*ax] == TileType::Empty {
-                                self.tiles[*ay][*ax] = projectile.seed_type;
-                                landed = true;
-                                break;
-                            }
-                        }
-                        
-                        if !landed {
-                            // No space to land, seed is destroyed
-                            // Could become nutrient instead if we want
-                        }
-                        
-                        self.seed_projectiles.remove(i);
-                    }
+                    flying.push(projectile);
+                    continue;
                 }
+                // Seeds float: water stops them dead without being displaced
+                TileType::Water(_, _) => false,
+                // Hit solid object - try to bounce or stop
+                _ => projectile.bounce_count < 2 && projectile.velocity_y > 1.0,
+            };
+            
+            if bounces {
+                // Bounce with reduced velocity
+                projectile.velocity_y = -projectile.velocity_y * 0.4;
+                projectile.velocity_x *= 0.7;
+                projectile.bounce_count += 1;
+                
+                // Move slightly away from collision point
+                if projectile.velocity_y > 0.0 {
+                    projectile.y = tile_y as f32 + 1.1;
+                } else {
+                    projectile.y = tile_y as f32 - 0.1;
+                }
+                
+                flying.push(projectile);
+                continue;
+            }
+            
+            // Land in the cell itself when it's open floor, or else find empty adjacent space;
+            // with none free the seed is lost
+            let adjacent_positions = [
+                (tile_x, tile_y),
+                (tile_x, tile_y.saturating_sub(1)),
+                (tile_x.saturating_sub(1), tile_y),
+                (tile_x.saturating_add(1).min(self.width - 1), tile_y),
+                (tile_x, tile_y.saturating_add(1).min(self.height - 1)),
+            ];
+            if let Some(&(ax, ay)) = adjacent_positions.iter().find(|&&(ax, ay)| self.tiles[ay][ax] == TileType::Empty && !claimed.contains(&(ax, ay))) {
+                claimed.insert((ax, ay));
+                self.queue_tile_change(ax, ay, projectile.seed_type);
+                self.genomes[ay][ax] = Some(projectile.genome);
             }
         }
+        
+        self.seed_projectiles = flying;
+        self.apply_tile_changes();
+    }
+    
+    /// Shoot a seed into the air from a position with the given velocity in tiles per tick,
+    /// as a flower does when it sets seed
+    pub fn launch_seed(&mut self, x: f32, y: f32, velocity_x: f32, velocity_y: f32, size: Size) {
+        self.seed_projectiles.push(SeedProjectile {
+            x,
+            y,
+            velocity_x,
+            velocity_y,
+            seed_type: TileType::Seed(0, size),
+            bounce_count: 0,
+            genome: PlantGenome::default(),
+        });
     }
     
     /// Apply gravity to unsupported entities (pillbugs and loose objects) - OPTIMIZED
-    fn apply_gravity(&mut self) {
-        let mut rng = rand::thread_rng();
+    fn apply_gravity(&mut self, rng: &mut impl Rng) {
         let mut processed_positions = HashSet::new();
         
         // OPTIMIZATION: Collect potentially unstable entities first, skip others entirely  
@@ -647,15 +2195,15 @@ impl World {
         for y in 0..self.height.saturating_sub(1) {
             for x in 0..self.width {
                 match self.tiles[y][x] {
-                    tile if tile.is_pillbug() => {
+                    tile if tile.is_pillbug() || tile.is_beetle() => {
                         // Quick stability check - if directly supported, skip expensive group analysis
                         if y + 1 < self.height {
                             let below = self.tiles[y + 1][x];
-                            if below.can_support_plants() || below.is_plant() || below.is_pillbug() {
+                            if below.can_support_plants() || below.is_plant() || below.is_pillbug() || below.is_beetle() {
                                 continue; // Obviously supported, skip
                             }
                         }
-                        unstable_entities.push((x, y, "pillbug"));
+                        unstable_entities.push((x, y, if tile.is_beetle() { "beetle" } else { "pillbug" }));
                     }
                     tile if tile.is_plant() => {
                         // MAJOR OPTIMIZATION: Skip roots that are deep underground (bottom 25% of world)
@@ -692,35 +2240,48 @@ impl World {
             }
             
             match entity_type {
-                "pillbug" => {
-                    let connected_segments = self.find_connected_pillbug_segments(x, y);
-                    if self.is_pillbug_group_unsupported(&connected_segments) {
-                        if self.can_move_group_down_simple(&connected_segments) {
-                            // Queue moves instead of modifying directly
-                            for (seg_x, seg_y, tile) in &connected_segments {
-                                self.queue_tile_change(*seg_x, *seg_y, TileType::Empty);
-                                self.queue_tile_change(*seg_x, seg_y + 1, *tile);
+                "pillbug" | "beetle" => {
+                    let is_segment = if entity_type == "beetle" { TileType::is_beetle } else { TileType::is_pillbug };
+                    let connected_segments = self.find_connected_segments(x, y, is_segment);
+                    if self.is_group_unsupported(&connected_segments, is_segment) && self.can_move_group_down_simple(&connected_segments, true) {
+                        // Water the group sinks into rises into the top cell it leaves in that column
+                        let mut displaced = Vec::new();
+                        for (seg_x, seg_y, _) in &connected_segments {
+                            if let water @ TileType::Water(_, _) = self.tiles[seg_y + 1][*seg_x] {
+                                let top = connected_segments.iter().filter(|(x, _, _)| x == seg_x).map(|(_, y, _)| *y).min().unwrap_or(*seg_y);
+                                displaced.push((*seg_x, top, water));
                             }
-                            // Mark all segments as processed
-                            for (seg_x, seg_y, _) in &connected_segments {
-                                processed_positions.insert((*seg_x, *seg_y));
+                        }
+                        // Queue moves instead of modifying directly
+                        for (seg_x, seg_y, tile) in &connected_segments {
+                            self.queue_tile_change(*seg_x, *seg_y, TileType::Empty);
+                            self.queue_tile_change(*seg_x, seg_y + 1, *tile);
+                            if let TileType::PillbugHead(_, _) | TileType::PillbugRolled(_, _) = tile {
+                                if let Some(state) = self.pillbug_states.remove(&(*seg_x, *seg_y)) {
+                                    self.pillbug_states.insert((*seg_x, seg_y + 1), state);
+                                }
                             }
                         }
+                        for (water_x, water_y, water) in displaced {
+                            self.queue_tile_change(water_x, water_y, water);
+                        }
+                        // Mark all segments as processed
+                        for (seg_x, seg_y, _) in &connected_segments {
+                            processed_positions.insert((*seg_x, *seg_y));
+                        }
                     }
                 }
                 "plant" => {
                     let connected_plant_parts = self.find_connected_plant_parts(x, y);
-                    if self.is_plant_group_unsupported(&connected_plant_parts) {
-                        if self.can_move_group_down_simple(&connected_plant_parts) {
-                            // Queue moves instead of modifying directly
-                            for (part_x, part_y, tile) in &connected_plant_parts {
-                                self.queue_tile_change(*part_x, *part_y, TileType::Empty);
-                                self.queue_tile_change(*part_x, part_y + 1, *tile);
-                            }
-                            // Mark all parts as processed
-                            for (part_x, part_y, _) in &connected_plant_parts {
-                                processed_positions.insert((*part_x, *part_y));
-                            }
+                    if self.is_plant_group_unsupported(&connected_plant_parts) && self.can_move_group_down_simple(&connected_plant_parts, false) {
+                        // Queue moves instead of modifying directly
+                        for (part_x, part_y, tile) in &connected_plant_parts {
+                            self.queue_tile_change(*part_x, *part_y, TileType::Empty);
+                            self.queue_tile_change(*part_x, part_y + 1, *tile);
+                        }
+                        // Mark all parts as processed
+                        for (part_x, part_y, _) in &connected_plant_parts {
+                            processed_positions.insert((*part_x, *part_y));
                         }
                     }
                 }
@@ -732,23 +2293,18 @@ impl World {
         for y in (0..self.height - 1).rev() {
             for x in 0..self.width {
                 match self.tiles[y][x] {
-                    TileType::Seed(age, size) => {
-                        if self.tiles[y + 1][x] == TileType::Empty && rng.gen_bool(0.6) {
-                            self.queue_tile_change(x, y, TileType::Empty);
-                            self.queue_tile_change(x, y + 1, TileType::Seed(age, size));
-                        }
+                    TileType::Seed(age, size) if self.tiles[y + 1][x] == TileType::Empty && rng.gen_bool(self.physics_config.seed_fall_chance.clamp(0.0, 1.0) as f64) => {
+                        self.queue_tile_change(x, y, TileType::Empty);
+                        self.queue_tile_change(x, y + 1, TileType::Seed(age, size));
+                        self.move_genome((x, y), (x, y + 1));
                     }
-                    TileType::Spore(age) => {
-                        if self.tiles[y + 1][x] == TileType::Empty && rng.gen_bool(0.3) {
-                            self.queue_tile_change(x, y, TileType::Empty);
-                            self.queue_tile_change(x, y + 1, TileType::Spore(age));
-                        }
+                    TileType::Spore(age) if self.tiles[y + 1][x] == TileType::Empty && rng.gen_bool(self.physics_config.spore_fall_chance.clamp(0.0, 1.0) as f64) => {
+                        self.queue_tile_change(x, y, TileType::Empty);
+                        self.queue_tile_change(x, y + 1, TileType::Spore(age));
                     }
-                    TileType::Nutrient => {
-                        if self.tiles[y + 1][x] == TileType::Empty && rng.gen_bool(0.2) {
-                            self.queue_tile_change(x, y, TileType::Empty);
-                            self.queue_tile_change(x, y + 1, TileType::Nutrient);
-                        }
+                    TileType::Nutrient if self.tiles[y + 1][x] == TileType::Empty && rng.gen_bool(self.physics_config.nutrient_fall_chance.clamp(0.0, 1.0) as f64) => {
+                        self.queue_tile_change(x, y, TileType::Empty);
+                        self.queue_tile_change(x, y + 1, TileType::Nutrient);
                     }
                     _ => {}
                 }
@@ -759,8 +2315,10 @@ impl World {
         self.apply_tile_changes();
     }
     
-    /// Check if a pillbug segment is completely unsupported (no solid ground, plants, or connected pillbug parts)
-    fn is_pillbug_segment_unsupported(&self, x: usize, y: usize) -> bool {
+    /// Check if a creature segment is completely unsupported (no solid ground, plants, or another
+    /// creature of the same kind, as told by `is_segment`). Segments of its own `group` can't hold
+    /// it up, or a creature could stand on itself in mid-air.
+    fn is_segment_unsupported(&self, x: usize, y: usize, is_segment: fn(TileType) -> bool, group: &[(usize, usize, TileType)]) -> bool {
         // Already at bottom - supported by world boundary
         if y >= self.height - 1 {
             return false;
@@ -769,23 +2327,19 @@ impl World {
         // Check all 8 directions for support
         for dy in -1i32..=1 {
             for dx in -1i32..=1 {
-                if dx == 0 && dy == 0 { continue; } // Skip self
-                
-                let nx = (x as i32 + dx) as usize;
-                let ny = (y as i32 + dy) as usize;
+                if dx == 0 && dy == 0 { continue; } // Skip self
                 
-                if nx < self.width && ny < self.height {
+                if let Some((nx, ny)) = self.neighbor(x, y, dx, dy) {
                     match self.tiles[ny][nx] {
                         // Solid support
-                        TileType::Dirt | TileType::Sand => return false,
+                        tile if tile.can_support_plants() || tile == TileType::Rock => return false,
                         // Plant support
                         TileType::PlantStem(_, _) | TileType::PlantRoot(_, _) | TileType::PlantBranch(_, _) => return false,
-                        // Other pillbug support (connected segments)
-                        tile if tile.is_pillbug() => {
-                            // Only count as support if the other segment is also supported or connected to something solid
-                            if dy == 1 || self.has_solid_support_nearby(nx, ny) {
-                                return false;
-                            }
+                        // Other segment support (connected segments), but only if the other segment is also
+                        // supported or connected to something solid
+                        tile if is_segment(tile) && !group.iter().any(|&(gx, gy, _)| (gx, gy) == (nx, ny))
+                            && (dy == 1 || self.has_solid_support_nearby(nx, ny)) => {
+                            return false;
                         }
                         _ => {}
                     }
@@ -806,13 +2360,10 @@ impl World {
         // Check adjacent positions for solid support
         for dy in -1i32..=1 {
             for dx in -1i32..=1 {
-                let nx = (x as i32 + dx) as usize;
-                let ny = (y as i32 + dy) as usize;
-                
-                if nx < self.width && ny < self.height {
+                if let Some((nx, ny)) = self.neighbor(x, y, dx, dy) {
                     match self.tiles[ny][nx] {
-                        TileType::Dirt | TileType::Sand | TileType::PlantStem(_, _) | 
-                        TileType::PlantRoot(_, _) | TileType::PlantBranch(_, _) => return true,
+                        TileType::PlantStem(_, _) | TileType::PlantRoot(_, _) | TileType::PlantBranch(_, _) => return true,
+                        tile if tile.can_support_plants() || tile == TileType::Rock => return true,
                         _ => {}
                     }
                 }
@@ -835,7 +2386,7 @@ impl World {
                 if nx < self.width && ny < self.height {
                     match self.tiles[ny][nx] {
                         // These tiles count as "soil" for root stability
-                        TileType::Dirt | TileType::NutrientDirt(_) | TileType::Sand => {
+                        tile if tile.is_ground() => {
                             // Good, surrounded by soil
                         }
                         TileType::PlantRoot(_, _) => {
@@ -856,8 +2407,9 @@ impl World {
         true // Root is completely surrounded by soil/other roots
     }
     
-    /// Find all connected pillbug segments starting from a given position
-    fn find_connected_pillbug_segments(&self, start_x: usize, start_y: usize) -> Vec<(usize, usize, TileType)> {
+    /// Find all connected creature segments starting from a given position, where `is_segment`
+    /// picks the kind of creature (`TileType::is_pillbug`, `TileType::is_beetle`)
+    fn find_connected_segments(&self, start_x: usize, start_y: usize, is_segment: fn(TileType) -> bool) -> Vec<(usize, usize, TileType)> {
         let mut connected = Vec::new();
         let mut visited = HashSet::new();
         let mut to_check = vec![(start_x, start_y)];
@@ -869,21 +2421,21 @@ impl World {
             visited.insert((x, y));
             
             let tile = self.tiles[y][x];
-            if tile.is_pillbug() {
+            if is_segment(tile) {
                 connected.push((x, y, tile));
                 
-                // Check adjacent positions for more pillbug parts
+                // Check adjacent positions for more parts
                 for dy in -1i32..=1 {
                     for dx in -1i32..=1 {
                         if dx == 0 && dy == 0 { continue; }
                         
-                        let nx = (x as i32 + dx) as usize;
-                        let ny = (y as i32 + dy) as usize;
+                        // Creatures can straddle the seam when the world wraps
+                        let Some((nx, ny)) = self.neighbor(x, y, dx, dy) else { continue };
                         
-                        if nx < self.width && ny < self.height && !visited.contains(&(nx, ny)) {
+                        if !visited.contains(&(nx, ny)) {
                             let neighbor_tile = self.tiles[ny][nx];
-                            if neighbor_tile.is_pillbug() {
-                                // Check if sizes match (same pillbug)
+                            if is_segment(neighbor_tile) {
+                                // Check if sizes match (same creature)
                                 if let (Some(size1), Some(size2)) = (tile.get_size(), neighbor_tile.get_size()) {
                                     if size1 == size2 {
                                         to_check.push((nx, ny));
@@ -899,6 +2451,27 @@ impl World {
         connected
     }
     
+    /// Whether another flower is close enough to have pollinated the flower at a position. Wind
+    /// carries pollen farther, so flowers spaced too far apart for calm air still set seed in a gale
+    pub fn is_pollinated(&self, x: usize, y: usize) -> bool {
+        let radius = POLLINATION_RADIUS + self.effective_wind_strength() * POLLINATION_WIND_RADIUS;
+        let reach = radius as i32;
+        for dy in -reach..=reach {
+            for dx in -reach..=reach {
+                if (dx * dx + dy * dy) as f32 > radius * radius {
+                    continue;
+                }
+                // A narrow wrapped world can bring the search back round to the flower itself
+                if let Some((nx, ny)) = self.neighbor(x, y, dx, dy).filter(|&cell| cell != (x, y)) {
+                    if matches!(self.tiles[ny][nx], TileType::PlantFlower(..)) {
+                        return true;
+                    }
+                }
+            }
+        }
+        false
+    }
+    
     /// Find all connected plant parts starting from a given position
     fn find_connected_plant_parts(&self, start_x: usize, start_y: usize) -> Vec<(usize, usize, TileType)> {
         let mut connected = Vec::new();
@@ -920,10 +2493,9 @@ impl World {
                     for dx in -1i32..=1 {
                         if dx == 0 && dy == 0 { continue; }
                         
-                        let nx = (x as i32 + dx) as usize;
-                        let ny = (y as i32 + dy) as usize;
+                        let Some((nx, ny)) = self.offset(x, y, dx, dy) else { continue };
                         
-                        if nx < self.width && ny < self.height && !visited.contains(&(nx, ny)) {
+                        if !visited.contains(&(nx, ny)) {
                             let neighbor_tile = self.tiles[ny][nx];
                             if neighbor_tile.is_plant() {
                                 // Check if sizes match (same plant)
@@ -942,11 +2514,11 @@ impl World {
         connected
     }
     
-    /// Check if an entire pillbug group is unsupported
-    fn is_pillbug_group_unsupported(&self, segments: &[(usize, usize, TileType)]) -> bool {
+    /// Check if an entire creature group is unsupported
+    fn is_group_unsupported(&self, segments: &[(usize, usize, TileType)], is_segment: fn(TileType) -> bool) -> bool {
         // If any segment has solid support, the entire group is supported
         for (x, y, _) in segments {
-            if !self.is_pillbug_segment_unsupported(*x, *y) {
+            if !self.is_segment_unsupported(*x, *y, is_segment, segments) {
                 return false;
             }
         }
@@ -967,7 +2539,7 @@ impl World {
                     
                     if nx < self.width && ny < self.height {
                         match self.tiles[ny][nx] {
-                            TileType::Dirt | TileType::Sand => return false, // Solid support found
+                            tile if tile.can_support_plants() || tile == TileType::Rock => return false, // Solid support found
                             _ => {}
                         }
                     }
@@ -982,21 +2554,21 @@ impl World {
         true
     }
     
-    /// Check if a group can move down (all spaces below are empty)
-    fn can_move_group_down(&self, group: &[(usize, usize, TileType)], new_tiles: &Vec<Vec<TileType>>) -> bool {
+    /// Simple version that checks current tiles (optimized for gravity). Creatures sink through
+    /// water, so it counts as free space when `into_water` is set.
+    fn can_move_group_down_simple(&self, group: &[(usize, usize, TileType)], into_water: bool) -> bool {
         for (x, y, _) in group {
             // Check if the position below is available
             if *y + 1 >= self.height {
                 return false; // Can't fall past bottom
             }
             
-            let below_pos = (*x, *y + 1);
-            let below_tile = new_tiles[below_pos.1][below_pos.0];
+            let below_tile = self.tiles[*y + 1][*x];
             
             // Position must be empty or will be vacated by another group member falling
-            if below_tile != TileType::Empty {
+            if below_tile != TileType::Empty && !(into_water && below_tile.is_water()) {
                 // Check if it's occupied by another member of the same group
-                let occupied_by_group = group.iter().any(|(gx, gy, _)| *gx == below_pos.0 && *gy == below_pos.1);
+                let occupied_by_group = group.iter().any(|(gx, gy, _)| *gx == *x && *gy == *y + 1);
                 if !occupied_by_group {
                     return false;
                 }
@@ -1005,79 +2577,182 @@ impl World {
         true
     }
     
-    /// Simple version that checks current tiles (optimized for gravity)
-    fn can_move_group_down_simple(&self, group: &[(usize, usize, TileType)]) -> bool {
-        for (x, y, _) in group {
-            // Check if the position below is available
-            if *y + 1 >= self.height {
-                return false; // Can't fall past bottom
+    /// Snow piles like sand and melts back into its water once it's warm enough.
+    /// Snow landing on water melts into it, as much as the water has room for.
+    fn process_snow_physics(&self, x: usize, y: usize, new_tiles: &mut [Vec<TileType>], budget: &mut WaterBudget, rng: &mut impl Rng) {
+        let content = match new_tiles[y][x] {
+            TileType::Snow(content) => content,
+            _ => return,
+        };
+        
+        // Thaw: warmer weather and daylight melt snow faster
+        let warmth = self.temperature - SNOW_MELT_TEMPERATURE;
+        if warmth > 0.0 {
+            let day_modifier = if self.is_day() { 1.5 } else { 0.5 };
+            if rng.gen_bool((warmth * 0.2 * day_modifier).min(1.0) as f64) {
+                new_tiles[y][x] = TileType::Water(content, CLEAN_WATER);
+                budget.melted += content as u64;
+                return;
             }
-            
-            let below_tile = self.tiles[*y + 1][*x];
-            
-            // Position must be empty or will be vacated by another group member falling
-            if below_tile != TileType::Empty {
-                // Check if it's occupied by another member of the same group
-                let occupied_by_group = group.iter().any(|(gx, gy, _)| *gx == *x && *gy == *y + 1);
-                if !occupied_by_group {
-                    return false;
+        }
+        
+        // Fall straight down, into water below, or slide off to pile up
+        match new_tiles[y + 1][x] {
+            TileType::Empty => {
+                new_tiles[y][x] = TileType::Empty;
+                new_tiles[y + 1][x] = TileType::Snow(content);
+            }
+            TileType::Water(depth, quality) if depth < u8::MAX => {
+                // Whatever the water has no room for stays behind as snow
+                let melted = content.min(u8::MAX - depth);
+                new_tiles[y][x] = if melted < content { TileType::Snow(content - melted) } else { TileType::Empty };
+                new_tiles[y + 1][x] = TileType::Water(depth + melted, mix_quality(depth, quality, melted, CLEAN_WATER));
+                budget.melted += melted as u64;
+            }
+            _ => {
+                let directions = if rng.gen_bool(0.5) { [(-1, 1), (1, 1)] } else { [(1, 1), (-1, 1)] };
+                for (dx, dy) in directions {
+                    let Some((nx, ny)) = self.neighbor(x, y, dx, dy) else {
+                        continue;
+                    };
+                    if new_tiles[ny][nx] == TileType::Empty {
+                        new_tiles[y][x] = TileType::Empty;
+                        new_tiles[ny][nx] = TileType::Snow(content);
+                        break;
+                    }
                 }
             }
         }
-        true
     }
     
-    /// Move a group down by one position
-    fn move_group_down(&self, group: &[(usize, usize, TileType)], new_tiles: &mut Vec<Vec<TileType>>) {
-        // First clear all current positions
-        for (x, y, _) in group {
-            new_tiles[*y][*x] = TileType::Empty;
+    /// Freeze standing water in a cold snap and melt ice once it warms up. Only water open to the
+    /// air above freezes, shallow water sooner than deep, so a pond ices over at the surface and
+    /// stays liquid beneath. Ice melts back clean, like snow.
+    fn update_ice(&mut self, rng: &mut impl Rng) {
+        let freeze_chance = ((FREEZE_TEMPERATURE - self.temperature) * FREEZE_RATE).clamp(0.0, 1.0) as f64;
+        let melt_chance = ((self.temperature - ICE_MELT_TEMPERATURE) * ICE_MELT_RATE).clamp(0.0, 1.0) as f64;
+        if freeze_chance == 0.0 && melt_chance == 0.0 {
+            return;
         }
         
-        // Then place all tiles in new positions
-        for (x, y, tile) in group {
-            new_tiles[*y + 1][*x] = *tile;
+        // Top down, so water that froze this tick covers the water below it
+        for y in 0..self.height {
+            for x in 0..self.width {
+                match self.tiles[y][x] {
+                    TileType::Water(depth, _) if freeze_chance > 0.0 => {
+                        let open = y == 0 || !matches!(self.tiles[y - 1][x], TileType::Water(_, _) | TileType::Ice(_));
+                        let standing = y + 1 < self.height && self.tiles[y + 1][x] != TileType::Empty;
+                        if open && standing && rng.gen_bool(freeze_chance * (1.0 - depth as f64 / 510.0)) {
+                            self.tiles[y][x] = TileType::Ice(depth);
+                        }
+                    }
+                    TileType::Ice(depth) if melt_chance > 0.0 && rng.gen_bool(melt_chance) => {
+                        self.tiles[y][x] = TileType::Water(depth, CLEAN_WATER);
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    
+    /// Decaying plants and pillbugs in the 3x3 neighbourhood of a position
+    fn count_decaying_around(&self, x: usize, y: usize) -> usize {
+        let mut count = 0;
+        for ny in y.saturating_sub(1)..=(y + 1).min(self.height - 1) {
+            for nx in x.saturating_sub(1)..=(x + 1).min(self.width - 1) {
+                if matches!(self.tiles[ny][nx], TileType::PlantWithered(_, _) | TileType::PillbugDecaying(_, _)) {
+                    count += 1;
+                }
+            }
         }
+        count
     }
     
     /// Enhanced water physics with depth-based flow mechanics and pooling
-    fn process_water_physics(&self, x: usize, y: usize, depth: u8, new_tiles: &mut Vec<Vec<TileType>>, rng: &mut impl Rng) {
+    ///
+    /// Water volume is conserved: it only leaves the world through evaporation or
+    /// soaking into soil, and moves between cells without being created or lost.
+    /// Quality travels with the water, and water that merges takes the depth-weighted mix.
+    /// What evaporates or soaks in goes on the budget; evaporation goes on to humidify the air.
+    fn process_water_physics(&self, x: usize, y: usize, new_tiles: &mut [Vec<TileType>], budget: &mut WaterBudget, rng: &mut impl Rng) {
+        // Read the current depth, which includes anything that already flowed in this tick
+        let (mut depth, mut quality) = match new_tiles[y][x] {
+            TileType::Water(depth, quality) => (depth, quality),
+            _ => return,
+        };
+        
+        // Nutrients beside the water leach into it
+        let mut enriched = false;
+        for (dx, dy) in [(0, 1), (-1, 0), (1, 0), (0, -1)] {
+            let Some((nx, ny)) = self.neighbor(x, y, dx, dy) else { continue };
+            let (leached, richness) = match new_tiles[ny][nx] {
+                TileType::Nutrient => (TileType::Empty, DISSOLVED_NUTRIENT),
+                TileType::NutrientDirt(level) if level > RUNOFF_LEVEL => (TileType::NutrientDirt(level - NUTRIENT_RUNOFF), NUTRIENT_RUNOFF),
+                _ => continue,
+            };
+            enriched = true;
+            if rng.gen_bool(RUNOFF_CHANCE) {
+                new_tiles[ny][nx] = leached;
+                quality = quality.saturating_sub(richness);
+            }
+        }
+        
+        // Clusters of decaying matter foul the water; without any, or nutrients feeding it, it slowly clears
+        let decaying = self.count_decaying_around(x, y);
+        quality = if decaying >= POLLUTION_CLUSTER {
+            quality.saturating_sub(POLLUTION_PER_DECAY * decaying as u8)
+        } else if decaying == 0 && !enriched {
+            quality.saturating_add(WATER_RECOVERY)
+        } else {
+            quality
+        };
+        new_tiles[y][x] = TileType::Water(depth, quality);
+        
+        // Rich, warm standing water blooms with algae
+        let open_surface = y > 0 && new_tiles[y - 1][x] == TileType::Empty;
+        if open_surface && quality < ALGAE_BLOOM_QUALITY && depth > 30 && self.temperature > 0.0 && rng.gen_bool(ALGAE_BLOOM_CHANCE) {
+            new_tiles[y - 1][x] = TileType::Algae(0);
+        }
+        
         let biome = self.get_biome_at(x, y);
         let moisture_retention = biome.moisture_retention();
         
         // Water wetting earth - water can soak into dirt/sand instead of just piling up
-        if depth <= 80 && rng.gen_bool(0.15) { // Moderate chance for light/medium water to soak in
-            // Check if there's dirt or sand adjacent that can absorb water
-            let absorption_positions = [
-                (x, y.saturating_add(1).min(self.height - 1)), // Below
-                (x.saturating_sub(1), y), (x.saturating_add(1).min(self.width - 1), y), // Sides
-            ];
+        if depth <= 80 && rng.gen_bool((0.15 * self.physics_config.absorption_rate).clamp(0.0, 1.0) as f64) { // Moderate chance for light/medium water to soak in
+            // Check if there's dirt or sand adjacent that can absorb water (never the world edge)
+            let mut absorption_positions = Vec::with_capacity(3);
+            if y + 1 < self.height { absorption_positions.push((x, y + 1)); } // Below
+            if x > 0 { absorption_positions.push((x - 1, y)); }               // Sides
+            if x + 1 < self.width { absorption_positions.push((x + 1, y)); }
             
             for (ax, ay) in absorption_positions.iter() {
-                if *ax < self.width && *ay < self.height {
-                    match new_tiles[*ay][*ax] {
-                        tile if tile.can_support_plants() => {
-                            // Water soaks into the earth, reducing water depth
-                            let absorption_amount = match depth {
-                                0..=30 => depth, // Light water completely absorbed
-                                31..=50 => 20 + rng.gen_range(0..15), // Partial absorption
-                                _ => 10 + rng.gen_range(0..20), // Heavy water partially absorbed
-                            };
-                            
-                            let remaining_depth = depth.saturating_sub(absorption_amount);
-                            if remaining_depth > 10 {
-                                new_tiles[y][x] = TileType::Water(remaining_depth);
-                            } else {
-                                new_tiles[y][x] = TileType::Empty; // Water fully absorbed
-                            }
-                            return; // Water absorbed, skip other physics
+                match new_tiles[*ay][*ax] {
+                    tile if tile.is_earth() && rng.gen_bool(self.soil_factor_at(*ax, *ay, SoilLayer::water_absorption) as f64) => {
+                        // Water soaks into the earth, reducing water depth
+                        let absorption_amount = match depth {
+                            0..=30 => depth, // Light water completely absorbed
+                            31..=50 => 20 + rng.gen_range(0..15), // Partial absorption
+                            _ => 10 + rng.gen_range(0..20), // Heavy water partially absorbed
+                        };
+                        
+                        let remaining_depth = depth.saturating_sub(absorption_amount);
+                        if remaining_depth > 10 {
+                            new_tiles[y][x] = TileType::Water(remaining_depth, quality);
+                            budget.absorbed += (depth - remaining_depth) as u64;
+                        } else {
+                            new_tiles[y][x] = TileType::Empty; // Water fully absorbed
+                            budget.absorbed += depth as u64;
                         }
-                        _ => {}
+                        return; // Water absorbed, skip other physics
                     }
+                    _ => {}
                 }
             }
         }
         
+        // A snowpack or sheet of ice insulates what lies beneath it, so covered water doesn't evaporate
+        let insulated = y > 0 && matches!(new_tiles[y - 1][x], TileType::Snow(_) | TileType::Ice(_));
+        
         // Calculate evaporation based on depth, biome, and environmental conditions
         let base_evaporation = match depth {
             0..=30 => 0.08,   // Small droplets evaporate quickly
@@ -1089,55 +2764,75 @@ impl World {
         let day_modifier = if self.is_day() { 1.5 } else { 0.8 };
         let temp_modifier = (self.temperature + 1.0) * 0.5; // 0.0 to 1.0 range
         let biome_modifier = 2.0 - moisture_retention; // 0.6 to 1.4 range
-        let final_evaporation = base_evaporation * day_modifier * (0.5 + temp_modifier) * biome_modifier;
+        let drought_modifier = 1.0 + 2.0 * self.drought_stress(); // Parched ground dries puddles faster
+        let final_evaporation = base_evaporation * day_modifier * (0.5 + temp_modifier) * biome_modifier * drought_modifier * self.physics_config.evaporation_rate;
         
         // Small chance of evaporation, higher for shallow water
-        if rng.gen_bool(final_evaporation.min(1.0) as f64) {
+        if !insulated && rng.gen_bool(final_evaporation.clamp(0.0, 1.0) as f64) {
             if depth <= 30 {
                 new_tiles[y][x] = TileType::Empty; // Complete evaporation
+                budget.evaporated += depth as u64;
+                return;
+            }
+            // Partial evaporation - reduce depth
+            let new_depth = depth.saturating_sub(10 + rng.gen_range(0..10));
+            if new_depth > 0 {
+                new_tiles[y][x] = TileType::Water(new_depth, quality);
             } else {
-                // Partial evaporation - reduce depth
-                let new_depth = depth.saturating_sub(10 + rng.gen_range(0..10));
-                if new_depth > 0 {
-                    new_tiles[y][x] = TileType::Water(new_depth);
-                } else {
-                    new_tiles[y][x] = TileType::Empty;
-                }
+                new_tiles[y][x] = TileType::Empty;
             }
+            budget.evaporated += (depth - new_depth) as u64;
             return;
         }
         
-        // Enhanced flow physics with depth-based pressure
-        if y + 1 < self.tiles.len() {
-            let below = new_tiles[y + 1][x];
-            
-            match below {
+        // Vertical flow: fall into open space, or top up the water below until it's full
+        if y + 1 < self.height {
+            match new_tiles[y + 1][x] {
                 TileType::Empty => {
-                    // Water falls with momentum - deeper water falls faster and harder
-                    let fall_depth = if depth <= 50 { depth } else { depth.saturating_add(10) }; // Deep water gains momentum
+                    // Water falls as a whole
                     new_tiles[y][x] = TileType::Empty;
-                    new_tiles[y + 1][x] = TileType::Water(fall_depth.min(255));
+                    new_tiles[y + 1][x] = TileType::Water(depth, quality);
                     return;
                 }
-                TileType::Water(below_depth) => {
-                    // Water combines with water below, creating pressure
-                    let combined_depth = below_depth.saturating_add(depth / 3); // Some water flows down
-                    if combined_depth != below_depth {
-                        let flow_amount = combined_depth - below_depth;
-                        let remaining_depth = depth.saturating_sub(flow_amount);
-                        new_tiles[y + 1][x] = TileType::Water(combined_depth.min(255));
-                        if remaining_depth > 20 {
-                            new_tiles[y][x] = TileType::Water(remaining_depth);
-                        } else {
-                            new_tiles[y][x] = TileType::Empty;
-                        }
+                TileType::Water(below_depth, below_quality) if below_depth < u8::MAX => {
+                    let flow_amount = depth.min(u8::MAX - below_depth);
+                    new_tiles[y + 1][x] = TileType::Water(below_depth + flow_amount, mix_quality(below_depth, below_quality, flow_amount, quality));
+                    depth -= flow_amount;
+                    if depth == 0 {
+                        new_tiles[y][x] = TileType::Empty;
+                        return;
                     }
+                    new_tiles[y][x] = TileType::Water(depth, quality);
+                }
+                _ => {} // Blocked by solid material or full water
+            }
+        }
+        
+        // Pressure wears through a thin dam
+        if depth > DAM_BURST_DEPTH {
+            let pressure = (depth - DAM_BURST_DEPTH) as f64 / (u8::MAX - DAM_BURST_DEPTH) as f64;
+            let sides = if rng.gen_bool(0.5) { [-1, 1] } else { [1, -1] };
+            for dx in sides {
+                let (Some((wall_x, _)), Some((beyond_x, _))) = (self.neighbor(x, y, dx, 0), self.neighbor(x, y, 2 * dx, 0)) else { continue };
+                let thin_wall = matches!(new_tiles[y][wall_x], TileType::Dirt | TileType::NutrientDirt(_) | TileType::DepletedDirt | TileType::Sand)
+                    && new_tiles[y][beyond_x] == TileType::Empty;
+                if !thin_wall || !rng.gen_bool(DAM_BURST_CHANCE * pressure) {
+                    continue;
+                }
+                if new_tiles[y][wall_x] == TileType::Sand {
+                    // The breach: half the water surges into the gap the sand leaves
+                    let surge = depth / 2;
+                    new_tiles[y][wall_x] = TileType::Water(surge, quality);
+                    depth -= surge;
+                    new_tiles[y][x] = TileType::Water(depth, quality);
+                    return;
                 }
-                _ => {} // Blocked by solid material
+                new_tiles[y][wall_x] = TileType::Sand;
+                break;
             }
         }
         
-        // Horizontal flow with pressure-driven mechanics
+        // Sideways flow with pressure-driven mechanics
         let flow_pressure = depth as f32 / 255.0;
         let flow_chance = flow_pressure * 0.8; // Deeper water flows more readily
         
@@ -1149,95 +2844,151 @@ impl World {
             Biome::Drylands => 1.0,  // Flows away quickly
         };
         
-        if rng.gen_bool((flow_chance * biome_flow_resistance) as f64) {
-            // Find the best flow direction using elevation and existing water levels
-            let mut flow_targets = Vec::new();
-            
-            // Check all adjacent positions for flow potential
-            let directions = [(-1, 0), (1, 0), (-1, 1), (1, 1)]; // Horizontal and diagonal-down
-            
-            for (dx, dy) in directions.iter() {
-                let nx = x as i32 + dx;
-                let ny = y as i32 + dy;
-                
-                if nx >= 0 && nx < self.width as i32 && ny >= 0 && ny < new_tiles.len() as i32 {
-                    let nx = nx as usize;
-                    let ny = ny as usize;
-                    
-                    let target_tile = new_tiles[ny][nx];
-                    if target_tile.can_water_flow_into() {
-                        let flow_priority = if *dy == 1 { 3 } else { 2 }; // Prefer diagonal flow downward
-                        flow_targets.push((nx, ny, flow_priority, 0u8));
-                    } else if let Some(target_depth) = target_tile.get_water_depth() {
-                        // Flow into areas with lower water level
-                        if target_depth < depth.saturating_sub(20) {
-                            let flow_priority = if *dy == 1 { 2 } else { 1 }; // Lower priority than empty space
-                            flow_targets.push((nx, ny, flow_priority, target_depth));
-                        }
-                    }
-                }
+        if !rng.gen_bool((flow_chance * biome_flow_resistance) as f64) {
+            return;
+        }
+        
+        // Lower ground first, a coin toss between sides that lie level
+        let mut sides: [i32; 2] = if rng.gen_bool(0.5) { [-1, 1] } else { [1, -1] };
+        sides.sort_by_key(|&dx| self.neighbor(x, y, dx, 0).map_or(u8::MAX, |(nx, _)| self.elevation[y][nx]));
+        
+        // Downhill first: spill diagonally into a lower cell that has room
+        for dx in sides {
+            let Some((nx, _)) = self.neighbor(x, y, dx, 1) else { continue };
+            let (room, target_depth, target_quality) = match new_tiles[y + 1][nx] {
+                TileType::Empty => (u8::MAX, 0, quality),
+                TileType::Water(target_depth, target_quality) => (u8::MAX - target_depth, target_depth, target_quality),
+                _ => (0, 0, quality),
+            };
+            if room > 0 {
+                let flow_amount = depth.min(room);
+                new_tiles[y + 1][nx] = TileType::Water(target_depth + flow_amount, mix_quality(target_depth, target_quality, flow_amount, quality));
+                depth -= flow_amount;
+                new_tiles[y][x] = if depth > 0 { TileType::Water(depth, quality) } else { TileType::Empty };
+                return;
             }
-            
-            // Sort by flow priority (higher priority first)
-            flow_targets.sort_by_key(|&(_, _, priority, _)| std::cmp::Reverse(priority));
-            
-            if let Some((target_x, target_y, _, target_depth)) = flow_targets.first() {
-                let flow_amount = if depth > 100 {
-                    depth / 3 // Deep water flows more aggressively
-                } else if depth > 50 {
-                    depth / 4
-                } else {
-                    depth / 5 // Shallow water flows conservatively
-                }.max(10);
-                
-                let remaining_depth = depth.saturating_sub(flow_amount);
-                let new_target_depth = target_depth.saturating_add(flow_amount);
-                
-                // Update target position
-                new_tiles[*target_y][*target_x] = TileType::Water(new_target_depth.min(255));
+        }
+        
+        // Then level out with the side neighbour whose surface, water plus the ground's elevation,
+        // lies lowest. Moving half the difference can never overshoot, and small differences on
+        // level ground are left alone, so resting water stays still; onto lower ground it keeps
+        // trickling until the surfaces meet, which is how valleys fill
+        let surface = depth as i32 + self.elevation[y][x] as i32;
+        let mut lowest: Option<(usize, u8, u8, i32)> = None;
+        for dx in sides {
+            let Some((nx, _)) = self.neighbor(x, y, dx, 0) else { continue };
+            let (level, target_quality) = match new_tiles[y][nx] {
+                TileType::Empty => (0, quality),
+                TileType::Water(target_depth, target_quality) => (target_depth, target_quality),
+                _ => continue,
+            };
+            let target_surface = level as i32 + self.elevation[y][nx] as i32;
+            if lowest.is_none_or(|(_, _, _, lowest_surface)| target_surface < lowest_surface) {
+                lowest = Some((nx, level, target_quality, target_surface));
+            }
+        }
+        if let Some((target_x, level, target_quality, target_surface)) = lowest {
+            let tolerance = if self.elevation[y][target_x] < self.elevation[y][x] { 1 } else { WATER_LEVEL_TOLERANCE as i32 };
+            if surface - target_surface > tolerance {
+                let flow_amount = ((surface - target_surface) / 2).min(depth as i32).min((u8::MAX - level) as i32) as u8;
+                new_tiles[y][target_x] = TileType::Water(level + flow_amount, mix_quality(level, target_quality, flow_amount, quality));
+                new_tiles[y][x] = if flow_amount < depth { TileType::Water(depth - flow_amount, quality) } else { TileType::Empty };
                 
-                // Update current position
-                if remaining_depth > 10 {
-                    new_tiles[y][x] = TileType::Water(remaining_depth);
-                } else {
-                    new_tiles[y][x] = TileType::Empty;
+                // A strong current scours the sand beneath it: what water stays behind sinks into
+                // the hollow and the lifted grains take its place, to be carried downstream
+                let scouring = depth >= EROSION_DEPTH && flow_amount >= EROSION_FLOW && flow_amount < depth;
+                if scouring && new_tiles[y + 1][x] == TileType::Sand && rng.gen_bool(EROSION_CHANCE) {
+                    new_tiles[y + 1][x] = TileType::Water(depth - flow_amount, quality);
+                    new_tiles[y][x] = TileType::Sediment(0);
                 }
             }
         }
     }
     
+    /// Sand held up in flowing water. It drifts toward the lower of the water surfaces either
+    /// side of it, trading places with that water, for as long as they differ by more than
+    /// `WATER_LEVEL_TOLERANCE`. Where the flow slackens it sinks through the water and settles
+    /// into sand on the bottom, building deltas where streams meet still water; left high and
+    /// dry, or falling through open air, it is plain sand again.
+    fn process_sediment(&mut self, x: usize, y: usize, new_tiles: &mut [Vec<TileType>], rng: &mut impl Rng) {
+        let TileType::Sediment(age) = new_tiles[y][x] else { return };
+        let age = age.saturating_add(1);
+        
+        let wet = [(-1, 0), (1, 0), (0, -1), (0, 1)].into_iter()
+            .any(|(dx, dy)| self.neighbor(x, y, dx, dy).is_some_and(|(nx, ny)| new_tiles[ny][nx].is_water()));
+        if !wet || new_tiles[y + 1][x] == TileType::Empty {
+            new_tiles[y][x] = TileType::Sand;
+            return;
+        }
+        
+        // The current, read from the water surfaces on either side
+        let surface = |dx: i32| self.neighbor(x, y, dx, 0).and_then(|(nx, _)| match new_tiles[y][nx] {
+            TileType::Water(depth, _) => Some((nx, depth as i32 + self.elevation[y][nx] as i32)),
+            _ => None,
+        });
+        if let (Some((left_x, left)), Some((right_x, right))) = (surface(-1), surface(1)) {
+            if (left - right).abs() > WATER_LEVEL_TOLERANCE as i32 && rng.gen_bool(SEDIMENT_CARRY_CHANCE) {
+                let downstream = if left < right { left_x } else { right_x };
+                new_tiles[y][x] = new_tiles[y][downstream];
+                new_tiles[y][downstream] = TileType::Sediment(age);
+                self.move_soil_layer((x, y), (downstream, y));
+                return;
+            }
+        }
+        
+        if new_tiles[y + 1][x].is_water() {
+            new_tiles[y][x] = new_tiles[y + 1][x];
+            new_tiles[y + 1][x] = TileType::Sediment(age);
+            self.move_soil_layer((x, y), (x, y + 1));
+        } else if rng.gen_bool(SEDIMENT_SETTLE_CHANCE) {
+            new_tiles[y][x] = TileType::Sand;
+        } else {
+            new_tiles[y][x] = TileType::Sediment(age);
+        }
+    }
+    
     /// Process wind effects on seeds, spores, light particles, and water droplets
-    fn process_wind_effects(&mut self) {
-        if self.wind_strength < 0.1 {
+    fn process_wind_effects(&mut self, rng: &mut impl Rng) {
+        if self.effective_wind_strength() < 0.1 {
             return; // No significant wind
         }
         
         let mut new_tiles = self.tiles.clone();
-        let mut rng = rand::thread_rng();
         
         // Calculate wind direction components
-        let wind_x = self.wind_direction.cos();
-        let wind_y = self.wind_direction.sin();
+        let wind = (self.wind_direction.cos(), self.wind_direction.sin());
         
         // Process from top to bottom, left to right for consistent wind direction
+        let mut blown_seeds = Vec::new();
         for y in 0..self.height {
             for x in 0..self.width {
                 match self.tiles[y][x] {
+                    // Skip anything something else already blew into or out of this pass
+                    tile if new_tiles[y][x] != tile => {}
                     tile if tile.is_wind_dispersible() || tile.is_light_particle() => {
-                        self.process_wind_particle(x, y, tile, &mut new_tiles, &mut rng, wind_x, wind_y);
+                        let moved_to = self.process_wind_particle(x, y, tile, &mut new_tiles, rng, wind);
+                        if let (Some(to), TileType::Seed(_, _)) = (moved_to, tile) {
+                            blown_seeds.push(((x, y), to));
+                        }
                     }
                     _ => {}
                 }
             }
         }
         
+        // Wind only carries water about
+        debug_assert_eq!(total_water_in(&self.tiles), total_water_in(&new_tiles), "wind created or destroyed water");
         self.tiles = new_tiles;
+        // Seeds take their genomes with them, in the order they moved
+        for (from, to) in blown_seeds {
+            self.move_genome(from, to);
+        }
     }
     
-    /// Process individual particle movement due to wind
+    /// Process individual particle movement due to wind, returning where the particle landed if it moved
     fn process_wind_particle(&self, x: usize, y: usize, particle: TileType, 
-                           new_tiles: &mut Vec<Vec<TileType>>, rng: &mut impl Rng, 
-                           wind_x: f32, wind_y: f32) {
+                           new_tiles: &mut [Vec<TileType>], rng: &mut impl Rng, 
+                           (wind_x, wind_y): (f32, f32)) -> Option<(usize, usize)> {
         // Check if this particle should be affected by wind
         let wind_susceptibility = match particle {
             TileType::Seed(_, Size::Small) => 0.9,    // Small seeds very susceptible
@@ -1245,15 +2996,16 @@ impl World {
             TileType::Seed(_, Size::Large) => 0.3,    // Large seeds less susceptible
             TileType::Spore(_) => 1.0,                // Spores very light
             TileType::Nutrient => 0.4,                // Nutrients moderately affected
-            TileType::Water(depth) if depth <= 30 => (30 - depth) as f32 / 30.0, // Light water droplets
-            _ => return, // Not wind-affected
+            TileType::Water(depth, _) if depth <= 30 => (30 - depth) as f32 / 30.0, // Light water droplets
+            _ => return None, // Not wind-affected
         };
         
         // Calculate movement probability based on wind strength and susceptibility
-        let movement_chance = self.wind_strength * wind_susceptibility * 0.8;
+        let wind_strength = self.effective_wind_strength();
+        let movement_chance = (wind_strength * wind_susceptibility * 0.8).min(1.0);
         
         if !rng.gen_bool(movement_chance as f64) {
-            return; // No movement this tick
+            return None; // No movement this tick
         }
         
         // Calculate target position based on wind direction
@@ -1261,43 +3013,39 @@ impl World {
         let random_x = rng.gen_range(-0.3..0.3);
         let random_y = rng.gen_range(-0.3..0.3);
         
-        let target_x = x as f32 + wind_x * self.wind_strength * 2.0 + random_x;
-        let target_y = y as f32 + wind_y * self.wind_strength * 2.0 + random_y;
-        
-        // Clamp to world bounds
-        let target_x = target_x.round() as i32;
-        let target_y = target_y.round() as i32;
+        let target_x = x as f32 + wind_x * wind_strength * 2.0 + random_x;
+        let target_y = y as f32 + wind_y * wind_strength * 2.0 + random_y;
         
-        if target_x < 0 || target_x >= self.width as i32 || 
-           target_y < 0 || target_y >= self.height as i32 {
-            // Particle blown out of world - remove it
-            new_tiles[y][x] = TileType::Empty;
-            return;
+        // Bring the target back inside the world according to the edge behavior
+        let Some((target_x, target_y)) = self.resolve_edge(target_x.round() as i32, target_y.round() as i32) else {
+            // Water droplets stop at the edge; everything else is blown out of the world
+            if !particle.is_water() {
+                new_tiles[y][x] = TileType::Empty;
+            }
+            return None;
+        };
+        if (target_x, target_y) == (x, y) {
+            return None; // Too light a gust to shift it
         }
         
-        let target_x = target_x as usize;
-        let target_y = target_y as usize;
-        
         // Check if target position is available
         match new_tiles[target_y][target_x] {
             TileType::Empty => {
                 // Move particle to new location
                 new_tiles[y][x] = TileType::Empty;
                 new_tiles[target_y][target_x] = particle;
+                Some((target_x, target_y))
             }
             target_tile if target_tile.is_water() => {
                 if let Some(depth) = target_tile.get_water_depth() {
-                    if depth <= 50 {
-                        // Light water can be displaced by wind particles
-                        if particle.is_light_particle() {
-                            new_tiles[y][x] = TileType::Empty;
-                            new_tiles[target_y][target_x] = particle;
-                            
-                            // Try to move the displaced water to adjacent positions
-                            self.try_displace_water(target_x, target_y, target_tile, new_tiles, rng);
-                        }
+                    // Light water can be displaced by wind particles, if it has somewhere to go
+                    if depth <= 50 && particle.is_light_particle() && self.try_displace_water(target_x, target_y, target_tile, new_tiles, rng) {
+                        new_tiles[y][x] = TileType::Empty;
+                        new_tiles[target_y][target_x] = particle;
+                        return Some((target_x, target_y));
                     }
                 }
+                None
             }
             _ => {
                 // Target blocked, try adjacent positions
@@ -1312,17 +3060,19 @@ impl World {
                     if new_tiles[*adj_y][*adj_x] == TileType::Empty {
                         new_tiles[y][x] = TileType::Empty;
                         new_tiles[*adj_y][*adj_x] = particle;
-                        return;
+                        return Some((*adj_x, *adj_y));
                     }
                 }
                 // No adjacent space available - particle stays put
+                None
             }
         }
     }
     
-    /// Helper function to try displacing water when wind particles collide
+    /// Helper function to try displacing water when wind particles collide, returning whether
+    /// the water found an empty neighbour to move into
     fn try_displace_water(&self, x: usize, y: usize, water: TileType, 
-                         new_tiles: &mut Vec<Vec<TileType>>, rng: &mut impl Rng) {
+                         new_tiles: &mut [Vec<TileType>], rng: &mut impl Rng) -> bool {
         let directions = [(0, 1), (-1, 0), (1, 0), (0, -1)]; // Down, left, right, up priority
         
         if let Some((dx, dy)) = directions.iter().choose(rng) {
@@ -1331,21 +3081,23 @@ impl World {
             
             if new_x < self.width && new_y < self.height && new_tiles[new_y][new_x] == TileType::Empty {
                 new_tiles[new_y][new_x] = water;
-                return;
+                return true;
             }
         }
-        // If no space found, water evaporates due to wind dispersal
+        // With nowhere to go the water stays put, and so does whatever blew against it
+        false
     }
     
-    fn check_plant_support(&mut self) {
+    // Support is checked cell by cell against neighbours above, below and beside
+    #[allow(clippy::needless_range_loop)]
+    fn check_plant_support(&mut self, rng: &mut impl Rng) {
         let mut new_tiles = self.tiles.clone();
-        let mut rng = rand::thread_rng();
         
         // Check plant parts from top to bottom
         for y in 0..self.height - 1 {
             for x in 0..self.width {
                 match self.tiles[y][x] {
-                    TileType::PlantLeaf(_, size) | TileType::PlantBud(_, size) | 
+                    TileType::PlantLeaf(_, size) | TileType::PlantBud(_, size) | TileType::PlantWilting(_, size) |
                     TileType::PlantBranch(_, size) | TileType::PlantFlower(_, size) => {
                         // Check for support in 8 directions
                         let mut has_support = false;
@@ -1356,7 +3108,11 @@ impl World {
                                 let ny = (y as i32 + dy) as usize;
                                 if nx < self.width && ny < self.height {
                                     match self.tiles[ny][nx] {
-                                        TileType::PlantStem(_, _) | TileType::PlantBranch(_, _) | TileType::PlantRoot(_, _) | TileType::Dirt => {
+                                        TileType::PlantStem(_, _) | TileType::PlantBranch(_, _) | TileType::PlantRoot(_, _) => {
+                                            has_support = true;
+                                            break;
+                                        }
+                                        tile if tile.is_soil() => {
                                             has_support = true;
                                             break;
                                         }
@@ -1368,16 +3124,14 @@ impl World {
                         }
                         
                         // If no support, it falls or withers
-                        if !has_support {
-                            if rng.gen_bool(0.3) {
-                                // Falls down if space below
-                                if y + 1 < self.height && new_tiles[y + 1][x] == TileType::Empty {
-                                    new_tiles[y + 1][x] = self.tiles[y][x];
-                                    new_tiles[y][x] = TileType::Empty;
-                                } else {
-                                    // Withers if can't fall
-                                    new_tiles[y][x] = TileType::PlantWithered(0, size);
-                                }
+                        if !has_support && rng.gen_bool(0.3) {
+                            // Falls down if space below
+                            if y + 1 < self.height && new_tiles[y + 1][x] == TileType::Empty {
+                                new_tiles[y + 1][x] = self.tiles[y][x];
+                                new_tiles[y][x] = TileType::Empty;
+                            } else {
+                                // Withers if can't fall
+                                new_tiles[y][x] = TileType::PlantWithered(0, size);
                             }
                         }
                     }
@@ -1388,7 +3142,10 @@ impl World {
                         // Check below
                         if y + 1 < self.height {
                             match self.tiles[y + 1][x] {
-                                TileType::PlantStem(_, _) | TileType::PlantBranch(_, _) | TileType::PlantRoot(_, _) | TileType::Dirt | TileType::Sand => {
+                                TileType::PlantStem(_, _) | TileType::PlantBranch(_, _) | TileType::PlantRoot(_, _) | TileType::Rock => {
+                                    has_support = true;
+                                }
+                                tile if tile.can_support_plants() => {
                                     has_support = true;
                                 }
                                 _ => {}
@@ -1425,9 +3182,8 @@ impl World {
         self.tiles = new_tiles;
     }
     
-    fn diffuse_nutrients(&mut self) {
+    fn diffuse_nutrients(&mut self, rng: &mut impl Rng) {
         // Nutrients spread slowly - optimized to avoid full array clone
-        let mut rng = rand::thread_rng();
         
         // Collect nutrient positions first to avoid iterator conflicts
         let mut nutrient_positions = Vec::new();
@@ -1443,7 +3199,7 @@ impl World {
         for (x, y) in nutrient_positions {
             if rng.gen_bool(0.1) {
                 let directions = [(-1, 0), (1, 0), (0, -1), (0, 1)];
-                if let Some(&(dx, dy)) = directions.choose(&mut rng) {
+                if let Some(&(dx, dy)) = directions.choose(rng) {
                     let nx = (x as i32 + dx) as usize;
                     let ny = (y as i32 + dy) as usize;
                     if nx < self.width && ny < self.height {
@@ -1453,17 +3209,27 @@ impl World {
                                 self.queue_tile_change(x, y, TileType::Empty);
                                 self.queue_tile_change(nx, ny, TileType::Nutrient);
                             }
-                            TileType::Dirt if rng.gen_bool(0.3) => {
+                            TileType::Dirt if rng.gen_bool((0.3 * self.soil_factor_at(nx, ny, SoilLayer::nutrient_modifier)).min(1.0) as f64) => {
                                 // Nutrients can absorb into dirt, creating nutrient dirt
                                 self.queue_tile_change(x, y, TileType::Empty);
                                 self.queue_tile_change(nx, ny, TileType::NutrientDirt(80)); // Medium nutrient level
                             }
+                            TileType::DepletedDirt if rng.gen_bool((0.3 * self.soil_factor_at(nx, ny, SoilLayer::nutrient_modifier)).min(1.0) as f64) => {
+                                // Nutrients soaking into exhausted soil restore it
+                                self.queue_tile_change(x, y, TileType::Empty);
+                                self.queue_tile_change(nx, ny, TileType::Dirt);
+                            }
                             TileType::NutrientDirt(existing_level) if rng.gen_bool(0.2) => {
                                 // Add more nutrients to existing nutrient dirt
                                 let new_level = existing_level.saturating_add(30);
                                 self.queue_tile_change(x, y, TileType::Empty);
                                 self.queue_tile_change(nx, ny, TileType::NutrientDirt(new_level));
                             }
+                            TileType::Mycelium(load) if rng.gen_bool(0.2) => {
+                                // Fungal threads take up loose nutrients into their network
+                                self.queue_tile_change(x, y, TileType::Empty);
+                                self.queue_tile_change(nx, ny, TileType::Mycelium(load.saturating_add(30)));
+                            }
                             _ => {}
                         }
                     }
@@ -1473,14 +3239,213 @@ impl World {
         
         // Apply all changes at once
         self.apply_tile_changes();
+        
+        self.update_mycelium(rng);
+    }
+    
+    /// Share nutrients between plants through the fungal networks in the soil. Each connected
+    /// patch of mycelium draws surplus nutrients out of rich dirt around it, pools them, and feeds
+    /// them to the roots it touches that have no nutrients of their own nearby, so a plant in rich
+    /// ground keeps a starving neighbour alive. Networks creep slowly through dirt from the roots
+    /// that anchor them and die back into the soil once no living root touches them.
+    fn update_mycelium(&mut self, rng: &mut impl Rng) {
+        const SIDES: [(i32, i32); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+        let mut seen = vec![vec![false; self.width]; self.height];
+        let mut growth = Vec::new();
+        
+        for y in 0..self.height {
+            for x in 0..self.width {
+                match self.tiles[y][x] {
+                    // Living roots seed new threads into the dirt around them
+                    TileType::PlantRoot(_, _) if rng.gen_bool(MYCELIUM_SPREAD_CHANCE) => {
+                        let (dx, dy) = SIDES[rng.gen_range(0..SIDES.len())];
+                        growth.extend(self.neighbor(x, y, dx, dy));
+                    }
+                    TileType::Mycelium(_) if !seen[y][x] => {
+                        // Flood out the whole connected network
+                        seen[y][x] = true;
+                        let mut network = vec![(x, y)];
+                        let mut roots = Vec::new();
+                        let mut next = 0;
+                        while next < network.len() {
+                            let (cx, cy) = network[next];
+                            next += 1;
+                            for (dx, dy) in SIDES {
+                                let Some((nx, ny)) = self.neighbor(cx, cy, dx, dy) else {
+                                    continue;
+                                };
+                                match self.tiles[ny][nx] {
+                                    TileType::Mycelium(_) if !seen[ny][nx] => {
+                                        seen[ny][nx] = true;
+                                        network.push((nx, ny));
+                                    }
+                                    TileType::PlantRoot(_, _) if !roots.contains(&(nx, ny)) => roots.push((nx, ny)),
+                                    _ => {}
+                                }
+                            }
+                        }
+                        
+                        // With no living root to anchor it, the network withers back into the soil
+                        if roots.is_empty() {
+                            for &(cx, cy) in &network {
+                                if let TileType::Mycelium(load) = self.tiles[cy][cx] {
+                                    if rng.gen_bool(MYCELIUM_DIEBACK_CHANCE) {
+                                        self.tiles[cy][cx] = if load >= 20 { TileType::NutrientDirt(load) } else { TileType::Dirt };
+                                    }
+                                }
+                            }
+                            continue;
+                        }
+                        
+                        // Draw surplus out of rich dirt alongside, and pool the whole network's load
+                        let mut pool = 0u32;
+                        for &(cx, cy) in &network {
+                            if let TileType::Mycelium(load) = self.tiles[cy][cx] {
+                                pool += load as u32;
+                            }
+                            for (dx, dy) in SIDES {
+                                if let Some((nx, ny)) = self.neighbor(cx, cy, dx, dy) {
+                                    if let TileType::NutrientDirt(level) = self.tiles[ny][nx] {
+                                        if level > MYCELIUM_SURPLUS_LEVEL && rng.gen_bool(MYCELIUM_UPTAKE_CHANCE) {
+                                            self.tiles[ny][nx] = TileType::NutrientDirt(level - MYCELIUM_UPTAKE);
+                                            pool += MYCELIUM_UPTAKE as u32;
+                                        }
+                                    }
+                                }
+                            }
+                            if rng.gen_bool(MYCELIUM_SPREAD_CHANCE) {
+                                let (dx, dy) = SIDES[rng.gen_range(0..SIDES.len())];
+                                growth.extend(self.neighbor(cx, cy, dx, dy));
+                            }
+                        }
+                        
+                        // Feed roots with nothing to absorb around them
+                        for (rx, ry) in roots {
+                            if pool < MYCELIUM_FEED {
+                                break;
+                            }
+                            let starving = !(ry.saturating_sub(1)..=(ry + 1).min(self.height - 1))
+                                .flat_map(|ny| (rx.saturating_sub(1)..=(rx + 1).min(self.width - 1)).map(move |nx| (nx, ny)))
+                                .any(|(nx, ny)| matches!(self.tiles[ny][nx], TileType::Nutrient | TileType::NutrientDirt(_)));
+                            if let TileType::PlantRoot(age, size) = self.tiles[ry][rx] {
+                                if starving {
+                                    pool -= MYCELIUM_FEED;
+                                    self.tiles[ry][rx] = TileType::PlantRoot(age.saturating_sub((MYCELIUM_FEED as f32 * 0.3) as u8), size);
+                                }
+                            }
+                        }
+                        
+                        // Spread what's left evenly back over the network
+                        let share = pool / network.len() as u32;
+                        let mut extra = pool % network.len() as u32;
+                        for &(cx, cy) in &network {
+                            let bonus = if extra > 0 { extra -= 1; 1 } else { 0 };
+                            self.tiles[cy][cx] = TileType::Mycelium((share + bonus).min(255) as u8);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        
+        // New threads only take in dirt, keeping whatever nutrients it held
+        for (x, y) in growth {
+            match self.tiles[y][x] {
+                TileType::Dirt => self.tiles[y][x] = TileType::Mycelium(0),
+                TileType::NutrientDirt(level) => self.tiles[y][x] = TileType::Mycelium(level),
+                _ => {}
+            }
+        }
+    }
+    
+    /// Set a tile alight. Only flammable tiles burn; returns whether it caught, leaving anything
+    /// else, and positions outside the world, as they were.
+    pub fn ignite(&mut self, x: usize, y: usize) -> bool {
+        if x >= self.width || y >= self.height || !self.tiles[y][x].is_flammable() {
+            return false;
+        }
+        self.tiles[y][x] = TileType::Fire(0);
+        true
+    }
+    
+    /// How readily fire spreads right now: hot, dry, drought-stricken weather fans it,
+    /// humidity and rain damp it down
+    pub fn fire_spread_chance(&self) -> f32 {
+        let heat = 1.0 + self.temperature.max(0.0);
+        let dryness = 1.5 - self.humidity;
+        let damping = 1.0 - self.rain_intensity.min(0.9);
+        (FIRE_SPREAD_CHANCE * heat * dryness * damping * (1.0 + self.drought_stress())).min(1.0)
+    }
+    
+    fn update_fire(&mut self, rng: &mut impl Rng) {
+        let mut new_tiles = self.tiles.clone();
+        let spread_chance = self.fire_spread_chance() as f64;
+        let mut burned = Vec::new();
+        
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let TileType::Fire(age) = self.tiles[y][x] else {
+                    continue;
+                };
+                
+                // Water puts the flame out at once
+                let mut doused = false;
+                for dy in -1i32..=1 {
+                    for dx in -1i32..=1 {
+                        if self.neighbor(x, y, dx, dy).is_some_and(|(nx, ny)| self.tiles[ny][nx].is_water()) {
+                            doused = true;
+                        }
+                    }
+                }
+                if doused {
+                    new_tiles[y][x] = TileType::Empty;
+                    continue;
+                }
+                
+                // Burnt out: leave ash behind half the time
+                if age >= FIRE_BURN_TICKS {
+                    new_tiles[y][x] = if rng.gen_bool(0.5) { TileType::Nutrient } else { TileType::Empty };
+                    continue;
+                }
+                new_tiles[y][x] = TileType::Fire(age + 1);
+                
+                // Catch neighbouring fuel
+                for dy in -1i32..=1 {
+                    for dx in -1i32..=1 {
+                        let Some((nx, ny)) = self.neighbor(x, y, dx, dy) else {
+                            continue;
+                        };
+                        if new_tiles[ny][nx].is_flammable() && self.tiles[ny][nx].is_flammable() && rng.gen_bool(spread_chance) {
+                            new_tiles[ny][nx] = TileType::Fire(0);
+                            burned.push((nx, ny));
+                        }
+                    }
+                }
+            }
+        }
+        
+        self.tiles = new_tiles;
+        // Burnt plants leave disturbed ground, like any other death
+        for (x, y) in burned {
+            self.disturb(x, y, DEATH_DISTURBANCE);
+        }
     }
     
-    fn update_life(&mut self) {
-        let mut rng = rand::thread_rng();
+    fn update_life(&mut self, rng: &mut impl Rng) {
         let mut new_tiles = self.tiles.clone();
+        let drought_stress = self.drought_stress();
+        let is_day = self.is_day();
+        self.pillbug_positions.clear();
+        let metabolism = self.pillbug_metabolism() as f64;
+        let aging = self.pillbug_aging();
         
         // Track pillbug segments for coordinated movement
         let mut pillbug_heads: Vec<(usize, usize, Size, u8)> = Vec::new();
+        let mut pillbug_states = HashMap::new();
+        let mut molting_heads = Vec::new();
+        let mut starved_heads = Vec::new();
+        let mut beetle_heads: Vec<(usize, usize, Size, u8)> = Vec::new();
+        let mut beetle_kills = Vec::new();
         
         for y in 0..self.height {
             for x in 0..self.width {
@@ -1494,12 +3459,10 @@ impl World {
                             for dx in -1i32..=1 {
                                 let nx = (x as i32 + dx) as usize;
                                 let ny = (y as i32 + dy) as usize;
-                                if nx < self.width && ny < self.height && rng.gen_bool(0.1) {
-                                    if self.tiles[ny][nx] == TileType::Nutrient {
-                                        new_tiles[ny][nx] = TileType::Empty;
-                                        new_age = new_age.saturating_sub(15); // Absorbing nutrients extends life
-                                        break;
-                                    }
+                                if nx < self.width && ny < self.height && rng.gen_bool(0.1) && self.tiles[ny][nx] == TileType::Nutrient {
+                                    new_tiles[ny][nx] = TileType::Empty;
+                                    new_age = new_age.saturating_sub(15); // Absorbing nutrients extends life
+                                    break;
                                 }
                             }
                         }
@@ -1509,24 +3472,22 @@ impl World {
                         } else {
                             new_tiles[y][x] = TileType::PlantStem(new_age, size);
                             
-                            // Plant growth - affected by seasonal conditions and biome
-                            let biome = self.get_biome_at(x, y);
-                            let seasonal_growth_rate = self.get_seasonal_growth_modifier() 
-                                * growth_rate 
-                                * biome.plant_growth_modifier();
-                            if rng.gen_bool((0.1 * seasonal_growth_rate).min(1.0) as f64) {
-                                // Try to grow upward (extend stem)
-                                if y > 0 && self.tiles[y - 1][x] == TileType::Empty && rng.gen_bool(0.3) {
+                            // Plant growth - affected by seasonal conditions and biome, and slowed under a canopy
+                            let seasonal_growth_rate = self.local_growth_rate(x, y) * growth_rate * self.canopy_light(x, y);
+                            if rng.gen_bool((0.1 * seasonal_growth_rate).min(1.0) as f64) && !self.is_overcrowded(&new_tiles, x, y) {
+                                // Try to grow upward (extend stem), each species at its own pace
+                                let species = self.species_at(x, y);
+                                if y > 0 && self.tiles[y - 1][x] == TileType::Empty && rng.gen_bool(species.stem_extend_chance()) {
                                     new_tiles[y - 1][x] = TileType::PlantStem(0, size);
                                 }
                                 // Grow leaves to the sides
-                                else if x > 0 && self.tiles[y][x - 1] == TileType::Empty && rng.gen_bool(0.4) {
+                                else if x > 0 && self.tiles[y][x - 1] == TileType::Empty && rng.gen_bool(species.leaf_chance()) {
                                     new_tiles[y][x - 1] = TileType::PlantLeaf(0, size);
-                                } else if x < self.width - 1 && self.tiles[y][x + 1] == TileType::Empty && rng.gen_bool(0.4) {
+                                } else if x < self.width - 1 && self.tiles[y][x + 1] == TileType::Empty && rng.gen_bool(species.leaf_chance()) {
                                     new_tiles[y][x + 1] = TileType::PlantLeaf(0, size);
                                 }
                                 // Grow roots downward for nutrient absorption
-                                else if y < self.height - 1 && matches!(self.tiles[y + 1][x], TileType::Empty | TileType::Dirt | TileType::Sand) && rng.gen_bool(0.5) {
+                                else if y < self.height - 1 && matches!(self.tiles[y + 1][x], TileType::Empty | TileType::Dirt | TileType::NutrientDirt(_) | TileType::Sand) && rng.gen_bool(0.5) {
                                     new_tiles[y + 1][x] = TileType::PlantRoot(0, size);
                                 }
                                 // Grow buds that will become flowers
@@ -1540,26 +3501,41 @@ impl World {
                         let new_age = age.saturating_add(1);
                         if new_age > (50.0 * size.lifespan_multiplier()) as u8 {
                             new_tiles[y][x] = TileType::PlantWithered(0, size);
+                        } else if drought_stress > 0.0 && rng.gen_bool(self.wilt_chance(x, y, drought_stress) as f64)
+                            && self.has_open_sky(x, y) && self.is_parched(x, y) {
+                            // Thirsty leaves in full sun wilt during a drought, less so on drought-tolerant plants
+                            new_tiles[y][x] = TileType::PlantWilting(0, size);
+                        } else if is_day && self.shaded_leaf_starves(x, y, rng) {
+                            // Too little light gets through the canopy above to keep the leaf going
+                            new_tiles[y][x] = TileType::PlantWithered(0, size);
+                        } else {
+                            new_tiles[y][x] = TileType::PlantLeaf(new_age, size);
+                        }
+                    }
+                    TileType::PlantWilting(ticks, size) => {
+                        if drought_stress == 0.0 || !self.is_parched(x, y) {
+                            // Rain or water within reach revives the leaf
+                            new_tiles[y][x] = TileType::PlantLeaf(0, size);
+                        } else if ticks >= WILT_TICKS {
+                            new_tiles[y][x] = TileType::PlantWithered(0, size);
                         } else {
-                            new_tiles[y][x] = TileType::PlantLeaf(new_age, size);
+                            new_tiles[y][x] = TileType::PlantWilting(ticks + 1, size);
                         }
                     }
                     TileType::PlantBud(age, size) => {
                         let new_age = age.saturating_add(1);
                         let growth_rate = size.growth_rate_multiplier();
                         
-                        let biome = self.get_biome_at(x, y);
-                        let seasonal_growth_rate = self.get_seasonal_growth_modifier() 
-                            * growth_rate 
-                            * biome.plant_growth_modifier();
+                        let seasonal_growth_rate = self.local_growth_rate(x, y) * growth_rate;
                         if new_age > 25 && rng.gen_bool((0.15 * seasonal_growth_rate).min(1.0) as f64) {
                             // Bud can mature into branch or flower
-                            if rng.gen_bool(0.6) {
-                                // 60% chance to become a branch for Y-shaped growth
+                            if rng.gen_bool(self.species_at(x, y).branch_share()) {
+                                // Usually a branch for Y-shaped growth (trees almost always, cacti rarely)
                                 new_tiles[y][x] = TileType::PlantBranch(0, size);
                             } else {
-                                // 40% chance to become flower for reproduction
+                                // Otherwise a flower for reproduction
                                 new_tiles[y][x] = TileType::PlantFlower(0, size);
+                                self.log_event(format!("A {} flowered at ({}, {})", self.species_at(x, y).name(), x, y));
                             }
                         } else if new_age > 50 {
                             new_tiles[y][x] = TileType::PlantWithered(0, size);
@@ -1577,14 +3553,12 @@ impl World {
                             new_tiles[y][x] = TileType::PlantBranch(new_age, size);
                             
                             // Branches grow diagonally and can spawn leaves/buds
-                            let biome = self.get_biome_at(x, y);
-                            let seasonal_growth_rate = self.get_seasonal_growth_modifier() 
-                                * growth_rate 
-                                * biome.plant_growth_modifier();
-                            if rng.gen_bool((0.08 * seasonal_growth_rate).min(1.0) as f64) {
+                            let seasonal_growth_rate = self.local_growth_rate(x, y) * growth_rate;
+                            let spread = self.species_at(x, y).branch_spread();
+                            if rng.gen_bool((0.08 * seasonal_growth_rate * spread).min(1.0) as f64) && !self.is_overcrowded(&new_tiles, x, y) {
                                 // Diagonal growth patterns for Y-shaped branching
                                 let directions = [(-1, -1), (1, -1), (-1, 1), (1, 1)];
-                                if let Some(&(dx, dy)) = directions.choose(&mut rng) {
+                                if let Some(&(dx, dy)) = directions.choose(rng) {
                                     let nx = (x as i32 + dx) as usize;
                                     let ny = (y as i32 + dy) as usize;
                                     if nx < self.width && ny < self.height && self.tiles[ny][nx] == TileType::Empty {
@@ -1611,18 +3585,15 @@ impl World {
                             new_tiles[y][x] = TileType::PlantFlower(new_age, size);
                             
                             // Flowers produce seeds that can be dispersed by wind
-                            let biome = self.get_biome_at(x, y);
-                            let seasonal_growth_rate = self.get_seasonal_growth_modifier() 
-                                * size.growth_rate_multiplier() 
-                                * biome.plant_growth_modifier();
+                            let seasonal_growth_rate = self.local_growth_rate(x, y) * size.growth_rate_multiplier();
                             
                             // Higher chance during windy conditions for natural dispersal
-                            let wind_boost = 1.0 + (self.wind_strength * 2.0);
+                            let wind_boost = 1.0 + (self.effective_wind_strength() * 2.0);
                             let seed_chance = (0.08 * seasonal_growth_rate * wind_boost).min(1.0);
                             
-                            if rng.gen_bool(seed_chance as f64) {
+                            if rng.gen_bool(seed_chance as f64) && self.is_pollinated(x, y) {
                                 // Shoot seed with velocity instead of placing nearby
-                                let seed_size = if rng.gen_bool(0.7) { size } else { random_size(&mut rng) };
+                                let seed_size = if rng.gen_bool(0.7) { size } else { random_size(rng) };
                                 
                                 // Calculate shooting direction and velocity
                                 let angle = rng.gen_range(0.0..2.0 * std::f32::consts::PI);
@@ -1635,7 +3606,7 @@ impl World {
                                 };
                                 
                                 // Wind can boost seed shooting velocity
-                                let wind_boost = 1.0 + (self.wind_strength * 0.5);
+                                let wind_boost = 1.0 + (self.effective_wind_strength() * 0.5);
                                 let velocity = base_velocity * wind_boost;
                                 
                                 // Prefer upward/outward directions for better dispersal
@@ -1651,8 +3622,8 @@ impl World {
                                     velocity_x,
                                     velocity_y,
                                     seed_type: TileType::Seed(0, seed_size),
-                                    age: 0,
                                     bounce_count: 0,
+                                    genome: self.genome_at(x, y).mutate(rng),
                                 };
                                 
                                 self.seed_projectiles.push(seed_projectile);
@@ -1665,14 +3636,14 @@ impl World {
                             new_tiles[y][x] = TileType::Nutrient;
                             
                             // Sometimes generate spores from decaying organic matter
-                            if rng.gen_bool(0.1) && self.wind_strength > 0.2 {
+                            if rng.gen_bool(0.1) && self.effective_wind_strength() > 0.2 {
                                 // Try to place spore in nearby empty space
                                 let spore_positions = [
                                     (x.saturating_sub(1), y), (x.saturating_add(1), y),
                                     (x, y.saturating_sub(1)), (x, y.saturating_add(1)),
                                 ];
                                 
-                                if let Some((sx, sy)) = spore_positions.iter().choose(&mut rng) {
+                                if let Some((sx, sy)) = spore_positions.iter().choose(rng) {
                                     if *sx < self.width && *sy < self.height && new_tiles[*sy][*sx] == TileType::Empty {
                                         new_tiles[*sy][*sx] = TileType::Spore(0);
                                     }
@@ -1684,15 +3655,19 @@ impl World {
                     }
                     TileType::PlantDiseased(age, size) => {
                         let new_age = age.saturating_add(1);
+                        let disease = self.disease_config;
                         
-                        if new_age > 60 {
+                        if new_age > disease.lethal_age {
                             // Disease kills the plant, turning it into withered plant
                             new_tiles[y][x] = TileType::PlantWithered(0, size);
+                        } else if rng.gen_bool(self.recovery_chance(x, y, drought_stress) as f64) {
+                            // Healthy tissue all around fights the infection off
+                            new_tiles[y][x] = TileType::PlantLeaf(new_age, size);
                         } else {
                             new_tiles[y][x] = TileType::PlantDiseased(new_age, size);
                             
                             // Diseased plants actively spread spores when windy
-                            if new_age > 10 && rng.gen_bool((0.05 + self.wind_strength * 0.1) as f64) {
+                            if new_age > 10 && rng.gen_bool((disease.spore_chance + self.effective_wind_strength() * 0.1).clamp(0.0, 1.0) as f64) {
                                 // Generate spores that spread disease
                                 let spore_positions = [
                                     (x.saturating_sub(1), y), (x.saturating_add(1), y),
@@ -1700,7 +3675,7 @@ impl World {
                                     (x.saturating_sub(1), y.saturating_sub(1)), (x.saturating_add(1), y.saturating_sub(1)),
                                 ];
                                 
-                                if let Some((sx, sy)) = spore_positions.iter().choose(&mut rng) {
+                                if let Some((sx, sy)) = spore_positions.iter().choose(rng) {
                                     if *sx < self.width && *sy < self.height && new_tiles[*sy][*sx] == TileType::Empty {
                                         new_tiles[*sy][*sx] = TileType::Spore(0);
                                     }
@@ -1708,7 +3683,7 @@ impl World {
                             }
                             
                             // Disease spreads to nearby healthy plants
-                            let spread_chance = 0.02 * (1.0 + new_age as f32 / 60.0); // Higher chance as disease progresses
+                            let spread_chance = disease.spread_chance * (1.0 + new_age as f32 / disease.lethal_age.max(1) as f32); // Higher chance as disease progresses
                             for dy in -1i32..=1 {
                                 for dx in -1i32..=1 {
                                     if dx == 0 && dy == 0 { continue; }
@@ -1716,10 +3691,12 @@ impl World {
                                     let nx = (x as i32 + dx) as usize;
                                     let ny = (y as i32 + dy) as usize;
                                     
-                                    if nx < self.width && ny < self.height && rng.gen_bool(spread_chance as f64) {
+                                    let resistance = if nx < self.width && ny < self.height { self.genome_at(nx, ny).disease_resistance } else { 0.0 };
+                                    if nx < self.width && ny < self.height && rng.gen_bool((spread_chance * (1.0 - resistance)).clamp(0.0, 1.0) as f64) {
                                         // Disease can infect healthy plant parts
                                         match self.tiles[ny][nx] {
                                             TileType::PlantLeaf(_leaf_age, leaf_size) |
+                                            TileType::PlantWilting(_leaf_age, leaf_size) |
                                             TileType::PlantBud(_leaf_age, leaf_size) |
                                             TileType::PlantBranch(_leaf_age, leaf_size) |
                                             TileType::PlantFlower(_leaf_age, leaf_size) => {
@@ -1734,9 +3711,49 @@ impl World {
                     }
                     TileType::PlantRoot(age, size) => {
                         let mut new_age = age.saturating_add(1);
-                        let growth_rate = size.growth_rate_multiplier();
+                        let mut growth_rate = size.growth_rate_multiplier();
                         let mut nutrients_absorbed = 0u8;
                         
+                        // Foul water alongside slows absorption and can sicken the root
+                        let worst_water = (y.saturating_sub(1)..=(y + 1).min(self.height - 1))
+                            .flat_map(|ny| (x.saturating_sub(1)..=(x + 1).min(self.width - 1)).map(move |nx| (nx, ny)))
+                            .filter_map(|(nx, ny)| self.tiles[ny][nx].get_water_quality())
+                            .min();
+                        if let Some(quality) = worst_water.filter(|quality| *quality < FOUL_WATER_QUALITY) {
+                            let foulness = 1.0 - quality as f32 / FOUL_WATER_QUALITY as f32;
+                            growth_rate *= 1.0 - foulness * 0.8;
+                            let resistance = self.genome_at(x, y).disease_resistance;
+                            if rng.gen_bool((self.disease_config.foul_water_chance * foulness * (1.0 - resistance)).clamp(0.0, 1.0) as f64) {
+                                new_tiles[y][x] = TileType::PlantDiseased(0, size);
+                                continue;
+                            }
+                        }
+                        
+                        // Roots drink from water alongside, drawing the pool down; bigger plants and
+                        // plants in biomes that hold little moisture are thirstier
+                        let base_thirst = match size {
+                            Size::Small => 2.0,
+                            Size::Medium => 3.0,
+                            Size::Large => 5.0,
+                        };
+                        let thirst = (base_thirst * (2.0 - self.get_biome_at(x, y).moisture_retention())).round() as u8;
+                        let mut water_drunk = 0u8;
+                        for row in &mut new_tiles[y.saturating_sub(1)..=(y + 1).min(self.height - 1)] {
+                            for cell in &mut row[x.saturating_sub(1)..=(x + 1).min(self.width - 1)] {
+                                if let TileType::Water(depth, quality) = *cell {
+                                    let drunk = depth.min(thirst - water_drunk);
+                                    water_drunk += drunk;
+                                    *cell = if drunk < depth { TileType::Water(depth - drunk, quality) } else { TileType::Empty };
+                                }
+                            }
+                        }
+                        if water_drunk > 0 {
+                            new_age = new_age.saturating_sub(water_drunk / 2);
+                            growth_rate *= ROOT_DRINK_GROWTH_BONUS;
+                        } else if drought_stress > ROOT_DROUGHT_AGING_STRESS {
+                            new_age = new_age.saturating_add(1);
+                        }
+                        
                         // Roots actively absorb nearby nutrients
                         let absorption_range = match size {
                             Size::Small => 1,
@@ -1744,8 +3761,8 @@ impl World {
                             Size::Large => 3,
                         };
                         
-                        for dy in -(absorption_range as i32)..=(absorption_range as i32) {
-                            for dx in -(absorption_range as i32)..=(absorption_range as i32) {
+                        for dy in -absorption_range..=absorption_range {
+                            for dx in -absorption_range..=absorption_range {
                                 let nx = (x as i32 + dx) as usize;
                                 let ny = (y as i32 + dy) as usize;
                                 if nx < self.width && ny < self.height {
@@ -1763,8 +3780,7 @@ impl World {
                                                 let extend_y = (y as i32 + steps_y) as usize;
                                                 
                                                 if extend_x < self.width && extend_y < self.height 
-                                                    && matches!(new_tiles[extend_y][extend_x], TileType::Empty) 
-                                                    && new_tiles[extend_y][extend_x].can_support_plants() {
+                                                    && new_tiles[extend_y][extend_x].is_earth() {
                                                     new_tiles[extend_y][extend_x] = TileType::PlantRoot(0, size);
                                                 }
                                             }
@@ -1776,27 +3792,45 @@ impl World {
                                             nutrients_absorbed = nutrients_absorbed.saturating_add(absorbed);
                                             
                                             if remaining < 20 {
-                                                // Nutrient dirt becomes regular dirt
-                                                new_tiles[ny][nx] = TileType::Dirt;
+                                                // Drained nutrient dirt is left exhausted
+                                                new_tiles[ny][nx] = TileType::DepletedDirt;
                                             } else {
                                                 new_tiles[ny][nx] = TileType::NutrientDirt(remaining);
                                             }
                                         },
-                                        TileType::Dirt if rng.gen_bool(0.05) => {
-                                            // Roots can merge with regular dirt, creating nutrient dirt
-                                            new_tiles[ny][nx] = TileType::NutrientDirt(40); // Small amount of nutrients
+                                        TileType::Dirt if rng.gen_bool((0.05 * self.soil_factor_at(nx, ny, SoilLayer::root_penetration)) as f64) => {
+                                            // Roots draw down the dirt's own nutrients, leaving it exhausted
+                                            nutrients_absorbed = nutrients_absorbed.saturating_add(DIRT_NUTRIENTS);
+                                            new_tiles[ny][nx] = TileType::DepletedDirt;
                                             
                                             // Root extends into the dirt
                                             if rng.gen_bool(0.3) {
                                                 new_tiles[ny][nx] = TileType::PlantRoot(0, size);
                                             }
                                         },
+                                        TileType::Clay if rng.gen_bool((0.02 * self.soil_factor_at(nx, ny, SoilLayer::root_penetration)) as f64) => {
+                                            // Roots work slowly into clay without enriching it
+                                            new_tiles[ny][nx] = TileType::PlantRoot(0, size);
+                                        },
                                         _ => {}
                                     }
                                 }
                             }
                         }
                         
+                        // Nitrogen-fixing roots enrich the dirt beside them for whatever grows there
+                        let fixing = self.interaction_config.nitrogen_fixing[self.species_at(x, y) as usize];
+                        if fixing > 0.0 && rng.gen_bool(fixing.min(1.0) as f64) {
+                            let (dx, dy) = (rng.gen_range(-1..=1), rng.gen_range(-1..=1));
+                            if let Some((nx, ny)) = self.offset(x, y, dx, dy).filter(|&(nx, ny)| new_tiles[ny][nx] == self.tiles[ny][nx]) {
+                                match self.tiles[ny][nx] {
+                                    TileType::Dirt | TileType::DepletedDirt => new_tiles[ny][nx] = TileType::NutrientDirt(NITROGEN_FIX_AMOUNT),
+                                    TileType::NutrientDirt(level) => new_tiles[ny][nx] = TileType::NutrientDirt(level.saturating_add(NITROGEN_FIX_AMOUNT)),
+                                    _ => {}
+                                }
+                            }
+                        }
+                        
                         // Nutrients absorbed delay aging (reset some age)
                         if nutrients_absorbed > 0 {
                             let age_reduction = (nutrients_absorbed as f32 * 0.3) as u8; 
@@ -1811,8 +3845,15 @@ impl World {
                         }
                     }
                     TileType::PillbugHead(age, size) => {
-                        pillbug_heads.push((x, y, size, age));
-                        let mut new_age = age.saturating_add(1);
+                        // Rows are visited in order, so the index comes out sorted
+                        self.pillbug_positions.push((x, y, size));
+                        let mut state = self.pillbug_states.get(&(x, y)).cloned().unwrap_or_default();
+                        state.energy = state.energy.saturating_sub(aging);
+                        let mut new_age = age.saturating_add(aging);
+                        if self.count_nearby_pillbugs(x, y, CLUSTER_RADIUS) >= CLUSTER_SIZE && rng.gen_bool(CLUSTER_AGING_RELIEF) {
+                            // Huddled pillbugs keep each other moist and age slower
+                            new_age = age;
+                        }
                         let mut well_fed = false;
                         
                         // Size-based eating behavior - efficiency depends on pillbug and food size
@@ -1822,9 +3863,9 @@ impl World {
                                 let ny = (y as i32 + dy) as usize;
                                 if nx < self.width && ny < self.height {
                                     match self.tiles[ny][nx] {
-                                        TileType::PlantLeaf(_, food_size) | TileType::PlantWithered(_, food_size) | TileType::PlantDiseased(_, food_size) => {
+                                        TileType::PlantLeaf(_, food_size) | TileType::PlantWilting(_, food_size) | TileType::PlantWithered(_, food_size) | TileType::PlantDiseased(_, food_size) | TileType::Seedling(_, food_size) => {
                                             let eating_efficiency = self.calculate_eating_efficiency(size, food_size);
-                                            if rng.gen_bool(eating_efficiency) {
+                                            if rng.gen_bool((eating_efficiency * metabolism).min(1.0)) {
                                                 new_tiles[ny][nx] = TileType::Empty;
                                                 // Nutrition gained depends on food size
                                                 let nutrition = match food_size {
@@ -1832,32 +3873,44 @@ impl World {
                                                     Size::Medium => 5,
                                                     Size::Large => 8,
                                                 };
-                                                new_age = new_age.saturating_sub(nutrition);
+                                                state.eat(nutrition);
                                                 well_fed = true;
                                             }
                                         }
                                         TileType::PlantBranch(_, food_size) => {
                                             // Branches are harder to eat but more nutritious
                                             let eating_efficiency = self.calculate_eating_efficiency(size, food_size) * 0.7;
-                                            if rng.gen_bool(eating_efficiency) {
+                                            if rng.gen_bool((eating_efficiency * metabolism).min(1.0)) {
                                                 new_tiles[ny][nx] = TileType::Empty;
                                                 let nutrition = match food_size {
                                                     Size::Small => 4,
                                                     Size::Medium => 6,
                                                     Size::Large => 10,
                                                 };
-                                                new_age = new_age.saturating_sub(nutrition);
+                                                state.eat(nutrition);
                                                 well_fed = true;
                                             }
                                         }
-                                        TileType::Nutrient => {
-                                            // Nutrients are always easy to consume regardless of pillbug size
-                                            if rng.gen_bool(0.4) {
+                                        TileType::Seed(_, seed_size) => {
+                                            // Granivory - small seeds are easy pickings, large seeds hard to crack
+                                            let eating_efficiency = self.calculate_eating_efficiency(size, seed_size);
+                                            if rng.gen_bool((eating_efficiency * metabolism).min(1.0)) {
                                                 new_tiles[ny][nx] = TileType::Empty;
-                                                new_age = new_age.saturating_sub(4);
+                                                let nutrition = match seed_size {
+                                                    Size::Small => 2,
+                                                    Size::Medium => 4,
+                                                    Size::Large => 6,
+                                                };
+                                                state.eat(nutrition);
                                                 well_fed = true;
                                             }
                                         }
+                                        // Nutrients are always easy to consume regardless of pillbug size
+                                        TileType::Nutrient if rng.gen_bool((0.4 * metabolism).min(1.0)) => {
+                                            new_tiles[ny][nx] = TileType::Empty;
+                                            state.eat(4);
+                                            well_fed = true;
+                                        }
                                         _ => {}
                                     }
                                 }
@@ -1865,30 +3918,73 @@ impl World {
                         }
                         
                         // Reproduction - well-fed mature pillbugs reproduce
-                        if well_fed && age > 30 && age < 100 && rng.gen_bool((0.05 * size.growth_rate_multiplier()).min(1.0) as f64) {
-                            // Try to spawn baby pillbug nearby
-                            for _ in 0..5 {  // Try 5 times to find a spot
-                                let spawn_x = (x as i32 + rng.gen_range(-3..=3)).clamp(2, self.width as i32 - 3) as usize;
+                        let breeding = self.pillbug_config;
+                        if well_fed && age > breeding.maturity_age && age < breeding.max_breeding_age
+                            && rng.gen_bool((breeding.breed_chance * size.growth_rate_multiplier()).clamp(0.0, 1.0) as f64) {
+                            // Babies are born whole, head, body, and legs in a row, so there has to be
+                            // room for all three; with none after 5 tries, no baby this time
+                            for _ in 0..5 {
+                                let spawn_x = (x as i32 + rng.gen_range(-3..=3)).clamp(2, (self.width as i32 - 3).max(2)).min(self.width as i32 - 1) as usize;
                                 let spawn_y = (y as i32 + rng.gen_range(-2..=2)).clamp(0, self.height as i32 - 1) as usize;
-                                
-                                if new_tiles[spawn_y][spawn_x] == TileType::Empty {
+                                let dx = if rng.gen_bool(0.5) { 1 } else { -1 };
+                                let nest = [(0, 0), (dx, 0), (2 * dx, 0)].map(|(ox, oy)| self.neighbor(spawn_x, spawn_y, ox, oy));
+                                let [Some(head), Some(body), Some(legs)] = nest else { continue };
+                                if [head, body, legs].iter().all(|&(nx, ny)| new_tiles[ny][nx] == TileType::Empty) {
                                     // Baby inherits size with chance of variation
-                                    let baby_size = if rng.gen_bool(0.8) { size } else { random_size(&mut rng) };
-                                    // Spawn baby pillbug (just head for now, body will grow)
-                                    new_tiles[spawn_y][spawn_x] = TileType::PillbugHead(0, baby_size);
+                                    let baby_size = if rng.gen_bool(breeding.litter_variation.clamp(0.0, 1.0) as f64) { random_size(rng) } else { size };
+                                    new_tiles[head.1][head.0] = TileType::PillbugHead(0, baby_size);
+                                    new_tiles[body.1][body.0] = TileType::PillbugBody(0, baby_size);
+                                    new_tiles[legs.1][legs.0] = TileType::PillbugLegs(0, baby_size);
+                                    self.log_event(format!("A pillbug was born at ({}, {})", spawn_x, spawn_y));
                                     break;
                                 }
                             }
                         }
                         
-                        if new_age > (150.0 * size.lifespan_multiplier()) as u8 {
+                        if state.energy == 0 {
+                            // Starved, however young: the whole pillbug is left as a carcass
+                            starved_heads.push((x, y));
+                        } else if new_age > (150.0 * size.lifespan_multiplier()) as u8 {
                             new_tiles[y][x] = TileType::PillbugDecaying(0, size);
                         } else {
                             new_tiles[y][x] = TileType::PillbugHead(new_age, size);
+                            
+                            // Molting - old enough, well-fed pillbugs grow into the next size, and
+                            // their new shell takes a while to harden
+                            state.soft = state.soft.saturating_sub(1);
+                            match size.molt_size() {
+                                Some(next_size) if age >= size.molt_age() && state.meals >= size.molt_meals() => {
+                                    molting_heads.push((x, y, next_size));
+                                    state.meals = 0;
+                                    state.soft = SOFT_SHELL_TICKS;
+                                }
+                                _ => pillbug_heads.push((x, y, size, age)),
+                            }
+                            pillbug_states.insert((x, y), state);
+                        }
+                    }
+                    TileType::PillbugRolled(age, size) => {
+                        // Rolled-up pillbugs don't eat, move, or molt, and uncurl once the scare wears off
+                        self.pillbug_positions.push((x, y, size));
+                        let mut state = self.pillbug_states.get(&(x, y)).cloned().unwrap_or_default();
+                        state.energy = state.energy.saturating_sub(aging);
+                        let new_age = age.saturating_add(aging);
+                        if state.energy == 0 {
+                            starved_heads.push((x, y));
+                        } else if new_age > (150.0 * size.lifespan_multiplier()) as u8 {
+                            new_tiles[y][x] = TileType::PillbugDecaying(0, size);
+                        } else {
+                            state.rolled = state.rolled.saturating_sub(1);
+                            new_tiles[y][x] = if state.rolled == 0 {
+                                TileType::PillbugHead(new_age, size)
+                            } else {
+                                TileType::PillbugRolled(new_age, size)
+                            };
+                            pillbug_states.insert((x, y), state);
                         }
                     }
                     TileType::PillbugBody(age, size) => {
-                        let new_age = age.saturating_add(1);
+                        let new_age = age.saturating_add(aging);
                         if new_age > (150.0 * size.lifespan_multiplier()) as u8 {
                             new_tiles[y][x] = TileType::PillbugDecaying(0, size);
                         } else {
@@ -1896,7 +3992,7 @@ impl World {
                         }
                     }
                     TileType::PillbugLegs(age, size) => {
-                        let new_age = age.saturating_add(1);
+                        let new_age = age.saturating_add(aging);
                         if new_age > (150.0 * size.lifespan_multiplier()) as u8 {
                             new_tiles[y][x] = TileType::PillbugDecaying(0, size);
                         } else {
@@ -1911,19 +4007,122 @@ impl World {
                             new_tiles[y][x] = TileType::PillbugDecaying(new_age, size);
                         }
                     }
+                    TileType::BeetleHead(age, size) => {
+                        let mut new_age = age.saturating_add(1);
+                        let mut well_fed = false;
+                        
+                        // Catch one adjacent pillbug; bigger beetles handle bigger prey, like pillbugs with food
+                        'hunt: for dy in -1..=1 {
+                            for dx in -1..=1 {
+                                let Some((nx, ny)) = self.neighbor(x, y, dx, dy) else {
+                                    continue;
+                                };
+                                if let TileType::PillbugHead(_, prey_size) | TileType::PillbugBody(_, prey_size) | TileType::PillbugLegs(_, prey_size) = self.tiles[ny][nx] {
+                                    let already_caught = beetle_kills.iter().any(|&(kx, ky)| self.find_connected_segments(kx, ky, TileType::is_pillbug).iter().any(|(sx, sy, _)| (*sx, *sy) == (nx, ny)));
+                                    // A pillbug rolled into a ball is armored all round, and one fresh from
+                                    // a molt is soft all over
+                                    let prey = self.find_connected_segments(nx, ny, TileType::is_pillbug);
+                                    let rolled_up = prey.iter().any(|(_, _, tile)| matches!(tile, TileType::PillbugRolled(_, _)));
+                                    let soft = prey.iter().any(|(sx, sy, _)| self.pillbug_states.get(&(*sx, *sy)).is_some_and(|state| state.soft > 0));
+                                    let catch_chance = self.calculate_eating_efficiency(size, prey_size) * 1.5 * if soft { SOFT_SHELL_CATCH_MULTIPLIER } else { 1.0 };
+                                    if !already_caught && !rolled_up && rng.gen_bool(catch_chance.min(1.0)) {
+                                        beetle_kills.push((nx, ny));
+                                        let nutrition = match prey_size {
+                                            Size::Small => 30,
+                                            Size::Medium => 40,
+                                            Size::Large => 50,
+                                        };
+                                        new_age = new_age.saturating_sub(nutrition);
+                                        well_fed = true;
+                                        break 'hunt;
+                                    }
+                                }
+                            }
+                        }
+                        
+                        // Reproduction - a fresh kill lets a mature beetle lay an egg nearby
+                        if well_fed && age > 30 && rng.gen_bool((0.2 * size.growth_rate_multiplier()).min(1.0) as f64) {
+                            for _ in 0..5 {
+                                let spawn_x = (x as i32 + rng.gen_range(-3..=3)).clamp(0, self.width as i32 - 1) as usize;
+                                let spawn_y = (y as i32 + rng.gen_range(-2..=2)).clamp(0, self.height as i32 - 1) as usize;
+                                if new_tiles[spawn_y][spawn_x] == TileType::Empty {
+                                    let baby_size = if rng.gen_bool(0.8) { size } else { random_size(rng) };
+                                    new_tiles[spawn_y][spawn_x] = TileType::BeetleHead(0, baby_size);
+                                    break;
+                                }
+                            }
+                        }
+                        
+                        // Beetles that go too long without a kill starve
+                        if new_age > (20.0 * size.lifespan_multiplier()) as u8 {
+                            new_tiles[y][x] = TileType::Nutrient;
+                        } else {
+                            new_tiles[y][x] = TileType::BeetleHead(new_age, size);
+                            beetle_heads.push((x, y, size, age));
+                        }
+                    }
+                    TileType::BeetleBody(age, size) => {
+                        // The body lives as long as its head does
+                        let has_head = self.find_connected_segments(x, y, TileType::is_beetle).iter()
+                            .any(|(_, _, tile)| matches!(tile, TileType::BeetleHead(_, _)));
+                        if has_head {
+                            new_tiles[y][x] = TileType::BeetleBody(age.saturating_add(1), size);
+                        } else {
+                            new_tiles[y][x] = TileType::Nutrient;
+                        }
+                    }
                     _ => {}
                 }
             }
         }
         
+        // Caught pillbugs: the bitten segment is eaten, the rest is left as a carcass
+        let mut killed_heads = HashSet::new();
+        for (x, y) in beetle_kills {
+            for (seg_x, seg_y, tile) in self.find_connected_segments(x, y, TileType::is_pillbug) {
+                if let TileType::PillbugHead(_, _) = tile {
+                    killed_heads.insert((seg_x, seg_y));
+                }
+                new_tiles[seg_y][seg_x] = match tile.get_size() {
+                    Some(size) if (seg_x, seg_y) != (x, y) => TileType::PillbugDecaying(0, size),
+                    _ => TileType::Empty,
+                };
+            }
+        }
+        pillbug_heads.retain(|(x, y, _, _)| !killed_heads.contains(&(*x, *y)));
+        
+        // Starved pillbugs die whole, the same as a carcass a beetle leaves
+        for (x, y) in starved_heads {
+            for (seg_x, seg_y, tile) in self.find_connected_segments(x, y, TileType::is_pillbug) {
+                if let Some(size) = tile.get_size() {
+                    new_tiles[seg_y][seg_x] = TileType::PillbugDecaying(0, size);
+                }
+            }
+        }
+        molting_heads.retain(|(x, y, _)| !killed_heads.contains(&(*x, *y)));
+        
+        // Molt whole pillbugs at once so the segments keep matching sizes
+        for (x, y, next_size) in molting_heads {
+            for (seg_x, seg_y, _) in self.find_connected_segments(x, y, TileType::is_pillbug) {
+                new_tiles[seg_y][seg_x] = match new_tiles[seg_y][seg_x] {
+                    TileType::PillbugHead(age, _) => TileType::PillbugHead(age, next_size),
+                    TileType::PillbugBody(age, _) => TileType::PillbugBody(age, next_size),
+                    TileType::PillbugLegs(age, _) => TileType::PillbugLegs(age, next_size),
+                    other => other,
+                };
+            }
+        }
+        
         // Move pillbugs (heads control movement) and grow baby segments
+        // Molting pillbugs sit still for the tick they molt
         for (x, y, size, age) in pillbug_heads {
-            // Baby pillbugs grow body segments as they mature, but only if they're stable (not falling)
-            let connected_segments = self.find_connected_pillbug_segments(x, y);
-            let is_falling = self.is_pillbug_group_unsupported(&connected_segments);
+            // Pillbugs missing segments, e.g. spawned with no room beside them, grow them as they
+            // mature, but only if they're stable (not falling)
+            let connected_segments = self.find_connected_segments(x, y, TileType::is_pillbug);
+            let is_falling = self.is_group_unsupported(&connected_segments, TileType::is_pillbug);
             
             if !is_falling {
-                if age == 10 {
+                if age == 10 && connected_segments.len() < 2 {
                     // Grow body segment only if stable
                     for (dx, dy) in &[(1, 0), (-1, 0), (0, 1), (0, -1)] {
                         let nx = (x as i32 + dx) as usize;
@@ -1933,7 +4132,7 @@ impl World {
                             break;
                         }
                     }
-                } else if age == 20 {
+                } else if age == 20 && connected_segments.len() < 3 {
                     // Grow legs segment only if stable
                     // Find the body segment first
                     for (dx, dy) in &[(1, 0), (-1, 0), (0, 1), (0, -1)] {
@@ -1962,7 +4161,13 @@ impl World {
                 }
             }
             
-            if rng.gen_bool(0.3) {  // 30% chance to move each tick
+            // Pillbugs wander far more in their active time of day, and more the warmer it is
+            let move_chance = if self.pillbugs_active() {
+                self.activity_config.active_move_chance
+            } else {
+                self.activity_config.resting_move_chance
+            };
+            if rng.gen_bool((move_chance as f64 * metabolism).clamp(0.0, 1.0)) {
                 let movement_speed = match size {
                     Size::Small => 0.5,   // Small bugs move more often
                     Size::Medium => 0.3,
@@ -1970,43 +4175,133 @@ impl World {
                 };
                 
                 if rng.gen_bool(movement_speed) {
-                    self.move_pillbug(&mut new_tiles, x, y, size, age);
+                    let strategy = self.determine_movement_strategy(x, y, size, age, rng);
+                    if let MovementStrategy::Roll = strategy {
+                        // Threatened pillbugs curl up where they stand
+                        if let TileType::PillbugHead(new_age, _) = new_tiles[y][x] {
+                            new_tiles[y][x] = TileType::PillbugRolled(new_age, size);
+                            pillbug_states.entry((x, y)).or_default().rolled = ROLL_TICKS;
+                        }
+                    } else {
+                        let segments = self.pillbug_segments(x, y, size);
+                        if let Some(new_head) = self.move_segments(&mut new_tiles, &segments, &strategy, rng) {
+                            let energy = pillbug_states.get(&(x, y)).map_or(PILLBUG_MAX_ENERGY, |state| state.energy);
+                            let deposit = if energy >= PHEROMONE_FED_ENERGY { PHEROMONE_FED_DEPOSIT } else { PHEROMONE_DEPOSIT };
+                            let scent = &mut self.pheromone[new_head.1][new_head.0];
+                            *scent = scent.saturating_add(deposit);
+                            if let Some(mut state) = pillbug_states.remove(&(x, y)) {
+                                self.carry_seed(&mut new_tiles, &segments, new_head, &mut state, rng);
+                                pillbug_states.insert(new_head, state);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        
+        // Move beetles and grow hatchlings their body segment
+        for (x, y, size, age) in beetle_heads {
+            let connected_segments = self.find_connected_segments(x, y, TileType::is_beetle);
+            if age == 10 && connected_segments.len() == 1 && !self.is_group_unsupported(&connected_segments, TileType::is_beetle) {
+                for (dx, dy) in [(1, 0), (-1, 0), (0, 1), (0, -1)] {
+                    if let Some((nx, ny)) = self.neighbor(x, y, dx, dy).filter(|&(nx, ny)| new_tiles[ny][nx] == TileType::Empty) {
+                        new_tiles[ny][nx] = TileType::BeetleBody(age, size);
+                        break;
+                    }
                 }
             }
+            
+            if rng.gen_bool(0.4) {
+                let strategy = self.determine_beetle_strategy(x, y, size, rng);
+                self.move_segments(&mut new_tiles, &connected_segments, &strategy, rng);
+            }
         }
         
-        // Process seed aging, germination, and spore lifecycle
+        // Process seed aging, germination, seedling establishment, and spore lifecycle
         for y in 0..self.height {
             for x in 0..self.width {
                 match self.tiles[y][x] {
-                    TileType::Seed(age, size) => {
-                        let new_age = age.saturating_add(1);
+                    // Seeds eaten by pillbugs this tick are gone
+                    TileType::Seed(age, size) if new_tiles[y][x] == self.tiles[y][x] => {
+                        // In the cold a seed goes dormant and banks in the soil until spring; the
+                        // bigger it is, the less it ages while it waits
+                        let dormant = self.temperature < SEED_DORMANCY_TEMPERATURE;
+                        let new_age = if dormant && rng.gen_bool(size.dormancy_chance() as f64) { age } else { age.saturating_add(1) };
                         if new_age > 100 {
                             // Old seeds decay into nutrients
                             new_tiles[y][x] = TileType::Nutrient;
                         } else {
                             new_tiles[y][x] = TileType::Seed(new_age, size);
+                            if dormant {
+                                continue;
+                            }
                             
                             // Seeds can germinate under good conditions
-                            let biome = self.get_biome_at(x, y);
-                            let seasonal_growth_rate = self.get_seasonal_growth_modifier() 
-                                * size.growth_rate_multiplier() 
-                                * biome.plant_growth_modifier();
+                            let seasonal_growth_rate = self.local_growth_rate(x, y) * size.growth_rate_multiplier();
                             
                             // Germination requires stable conditions (not too windy, good moisture)
-                            let wind_penalty = 1.0 - (self.wind_strength * 0.5);
-                            let germination_chance = (0.03 * seasonal_growth_rate * wind_penalty).min(1.0);
+                            let wind_penalty = 1.0 - (self.effective_wind_strength() * 0.5);
+                            let drought_penalty = 1.0 - 0.8 * drought_stress;
+                            let succession = self.succession_modifier(x, y, size);
+                            let companions = self.companion_modifier(x, y);
+                            let germination_chance = (0.03 * seasonal_growth_rate * wind_penalty * drought_penalty * succession * companions).min(1.0);
                             
-                            if rng.gen_bool(germination_chance as f64) {
+                            if rng.gen_bool(germination_chance as f64) && !self.is_overcrowded(&new_tiles, x, y) {
                                 // Check if there's soil below for rooting
-                                if y + 1 < self.height && matches!(new_tiles[y + 1][x], TileType::Dirt | TileType::Sand) {
-                                    new_tiles[y][x] = TileType::PlantStem(0, size);
-                                    // Add initial root
-                                    if rng.gen_bool(0.7) {
-                                        new_tiles[y + 1][x] = TileType::PlantRoot(0, size);
+                                if y + 1 < self.height && new_tiles[y + 1][x].is_earth() {
+                                    new_tiles[y][x] = TileType::Seedling(0, size);
+                                    // The sprout takes the form native to the biome it landed in
+                                    self.species[y][x] = Some(PlantSpecies::for_biome(self.get_biome_at(x, y)));
+                                }
+                            }
+                        }
+                    }
+                    // Seedlings eaten by pillbugs this tick are gone
+                    TileType::Seedling(age, size) if new_tiles[y][x] == self.tiles[y][x] => {
+                        let frost = self.temperature < SEED_DORMANCY_TEMPERATURE && rng.gen_bool(SEEDLING_FROST_CHANCE);
+                        let thirst = self.is_parched(x, y)
+                            && rng.gen_bool((SEEDLING_THIRST_CHANCE * (1.0 - self.genome_at(x, y).drought_tolerance)).clamp(0.0, 1.0) as f64);
+                        let trampled = (-1..=1).any(|dy| (-1..=1).any(|dx| {
+                            self.neighbor(x, y, dx, dy).is_some_and(|(nx, ny)| new_tiles[ny][nx].is_pillbug() || new_tiles[ny][nx].is_beetle())
+                        })) && rng.gen_bool(SEEDLING_TRAMPLE_CHANCE);
+                        
+                        if frost || thirst || trampled {
+                            new_tiles[y][x] = TileType::PlantWithered(0, size);
+                        } else if age + 1 >= SEEDLING_ESTABLISH_TICKS {
+                            // Established: it grows into a stem and puts down its first root
+                            new_tiles[y][x] = TileType::PlantStem(0, size);
+                            if y + 1 < self.height && new_tiles[y + 1][x].is_earth() && rng.gen_bool(0.7) {
+                                new_tiles[y + 1][x] = TileType::PlantRoot(0, size);
+                            }
+                        } else {
+                            new_tiles[y][x] = TileType::Seedling(age + 1, size);
+                        }
+                    }
+                    TileType::Algae(age) => {
+                        // Algae feeds on the richness of the water beneath it, using up its oxygen,
+                        // and spreads across the surface of water as rich
+                        match new_tiles.get(y + 1).map(|row| row[x]) {
+                            Some(TileType::Water(depth, quality)) if age < ALGAE_LIFESPAN => {
+                                new_tiles[y][x] = TileType::Algae(age + 1);
+                                new_tiles[y + 1][x] = TileType::Water(depth, quality.saturating_add(ALGAE_UPTAKE));
+                                for (dx, dy) in [(-1, 1), (1, 1), (0, 2)] {
+                                    let Some((nx, ny)) = self.neighbor(x, y, dx, dy) else { continue };
+                                    if let TileType::PlantRoot(_, size) = new_tiles[ny][nx] {
+                                        if rng.gen_bool(ALGAE_SUFFOCATE_CHANCE) {
+                                            new_tiles[ny][nx] = TileType::PlantWithered(0, size);
+                                        }
+                                    }
+                                }
+                                let dx = if rng.gen_bool(0.5) { 1 } else { -1 };
+                                if let Some((nx, _)) = self.neighbor(x, y, dx, 0) {
+                                    let rich_below = matches!(new_tiles[y + 1][nx], TileType::Water(_, quality) if quality < ALGAE_BLOOM_QUALITY);
+                                    if new_tiles[y][nx] == TileType::Empty && rich_below && rng.gen_bool(ALGAE_SPREAD_CHANCE) {
+                                        new_tiles[y][nx] = TileType::Algae(0);
                                     }
                                 }
                             }
+                            // Old or stranded, it dies, often leaving a nutrient to leach back in
+                            _ => new_tiles[y][x] = if rng.gen_bool(ALGAE_NUTRIENT_RETURN) { TileType::Nutrient } else { TileType::Empty },
                         }
                     }
                     TileType::Spore(age) => {
@@ -2018,7 +4313,7 @@ impl World {
                             new_tiles[y][x] = TileType::Spore(new_age);
                             
                             // Spores can occasionally cause plant disease
-                            if new_age > 20 && rng.gen_bool(0.02) {
+                            if new_age > 20 && rng.gen_bool(self.disease_config.spore_infection_chance.clamp(0.0, 1.0) as f64) {
                                 // Look for nearby plants to infect
                                 for dy in -1..=1 {
                                     for dx in -1..=1 {
@@ -2029,8 +4324,8 @@ impl World {
                                             | TileType::PlantStem(plant_age, plant_size) 
                                             | TileType::PlantBranch(plant_age, plant_size) 
                                             | TileType::PlantFlower(plant_age, plant_size) = new_tiles[ny][nx] {
-                                                // Only infect weakened (older) plants
-                                                if plant_age > 30 && rng.gen_bool(0.3) {
+                                                // Only infect weakened (older) plants, and resistant ones less often
+                                                if plant_age > 30 && rng.gen_bool((0.3 * (1.0 - self.genome_at(nx, ny).disease_resistance)) as f64) {
                                                     new_tiles[ny][nx] = TileType::PlantDiseased(0, plant_size);
                                                     new_tiles[y][x] = TileType::Empty; // Spore consumed
                                                     break;
@@ -2047,12 +4342,50 @@ impl World {
             }
         }
         
+        // Drop state for pillbugs that left the world or died this tick
+        pillbug_states.retain(|&(x, y), _| matches!(new_tiles[y][x], TileType::PillbugHead(_, _) | TileType::PillbugRolled(_, _)));
+        
+        // Deaths and pillbug footsteps disturb the ground they happen on
+        for (y, row) in new_tiles.iter().enumerate() {
+            for (x, &new) in row.iter().enumerate() {
+                let old = self.tiles[y][x];
+                let died = matches!(new, TileType::PlantWithered(_, _) | TileType::PillbugDecaying(_, _))
+                    && (old.is_plant() || old.is_pillbug())
+                    && !matches!(old, TileType::PlantWithered(_, _) | TileType::PillbugDecaying(_, _));
+                if died {
+                    self.disturb(x, y, DEATH_DISTURBANCE);
+                } else if (new.is_pillbug() && !old.is_pillbug()) || (new.is_beetle() && !old.is_beetle()) {
+                    self.disturb(x, y, TRAMPLE_DISTURBANCE);
+                }
+            }
+        }
+        
         self.tiles = new_tiles;
+        self.pillbug_states = pillbug_states;
+        self.update_genomes();
+    }
+    
+    /// Chance a sunlit leaf wilts this tick during a drought: fast growers are thirstier,
+    /// drought-tolerant plants hold on
+    fn wilt_chance(&self, x: usize, y: usize, drought_stress: f32) -> f32 {
+        let genome = self.genome_at(x, y);
+        0.05 * drought_stress * genome.growth_speed * (1.0 - genome.drought_tolerance)
+    }
+    
+    /// Whether the air is dry and there's no water or nutrient-rich dirt near enough to a
+    /// position for the plant part there to draw on
+    pub fn is_parched(&self, x: usize, y: usize) -> bool {
+        if self.humidity >= WILT_HUMIDITY {
+            return false;
+        }
+        (-WILT_WATER_RANGE..=WILT_WATER_RANGE).all(|dy| (-WILT_WATER_RANGE..=WILT_WATER_RANGE).all(|dx| {
+            !matches!(self.neighbor(x, y, dx, dy).map(|(nx, ny)| self.tiles[ny][nx]), Some(TileType::Water(_, _) | TileType::NutrientDirt(_)))
+        }))
     }
     
     fn calculate_eating_efficiency(&self, pillbug_size: Size, food_size: Size) -> f64 {
         // Base efficiency based on size matching
-        let base_efficiency = match (pillbug_size, food_size) {
+        match (pillbug_size, food_size) {
             // Perfect size matches are most efficient
             (Size::Small, Size::Small) => 0.35,
             (Size::Medium, Size::Medium) => 0.30,
@@ -2067,22 +4400,32 @@ impl World {
             (Size::Small, Size::Medium) => 0.15,
             (Size::Small, Size::Large) => 0.05,
             (Size::Medium, Size::Large) => 0.20,
-        };
-        
-        base_efficiency
+        }
+    }
+    
+    /// Whether a pillbug moved to `new_positions` would have nothing solid under any segment
+    /// (walking off a ledge or stepping up into open air)
+    fn is_over_drop(&self, new_tiles: &[Vec<TileType>], segments: &[(usize, usize, TileType)], new_positions: &[(usize, usize)]) -> bool {
+        !new_positions.iter().any(|&(nx, ny)| {
+            if ny + 1 >= self.height {
+                return true; // World floor
+            }
+            let below = new_tiles[ny + 1][nx];
+            let own_segment = segments.iter().any(|(sx, sy, _)| *sx == nx && *sy == ny + 1);
+            !own_segment && !matches!(below, TileType::Empty | TileType::Nutrient) && !below.is_water()
+        })
     }
     
-    fn determine_movement_strategy(&self, x: usize, y: usize, size: Size, age: u8) -> MovementStrategy {
-        let mut rng = rand::thread_rng();
+    fn determine_movement_strategy(&self, x: usize, y: usize, size: Size, age: u8, rng: &mut impl Rng) -> MovementStrategy {
         
         // Young pillbugs are more exploratory
         if age < 20 {
-            return MovementStrategy::Explore;
+            return self.explore(x, y, rng);
         }
         
         // Older pillbugs rest more
         if age > 120 {
-            return if rng.gen_bool(0.6) { MovementStrategy::Rest } else { MovementStrategy::Explore };
+            return if rng.gen_bool(0.6) { MovementStrategy::Rest } else { self.explore(x, y, rng) };
         }
         
         let search_radius = match size {
@@ -2090,47 +4433,54 @@ impl World {
             Size::Medium => 4,
             Size::Large => 5,
         };
+        // Resting pillbugs only bother with food close by
+        let active = self.pillbugs_active();
+        let food_radius = if active { search_radius } else { search_radius / 2 };
         
         // Look for food, social targets, and dangers in the area
         let mut food_positions = Vec::new();
         let mut pillbug_positions = Vec::new();
+        // Dangers carry whether they're a creature, the kind of threat worth rolling up against
         let mut danger_positions = Vec::new();
         
-        for dy in -(search_radius as i32)..=(search_radius as i32) {
-            for dx in -(search_radius as i32)..=(search_radius as i32) {
-                let nx = (x as i32 + dx) as usize;
-                let ny = (y as i32 + dy) as usize;
-                if nx < self.width && ny < self.height {
+        // Other pillbugs come from the index: same-size ones are company, larger ones a threat
+        for (px, py, other_size) in self.pillbugs_near(x, y, search_radius as usize) {
+            let (dx, dy) = (px as i32 - x as i32, py as i32 - y as i32);
+            if other_size == size && !(dx == 0 && dy == 0) {
+                pillbug_positions.push((dx, dy));
+            } else if other_size as u8 > size as u8 {
+                danger_positions.push((dx, dy, true));
+            }
+        }
+        
+        // Food and the lie of the land still come from the tiles
+        for dy in -search_radius..=search_radius {
+            for dx in -search_radius..=search_radius {
+                if let Some((nx, ny)) = self.neighbor(x, y, dx, dy) {
                     let tile = self.tiles[ny][nx];
                     
                     // Check for food using utility method
-                    if tile.is_plant() || matches!(tile, TileType::Nutrient) {
-                        // Only count living/withering plants as food
+                    if tile.is_plant() || matches!(tile, TileType::Nutrient | TileType::Seed(_, _)) {
+                        // Only count living/withering plants and fallen seeds as food
                         match tile {
-                            TileType::PlantLeaf(_, _) | TileType::PlantWithered(_, _) | TileType::PlantDiseased(_, _) | TileType::Nutrient => {
+                            TileType::PlantLeaf(_, _) | TileType::PlantWilting(_, _) | TileType::PlantWithered(_, _) | TileType::PlantDiseased(_, _) | TileType::Seedling(_, _) | TileType::Nutrient | TileType::Seed(_, _)
+                                if dx.abs() <= food_radius && dy.abs() <= food_radius => {
                                 food_positions.push((dx, dy));
                             },
                             _ => {}
                         }
                     }
                     
-                    // Check for social interactions
-                    if let TileType::PillbugHead(_, other_size) = tile {
-                        if other_size == size && !(dx == 0 && dy == 0) {
-                            pillbug_positions.push((dx, dy));
-                        }
-                    }
-                    
-                    // Detect dangers - larger pillbugs, unstable areas, deep water
+                    // Detect dangers - predators, unstable areas, deep water
                     match tile {
-                        TileType::PillbugHead(_, other_size) if other_size as u8 > size as u8 => {
-                            // Larger pillbugs are threatening
-                            danger_positions.push((dx, dy));
+                        TileType::BeetleHead(_, _) | TileType::BeetleBody(_, _) => {
+                            // Predators
+                            danger_positions.push((dx, dy, true));
                         },
                         tile if tile.is_water() => {
                             // Standing water is dangerous
                             if dy > 0 {  // Water below is especially dangerous
-                                danger_positions.push((dx, dy));
+                                danger_positions.push((dx, dy, false));
                             }
                         },
                         _ => {
@@ -2138,7 +4488,7 @@ impl World {
                             if matches!(tile, TileType::Sand) {
                                 // Check if sand has support
                                 if ny + 1 < self.height && (self.tiles[ny + 1][nx] == TileType::Empty || self.tiles[ny + 1][nx].is_water()) {
-                                    danger_positions.push((dx, dy));
+                                    danger_positions.push((dx, dy, false));
                                 }
                             }
                         }
@@ -2147,18 +4497,40 @@ impl World {
             }
         }
         
-        // Priority: Avoid Danger > Food > Social > Explore
+        // Same-size pillbugs close enough to huddle with, and the middle of the group in sight
+        let huddled = pillbug_positions.iter()
+            .filter(|(dx, dy)| dx.unsigned_abs() as usize <= CLUSTER_RADIUS && dy.unsigned_abs() as usize <= CLUSTER_RADIUS)
+            .count();
+        let group_direction = {
+            let (sum_x, sum_y) = pillbug_positions.iter().fold((0, 0), |(sx, sy), (dx, dy)| (sx + dx, sy + dy));
+            (sum_x.signum(), sum_y.signum())
+        };
+        
+        // Priority: Avoid Danger > Huddle > Rejoin the group > Food > Social > Explore
         if !danger_positions.is_empty() {
-            // Find closest danger and move away from it
+            // Find closest danger and move away from it, ties going to the first in reading order
             let closest_danger = danger_positions.iter()
-                .min_by_key(|(dx, dy)| dx.abs() + dy.abs())
+                .min_by_key(|(dx, dy, _)| (dx.abs() + dy.abs(), *dy, *dx))
                 .unwrap();
             
+            // Cornered by a predator or a bigger pillbug, sometimes roll into a ball instead of running,
+            // unless a fresh molt has left the shell too soft to curl
+            let soft = self.pillbug_states.get(&(x, y)).is_some_and(|state| state.soft > 0);
+            if closest_danger.2 && !soft && rng.gen_bool(ROLL_CHANCE) {
+                return MovementStrategy::Roll;
+            }
+            
             // Move in opposite direction
             let dir_x = if closest_danger.0 > 0 { -1 } else if closest_danger.0 < 0 { 1 } else { 0 };
             let dir_y = if closest_danger.1 > 0 { -1 } else if closest_danger.1 < 0 { 1 } else { 0 };
             
             MovementStrategy::Avoid((dir_x, dir_y))
+        } else if huddled >= CLUSTER_SIZE && rng.gen_bool(CLUSTER_HUDDLE_CHANCE) {
+            // Clustered pillbugs stay put, eating only what's within reach
+            MovementStrategy::Rest
+        } else if huddled == 0 && !pillbug_positions.is_empty() && rng.gen_bool(CLUSTER_SEEK_CHANCE) {
+            // A lone pillbug heads for the group it can see
+            MovementStrategy::Social(group_direction)
         } else if !food_positions.is_empty() {
             // Find closest food
             let closest_food = food_positions.iter()
@@ -2171,32 +4543,144 @@ impl World {
             
             MovementStrategy::SeekFood((dir_x, dir_y))
         } else if !pillbug_positions.is_empty() && rng.gen_bool(0.3) {
-            // Sometimes seek social interaction
-            let closest_pillbug = pillbug_positions.iter()
-                .min_by_key(|(dx, dy)| dx.abs() + dy.abs())
-                .unwrap();
-            
-            let dir_x = if closest_pillbug.0 > 0 { 1 } else if closest_pillbug.0 < 0 { -1 } else { 0 };
-            let dir_y = if closest_pillbug.1 > 0 { 1 } else if closest_pillbug.1 < 0 { -1 } else { 0 };
-            
-            MovementStrategy::Social((dir_x, dir_y))
+            // Sometimes draw in closer to the group
+            MovementStrategy::Social(group_direction)
         } else {
-            // Default to exploration or rest
-            if rng.gen_bool(0.7) { MovementStrategy::Explore } else { MovementStrategy::Rest }
+            // Default to exploration or rest, mostly rest outside the active time of day
+            let explore_chance = if active { 0.8 } else { 0.3 };
+            if rng.gen_bool(explore_chance) { self.explore(x, y, rng) } else { MovementStrategy::Rest }
+        }
+    }
+    
+    /// Wander off, usually along a pheromone trail beside the pillbug if there is one
+    fn explore(&self, x: usize, y: usize, rng: &mut impl Rng) -> MovementStrategy {
+        match self.trail_direction(x, y, rng) {
+            Some(direction) if rng.gen_bool(PHEROMONE_FOLLOW_CHANCE) => MovementStrategy::Trail(direction),
+            _ => MovementStrategy::Explore,
+        }
+    }
+    
+    /// Step toward one of the four cells beside a position, picked with odds in proportion to its
+    /// pheromone, or None with no trail beside it. Cells under pillbugs, its own body included,
+    /// don't count, so a pillbug doesn't just double back along the trail it's laying
+    fn trail_direction(&self, x: usize, y: usize, rng: &mut impl Rng) -> Option<(i32, i32)> {
+        let scents: Vec<((i32, i32), u32)> = [(-1, 0), (1, 0), (0, -1), (0, 1)].into_iter()
+            .filter_map(|(dx, dy)| self.neighbor(x, y, dx, dy).map(|(nx, ny)| ((dx, dy), nx, ny)))
+            .filter(|&(_, nx, ny)| !self.tiles[ny][nx].is_pillbug())
+            .map(|(direction, nx, ny)| (direction, self.pheromone[ny][nx] as u32))
+            .collect();
+        let total: u32 = scents.iter().map(|(_, scent)| scent).sum();
+        if total == 0 {
+            return None;
+        }
+        let mut pick = rng.gen_range(0..total);
+        scents.into_iter().find_map(|(direction, scent)| {
+            if pick < scent {
+                Some(direction)
+            } else {
+                pick -= scent;
+                None
+            }
+        })
+    }
+    
+    /// Pillbugs of any size, curled up or not, with their heads within `radius` (in both
+    /// directions) of a position, not counting one whose head is at the position itself
+    pub fn count_nearby_pillbugs(&self, x: usize, y: usize, radius: usize) -> usize {
+        let radius = radius as i32;
+        let mut count = 0;
+        for dy in -radius..=radius {
+            for dx in -radius..=radius {
+                if (dx, dy) == (0, 0) {
+                    continue;
+                }
+                if let Some((nx, ny)) = self.neighbor(x, y, dx, dy) {
+                    if matches!(self.tiles[ny][nx], TileType::PillbugHead(_, _) | TileType::PillbugRolled(_, _)) {
+                        count += 1;
+                    }
+                }
+            }
+        }
+        count
+    }
+    
+    /// Pillbug heads within `radius` (in both directions) of a position, in reading order,
+    /// looked up in `pillbug_positions` rather than scanning the tiles
+    pub fn pillbugs_near(&self, x: usize, y: usize, radius: usize) -> impl Iterator<Item = (usize, usize, Size)> + '_ {
+        let first = self.pillbug_positions.partition_point(|&(_, py, _)| py + radius < y);
+        self.pillbug_positions[first..].iter()
+            .copied()
+            .take_while(move |&(_, py, _)| py <= y + radius)
+            .filter(move |&(px, _, _)| px + radius >= x && px <= x + radius)
+    }
+    
+    /// Beetles chase the nearest pillbug they can see, and otherwise wander
+    fn determine_beetle_strategy(&self, x: usize, y: usize, size: Size, rng: &mut impl Rng) -> MovementStrategy {
+        let search_radius = match size {
+            Size::Small => 4,
+            Size::Medium => 5,
+            Size::Large => 6,
+        };
+        
+        let mut closest_prey: Option<(i32, i32)> = None;
+        for (px, py, _) in self.pillbugs_near(x, y, search_radius) {
+            let (dx, dy) = (px as i32 - x as i32, py as i32 - y as i32);
+            if closest_prey.is_none_or(|(cx, cy)| dx.abs() + dy.abs() < cx.abs() + cy.abs()) {
+                closest_prey = Some((dx, dy));
+            }
+        }
+        
+        match closest_prey {
+            Some((dx, dy)) => MovementStrategy::Hunt((dx.signum(), dy.signum())),
+            None => if rng.gen_bool(0.8) { MovementStrategy::Explore } else { MovementStrategy::Rest },
+        }
+    }
+    
+    /// Move a pillbug and its segments together, returning the new head position if it moved
+    /// A pillbug whose `segments` just moved, bringing its head to `head`, picks up a seed beside
+    /// its head or, once it has carried one far enough, drops it on a cell it just walked off,
+    /// spreading plants where the wind doesn't
+    fn carry_seed(&mut self, new_tiles: &mut [Vec<TileType>], segments: &[(usize, usize, TileType)], head: (usize, usize), state: &mut PillbugState, rng: &mut impl Rng) {
+        match state.seed {
+            Some((seed, genome)) => {
+                state.carried_for = state.carried_for.saturating_add(1);
+                if state.carried_for < SEED_CARRY_MOVES || !rng.gen_bool(SEED_DROP_CHANCE) {
+                    return;
+                }
+                // Dropped off the back of the bug, where it won't just be eaten
+                let spot = segments.iter().rev()
+                    .map(|&(sx, sy, _)| (sx, sy))
+                    .find(|&(sx, sy)| new_tiles[sy][sx] == TileType::Empty);
+                if let Some((nx, ny)) = spot {
+                    new_tiles[ny][nx] = seed;
+                    self.genomes[ny][nx] = Some(genome);
+                    state.seed = None;
+                    state.carried_for = 0;
+                }
+            }
+            None => {
+                let seed = [(0, 1), (-1, 0), (1, 0), (0, -1)].iter()
+                    .filter_map(|&(dx, dy)| self.neighbor(head.0, head.1, dx, dy))
+                    .find(|&(nx, ny)| matches!(new_tiles[ny][nx], TileType::Seed(_, _)));
+                if let Some((nx, ny)) = seed {
+                    if rng.gen_bool(SEED_PICKUP_CHANCE) {
+                        state.seed = Some((new_tiles[ny][nx], self.genome_at(nx, ny)));
+                        new_tiles[ny][nx] = TileType::Empty;
+                    }
+                }
+            }
         }
     }
     
-    fn move_pillbug(&self, new_tiles: &mut Vec<Vec<TileType>>, x: usize, y: usize, size: Size, age: u8) {
-        let mut rng = rand::thread_rng();
+    /// The head at a position and the body and legs attached to it, head first
+    fn pillbug_segments(&self, x: usize, y: usize, size: Size) -> Vec<(usize, usize, TileType)> {
         
         // Find connected body parts (should be adjacent)
-        let mut segments = vec![(x, y, TileType::PillbugHead(age, size))];
+        let mut segments = vec![(x, y, self.tiles[y][x])];
         
         // Look for body segments adjacent to head using utility methods
-        for (dx, dy) in &[(0, 1), (1, 0), (-1, 0), (0, -1)] {
-            let nx = (x as i32 + dx) as usize;
-            let ny = (y as i32 + dy) as usize;
-            if nx < self.width && ny < self.height {
+        for (dx, dy) in [(0, 1), (1, 0), (-1, 0), (0, -1)] {
+            if let Some((nx, ny)) = self.neighbor(x, y, dx, dy) {
                 let tile = self.tiles[ny][nx];
                 // Use is_pillbug utility to check if it's a pillbug part
                 if tile.is_pillbug() {
@@ -2205,10 +4689,8 @@ impl World {
                             segments.push((nx, ny, tile));
                             
                             // Look for legs adjacent to body
-                            for (dx2, dy2) in &[(0, 1), (1, 0), (-1, 0), (0, -1)] {
-                                let lx = (nx as i32 + dx2) as usize;
-                                let ly = (ny as i32 + dy2) as usize;
-                                if lx < self.width && ly < self.height {
+                            for (dx2, dy2) in [(0, 1), (1, 0), (-1, 0), (0, -1)] {
+                                if let Some((lx, ly)) = self.neighbor(nx, ny, dx2, dy2) {
                                     let leg_tile = self.tiles[ly][lx];
                                     if let TileType::PillbugLegs(_l_age, l_size) = leg_tile {
                                         if l_size == size && leg_tile.get_size() == Some(size) {
@@ -2223,66 +4705,91 @@ impl World {
             }
         }
         
+        segments
+    }
+    
+    /// Move a creature's segments together one step the way its strategy points, the head first
+    /// in `segments`. Returns the new head position if it moved.
+    fn move_segments(&self, new_tiles: &mut [Vec<TileType>], segments: &[(usize, usize, TileType)], strategy: &MovementStrategy, rng: &mut impl Rng) -> Option<(usize, usize)> {
         // Use movement strategy to determine direction
-        let strategy = self.determine_movement_strategy(x, y, size, age);
-        let (dx, dy) = strategy.get_movement_vector(&mut rng);
+        let (dx, dy) = strategy.get_movement_vector(rng);
         
         // Skip movement if strategy says not to move
-        if !strategy.should_move(&mut rng) {
-            return;
+        if !strategy.should_move(rng) {
+            return None;
         }
         
         // Check if movement is possible
         if dx == 0 && dy == 0 {
-            return;  // No movement
+            return None;  // No movement
+        }
+        
+        // The floor and ceiling are solid, whatever the edge behavior
+        if segments.iter().any(|(_, seg_y, _)| !(0..self.height as i32).contains(&(*seg_y as i32 + dy))) {
+            return None;
+        }
+        
+        // Walking off the east or west border: leave the world, turn around, or wrap, per edge behavior
+        let mut dx = dx;
+        let leaves_world = !self.wrap_horizontal && segments.iter().any(|(seg_x, _, _)| {
+            !(0..self.width as i32).contains(&(*seg_x as i32 + dx))
+        });
+        if leaves_world {
+            match self.edge_behavior {
+                EdgeBehavior::Absorb => {
+                    for (seg_x, seg_y, _) in segments {
+                        new_tiles[*seg_y][*seg_x] = TileType::Empty;
+                    }
+                    return None;
+                }
+                EdgeBehavior::Reflect => dx = -dx,
+                EdgeBehavior::Wrap => {}
+            }
         }
         
-        let new_x = x as i32 + dx;
-        let new_y = y as i32 + dy;
+        // Check if all segments can move
+        let mut can_move = true;
+        let mut new_positions = Vec::new();
         
-        if new_x >= 0 && new_x < self.width as i32 && new_y >= 0 && new_y < self.height as i32 {
-            // Check if all segments can move
-            let mut can_move = true;
-            let mut new_positions = Vec::new();
+        for (seg_x, seg_y, _) in segments {
+            let Some((new_seg_x, new_seg_y)) = self.resolve_edge(*seg_x as i32 + dx, *seg_y as i32 + dy) else {
+                can_move = false;
+                break;
+            };
             
-            for (seg_x, seg_y, _) in &segments {
-                let new_seg_x = *seg_x as i32 + dx;
-                let new_seg_y = *seg_y as i32 + dy;
-                
-                if new_seg_x < 0 || new_seg_x >= self.width as i32 || new_seg_y < 0 || new_seg_y >= self.height as i32 {
+            // Check if destination is empty or will be vacated by another segment
+            let dest_tile = new_tiles[new_seg_y][new_seg_x];
+            if !matches!(dest_tile, TileType::Empty | TileType::Nutrient) {
+                // Check if it's occupied by another segment of the same bug
+                let occupied_by_self = segments.iter().any(|(sx, sy, _)| *sx == new_seg_x && *sy == new_seg_y);
+                if !occupied_by_self {
                     can_move = false;
                     break;
                 }
-                
-                let new_seg_x = new_seg_x as usize;
-                let new_seg_y = new_seg_y as usize;
-                
-                // Check if destination is empty or will be vacated by another segment
-                let dest_tile = new_tiles[new_seg_y][new_seg_x];
-                if !matches!(dest_tile, TileType::Empty | TileType::Nutrient) {
-                    // Check if it's occupied by another segment of the same bug
-                    let occupied_by_self = segments.iter().any(|(sx, sy, _)| *sx == new_seg_x && *sy == new_seg_y);
-                    if !occupied_by_self {
-                        can_move = false;
-                        break;
-                    }
-                }
-                
-                new_positions.push((new_seg_x, new_seg_y));
             }
             
-            if can_move {
-                // Clear old positions
-                for (seg_x, seg_y, _) in &segments {
-                    new_tiles[*seg_y][*seg_x] = TileType::Empty;
-                }
-                
-                // Place segments in new positions
-                for (i, (new_seg_x, new_seg_y)) in new_positions.iter().enumerate() {
-                    new_tiles[*new_seg_y][*new_seg_x] = segments[i].2;
-                }
+            new_positions.push((new_seg_x, new_seg_y));
+        }
+        
+        // Ledges are a mild hazard - usually stay on solid ground rather than step into the void, unless fleeing
+        if can_move && !matches!(strategy, MovementStrategy::Avoid(_))
+            && self.is_over_drop(new_tiles, segments, &new_positions) && rng.gen_bool(0.9) {
+            can_move = false;
+        }
+        
+        if can_move {
+            // Clear old positions
+            for (seg_x, seg_y, _) in segments {
+                new_tiles[*seg_y][*seg_x] = TileType::Empty;
+            }
+            
+            // Place segments in new positions
+            for (i, (new_seg_x, new_seg_y)) in new_positions.iter().enumerate() {
+                new_tiles[*new_seg_y][*new_seg_x] = segments[i].2;
             }
+            return Some(new_positions[0]);
         }
+        None
     }
     
     fn spawn_pillbug(&mut self, x: usize, y: usize, size: Size, age: u8) {
@@ -2307,53 +4814,85 @@ impl World {
         }
     }
     
-    fn spawn_entities(&mut self) {
-        let mut rng = rand::thread_rng();
+    fn spawn_beetle(&mut self, x: usize, y: usize, size: Size, age: u8) {
+        // Head with the body behind it, on whichever side has room
+        self.tiles[y][x] = TileType::BeetleHead(age, size);
+        if x + 1 < self.width && self.tiles[y][x + 1] == TileType::Empty {
+            self.tiles[y][x + 1] = TileType::BeetleBody(age, size);
+        } else if x > 0 && self.tiles[y][x - 1] == TileType::Empty {
+            self.tiles[y][x - 1] = TileType::BeetleBody(age, size);
+        }
+    }
+    
+    fn spawn_entities(&mut self, rng: &mut impl Rng) {
         
         // Count existing entities using utility methods
         let mut plant_count = 0;
         let mut pillbug_count = 0;
+        let mut beetle_heads = Vec::new();
         
         for y in 0..self.height {
             for x in 0..self.width {
                 let tile = self.tiles[y][x];
+                if matches!(tile, TileType::BeetleHead(_, _)) {
+                    beetle_heads.push((x, y));
+                }
                 // Count plant stems as primary plant entities
                 if matches!(tile, TileType::PlantStem(_, _)) {
                     plant_count += 1;
                 }
                 // Count pillbug heads as primary pillbug entities
-                if matches!(tile, TileType::PillbugHead(_, _)) {
+                if matches!(tile, TileType::PillbugHead(_, _) | TileType::PillbugRolled(_, _)) {
                     pillbug_count += 1;
                 }
             }
         }
         
-        // Spawn new entities if needed
-        if plant_count < 2 {
-            for _ in 0..(3 - plant_count) {
+        // Spawn new entities if needed, unless the world is closed
+        let respawn = self.respawn_config;
+        if self.allow_respawn && plant_count < respawn.min_plants {
+            for _ in 0..respawn.plant_target.saturating_sub(plant_count) {
                 let x = rng.gen_range(0..self.width);
-                let y = rng.gen_range(0..5);
+                let y = rng.gen_range(0..self.height.min(5));
                 if self.tiles[y][x] == TileType::Empty {
-                    let size = random_size(&mut rng);
+                    let size = random_size(rng);
                     self.tiles[y][x] = TileType::PlantStem(5, size);
                 }
             }
         }
         
-        if pillbug_count < 1 {
-            for _ in 0..(2 - pillbug_count) {
-                let x = rng.gen_range(2..self.width.saturating_sub(2).max(3));
-                let y = rng.gen_range(0..self.height.saturating_sub(2));
+        if self.allow_respawn && pillbug_count < respawn.min_pillbugs {
+            for _ in 0..respawn.pillbug_target.saturating_sub(pillbug_count) {
+                let x = rng.gen_range(2..self.width.saturating_sub(2).max(3)).min(self.width - 1);
+                let y = rng.gen_range(0..self.height.saturating_sub(2).max(1));
                 if self.tiles[y][x] == TileType::Empty {
-                    let size = random_size(&mut rng);
+                    let size = random_size(rng);
                     self.spawn_pillbug(x, y, size, 10);
                 }
             }
         }
         
+        // A beetle wanders in once there are enough pillbugs to hunt
+        if self.allow_respawn && beetle_heads.is_empty() && pillbug_count >= BEETLE_MIN_PREY && rng.gen_bool(0.002) {
+            let x = rng.gen_range(1..self.width.saturating_sub(1).max(2)).min(self.width - 1);
+            let y = rng.gen_range(0..self.height.saturating_sub(2).max(1));
+            if self.tiles[y][x] == TileType::Empty {
+                let size = random_size(rng);
+                self.spawn_beetle(x, y, size, 0);
+            }
+        }
+        
+        // Prey only supports so many predators; the rest starve
+        let beetle_cap = (pillbug_count / PREY_PER_BEETLE).max(1);
+        for &(x, y) in beetle_heads.iter().skip(beetle_cap) {
+            for (seg_x, seg_y, _) in self.find_connected_segments(x, y, TileType::is_beetle) {
+                self.tiles[seg_y][seg_x] = TileType::Nutrient;
+            }
+        }
+        
         // Randomly introduce plant diseases (very rare)
         // Disease introduction is more likely in humid conditions and during certain seasons
-        let base_disease_chance = 0.0005; // Realistic but observable disease chance
+        let base_disease_chance = self.disease_config.outbreak_chance;
         let seasonal_disease_modifier = match self.get_current_season() {
             Season::Summer => 1.5,  // Hot humid summers increase disease risk
             Season::Fall => 1.2,    // Wet fall conditions favor disease
@@ -2363,7 +4902,7 @@ impl World {
         let humidity_modifier = 1.0 + self.humidity; // Higher humidity increases disease risk
         let disease_chance = base_disease_chance * seasonal_disease_modifier * humidity_modifier;
         
-        if rng.gen_bool(disease_chance as f64) {
+        if rng.gen_bool(disease_chance.clamp(0.0, 1.0) as f64) {
             // Find a random healthy plant part to infect
             let mut attempts = 0;
             while attempts < 50 {
@@ -2375,8 +4914,10 @@ impl World {
                     TileType::PlantBud(_age, size) |
                     TileType::PlantBranch(_age, size) |
                     TileType::PlantFlower(_age, size) => {
-                        // Introduce disease to this plant part
-                        self.tiles[y][x] = TileType::PlantDiseased(0, size);
+                        // Introduce disease to this plant part, unless it fights the infection off
+                        if rng.gen_bool((1.0 - self.genome_at(x, y).disease_resistance) as f64) {
+                            self.tiles[y][x] = TileType::PlantDiseased(0, size);
+                        }
                         break;
                     }
                     _ => {}
@@ -2388,13 +4929,24 @@ impl World {
     
     // Calculate ecosystem statistics for monitoring
     pub fn calculate_ecosystem_stats(&self) -> EcosystemStats {
+        let by_size = || [Size::Small, Size::Medium, Size::Large].iter().map(|&size| (size, 0)).collect::<BTreeMap<_, _>>();
+        let by_biome = || [Biome::Wetland, Biome::Grassland, Biome::Drylands, Biome::Woodland].iter().map(|&biome| (biome, 0)).collect::<BTreeMap<_, _>>();
         let mut stats = EcosystemStats {
+            tick: self.tick,
             total_plants: 0,
             total_pillbugs: 0,
+            total_beetles: 0,
             water_coverage: 0,
             nutrient_count: 0,
             plant_health_ratio: 0.0,
             biome_diversity: 0,
+            ticks_since_rain: self.ticks_since_rain,
+            drought_stress: self.drought_stress(),
+            plants_by_biome: by_biome(),
+            pillbugs_by_biome: by_biome(),
+            plants_by_size: by_size(),
+            pillbugs_by_size: by_size(),
+            beetles_by_size: by_size(),
         };
         
         let mut healthy_plants = 0;
@@ -2407,28 +4959,46 @@ impl World {
                     // Count plant parts
                     TileType::PlantStem(_, _) | TileType::PlantLeaf(_, _) | 
                     TileType::PlantBud(_, _) | TileType::PlantBranch(_, _) | 
-                    TileType::PlantFlower(_, _) | TileType::PlantRoot(_, _) => {
+                    TileType::PlantFlower(_, _) | TileType::PlantRoot(_, _) | TileType::Seedling(_, _) => {
                         stats.total_plants += 1;
                         healthy_plants += 1;
                     },
-                    TileType::PlantWithered(_, _) | TileType::PlantDiseased(_, _) => {
+                    TileType::PlantWilting(_, _) | TileType::PlantWithered(_, _) | TileType::PlantDiseased(_, _) => {
                         stats.total_plants += 1;
                         _diseased_plants += 1;
                     },
                     
                     // Count pillbug parts
                     TileType::PillbugHead(_, _) | TileType::PillbugBody(_, _) | 
-                    TileType::PillbugLegs(_, _) | TileType::PillbugDecaying(_, _) => {
+                    TileType::PillbugLegs(_, _) | TileType::PillbugDecaying(_, _) | TileType::PillbugRolled(_, _) => {
                         stats.total_pillbugs += 1;
                     },
+                    TileType::BeetleHead(_, _) | TileType::BeetleBody(_, _) => {
+                        stats.total_beetles += 1;
+                    },
                     
                     // Count environmental elements
-                    TileType::Water(_) => stats.water_coverage += 1,
+                    TileType::Water(_, _) => stats.water_coverage += 1,
                     TileType::Nutrient => stats.nutrient_count += 1,
                     
                     _ => {},
                 }
                 
+                // Break plants and creatures down by biome and size
+                let tile = self.tiles[y][x];
+                if let Some(size) = tile.get_size() {
+                    let counts = if tile.is_plant() {
+                        *stats.plants_by_biome.entry(self.biome_map[y][x]).or_default() += 1;
+                        &mut stats.plants_by_size
+                    } else if tile.is_pillbug() {
+                        *stats.pillbugs_by_biome.entry(self.biome_map[y][x]).or_default() += 1;
+                        &mut stats.pillbugs_by_size
+                    } else {
+                        &mut stats.beetles_by_size
+                    };
+                    *counts.entry(size).or_default() += 1;
+                }
+                
                 // Track biome diversity
                 biome_types.insert(std::mem::discriminant(&self.biome_map[y][x]));
             }
@@ -2442,13 +5012,105 @@ impl World {
         stats.biome_diversity = biome_types.len();
         stats
     }
+    
+    /// Whether the ecosystem is thriving, declining, collapsed, or extinct, judged from the plant and
+    /// pillbug populations in `population_history` (or as the world stands now, before the first
+    /// sample), taking whichever of the two is faring worse
+    pub fn ecosystem_phase(&self) -> EcosystemPhase {
+        let mut samples: Vec<(usize, usize)> = self.population_history.iter().map(|stats| (stats.total_plants, stats.total_pillbugs)).collect();
+        if samples.is_empty() {
+            let stats = self.calculate_ecosystem_stats();
+            samples.push((stats.total_plants, stats.total_pillbugs));
+        }
+        let plants: Vec<usize> = samples.iter().map(|&(plants, _)| plants).collect();
+        let pillbugs: Vec<usize> = samples.iter().map(|&(_, pillbugs)| pillbugs).collect();
+        EcosystemPhase::of_population(&plants).max(EcosystemPhase::of_population(&pillbugs))
+    }
+    
+    /// Add an event to the feed at the current tick, forgetting the oldest past `MAX_EVENTS`
+    pub fn log_event(&mut self, message: String) {
+        if self.events.len() >= MAX_EVENTS {
+            self.events.pop_front();
+        }
+        self.events.push_back(WorldEvent { tick: self.tick, message });
+    }
+    
+    /// Log the thresholds crossed since the last population sample: disease breaking out among
+    /// plants free of it, a lake filling, and the ecosystem collapsing or dying out
+    fn watch_for_events(&mut self) {
+        let diseased = (0..self.height).flat_map(|y| (0..self.width).map(move |x| (x, y)))
+            .find(|&(x, y)| matches!(self.tiles[y][x], TileType::PlantDiseased(_, _)));
+        if let Some((x, y)) = diseased.filter(|_| !self.event_watch.outbreak) {
+            self.log_event(format!("Disease broke out among the plants at ({}, {})", x, y));
+        }
+        self.event_watch.outbreak = diseased.is_some();
+        
+        let (lake_size, (x, y)) = self.largest_lake();
+        if lake_size >= LAKE_TILES && !self.event_watch.lake {
+            self.log_event(format!("A lake of {} water tiles formed around ({}, {})", lake_size, x, y));
+            self.event_watch.lake = true;
+        } else if lake_size < LAKE_TILES / 2 {
+            self.event_watch.lake = false;
+        }
+        
+        let phase = self.ecosystem_phase();
+        if phase != self.event_watch.phase && phase >= EcosystemPhase::Collapsed {
+            let stats = self.population_history.last().map_or((0, 0), |stats| (stats.total_plants, stats.total_pillbugs));
+            self.log_event(format!("Extinction warning: the ecosystem {} ({} plant tiles, {} pillbug tiles)", phase.name().to_lowercase(), stats.0, stats.1));
+        }
+        self.event_watch.phase = phase;
+    }
+    
+    /// Tiles in the biggest body of water touching side to side, and one of them
+    fn largest_lake(&self) -> (usize, (usize, usize)) {
+        let mut seen = vec![vec![false; self.width]; self.height];
+        let mut largest = (0, (0, 0));
+        for y in 0..self.height {
+            for x in 0..self.width {
+                if seen[y][x] || !self.tiles[y][x].is_water() {
+                    continue;
+                }
+                seen[y][x] = true;
+                let (mut size, mut stack) = (0, vec![(x, y)]);
+                while let Some((cx, cy)) = stack.pop() {
+                    size += 1;
+                    for (dx, dy) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+                        if let Some((nx, ny)) = self.neighbor(cx, cy, dx, dy) {
+                            if !seen[ny][nx] && self.tiles[ny][nx].is_water() {
+                                seen[ny][nx] = true;
+                                stack.push((nx, ny));
+                            }
+                        }
+                    }
+                }
+                if size > largest.0 {
+                    largest = (size, (x, y));
+                }
+            }
+        }
+        largest
+    }
+    
+    /// Full ecosystem statistics for dashboards and reports, see `EcosystemStats::to_json`
+    pub fn ecosystem_report(&self) -> EcosystemStats {
+        self.calculate_ecosystem_stats()
+    }
+    
+    /// Count of every tile kind by name, including kinds with no tiles, in a stable order
+    pub fn tile_census(&self) -> BTreeMap<&'static str, usize> {
+        let mut census: BTreeMap<&'static str, usize> = TileType::NAMES.iter().map(|name| (*name, 0)).collect();
+        for tile in self.tiles.iter().flatten() {
+            *census.entry(tile.name()).or_default() += 1;
+        }
+        census
+    }
 }
 
 impl fmt::Display for World {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         for y in 0..self.height {
             for x in 0..self.width {
-                write!(f, "{}", self.tiles[y][x].to_char())?;
+                write!(f, "{}", self.tile_char(x, y))?;
             }
             writeln!(f)?;
         }
@@ -2456,16 +5118,26 @@ impl fmt::Display for World {
         writeln!(f, "Day/Night: {}", if self.is_day() { "Day" } else { "Night" })?;
         writeln!(f, "Season: {} | Temperature: {:.1} | Humidity: {:.1}", 
                  self.get_season_name(), self.temperature, self.humidity)?;
-        writeln!(f, "Rain intensity: {:.2} | Wind: {:.1} @ {:.0}°", 
-                 self.rain_intensity, self.wind_strength, 
-                 self.wind_direction * 180.0 / std::f32::consts::PI)?;
+        writeln!(f, "Rain intensity: {:.2} | Wind: {:.1} toward {}", 
+                 self.rain_intensity, self.wind_strength, self.wind_compass())?;
+        if let Some((extra, ticks)) = self.gust {
+            writeln!(f, "Gust: +{:.1} for {} more ticks", extra, ticks)?;
+        }
         
         // Add ecosystem statistics
         let stats = self.calculate_ecosystem_stats();
-        writeln!(f, "Ecosystem: Plants:{} Pillbugs:{} Water:{} Nutrients:{}", 
-                 stats.total_plants, stats.total_pillbugs, stats.water_coverage, stats.nutrient_count)?;
+        writeln!(f, "Ecosystem: Plants:{} Pillbugs:{} Beetles:{} Water:{} Nutrients:{}", 
+                 stats.total_plants, stats.total_pillbugs, stats.total_beetles, stats.water_coverage, stats.nutrient_count)?;
         writeln!(f, "Health:{:.1}% Biomes:{} ({}x{} world)", 
                  stats.plant_health_ratio * 100.0, stats.biome_diversity, self.width, self.height)?;
+        // Plants/pillbugs per biome, to compare how each biome supports life
+        write!(f, "By biome (plants/pillbugs):")?;
+        for (biome, plants) in &stats.plants_by_biome {
+            write!(f, " {} {}/{}", biome.name(), plants, stats.pillbugs_by_biome[biome])?;
+        }
+        writeln!(f)?;
+        writeln!(f, "Dry spell: {} ticks | Drought stress: {:.0}%", 
+                 stats.ticks_since_rain, stats.drought_stress * 100.0)?;
         Ok(())
     }
 }
\ No newline at end of file
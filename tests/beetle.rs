@@ -0,0 +1,117 @@
+use pillbugplants::builder::WorldBuilder;
+use pillbugplants::types::{TileType, Size};
+use pillbugplants::world::{World, PillbugState};
+
+// A beetle and a pillbug on a rock floor, `gap` tiles apart
+fn hunting_ground(seed: u64, gap: usize) -> World {
+    let mut builder = WorldBuilder::new(24, 8).seed(seed);
+    for x in 0..24 {
+        builder = builder.place(x, 6, TileType::Rock).place(x, 7, TileType::Rock);
+    }
+    let prey_x = 5 + gap;
+    let mut world = builder
+        .place(3, 5, TileType::BeetleBody(20, Size::Large))
+        .place(4, 5, TileType::BeetleHead(20, Size::Large))
+        .place(prey_x, 5, TileType::PillbugHead(20, Size::Small))
+        .place(prey_x + 1, 5, TileType::PillbugBody(20, Size::Small))
+        .place(prey_x + 2, 5, TileType::PillbugLegs(20, Size::Small))
+        .build();
+    world.allow_respawn = false;
+    world.set_weather_controller(Box::new(|world: &mut World| world.rain_intensity = 0.0));
+    world
+}
+
+fn beetle_head_x(world: &World) -> Option<usize> {
+    (0..world.height)
+        .flat_map(|y| (0..world.width).map(move |x| (x, y)))
+        .find(|&(x, y)| matches!(world.tiles[y][x], TileType::BeetleHead(_, _)))
+        .map(|(x, _)| x)
+}
+
+#[test]
+fn beetles_catch_adjacent_pillbugs() {
+    let mut caught = 0;
+    for seed in 0..10 {
+        let mut world = hunting_ground(seed, 0);
+        for _ in 0..10 {
+            world.update();
+            if world.tiles.iter().flatten().any(|t| matches!(t, TileType::PillbugDecaying(_, _))) {
+                caught += 1;
+                break;
+            }
+        }
+    }
+    
+    assert!(caught >= 8, "beetles caught only {} of 10 pillbugs", caught);
+}
+
+#[test]
+fn beetles_chase_pillbugs_in_sight() {
+    let mut total_dx = 0;
+    for seed in 0..20 {
+        let mut world = hunting_ground(seed, 4);
+        for _ in 0..5 {
+            world.update();
+        }
+        total_dx += beetle_head_x(&world).unwrap_or(4) as i32 - 4;
+    }
+    
+    // Wandering at random averages out to roughly zero
+    assert!(total_dx >= 10, "beetles moved a net {} tiles toward their prey", total_dx);
+}
+
+#[test]
+fn rolled_pillbugs_shrug_off_beetles_then_uncurl() {
+    let mut world = hunting_ground(0, 0);
+    world.tiles[5][5] = TileType::PillbugRolled(20, Size::Small);
+    world.pillbug_states.insert((5, 5), PillbugState { rolled: 6, ..PillbugState::default() });
+    
+    for _ in 0..5 {
+        world.update();
+        assert!(!world.tiles.iter().flatten().any(|t| matches!(t, TileType::PillbugDecaying(_, _))), "a rolled pillbug was caught");
+        assert!(matches!(world.tiles[5][5], TileType::PillbugRolled(_, _)));
+    }
+    world.update();
+    
+    assert!(matches!(world.tiles[5][5], TileType::PillbugHead(_, _)), "pillbug should have uncurled, found {:?}", world.tiles[5][5]);
+}
+
+#[test]
+fn threatened_pillbugs_sometimes_roll_up() {
+    let mut rolled = 0;
+    for seed in 0..60 {
+        let mut world = hunting_ground(seed, 2);
+        for _ in 0..10 {
+            world.update();
+            if world.tiles.iter().flatten().any(|t| matches!(t, TileType::PillbugRolled(_, _))) {
+                rolled += 1;
+                break;
+            }
+        }
+    }
+    
+    assert!(rolled >= 9, "only {} of 60 pillbugs rolled up with a beetle nearby", rolled);
+}
+
+#[test]
+fn freshly_molted_pillbugs_are_easy_prey() {
+    let caught_at_once = |soft: u8| (0..20).filter(|&seed| {
+        let mut world = hunting_ground(seed, 0);
+        world.pillbug_states.insert((5, 5), PillbugState { soft, ..PillbugState::default() });
+        world.update();
+        world.tiles.iter().flatten().any(|t| matches!(t, TileType::PillbugDecaying(_, _)))
+    }).count();
+    let hard = caught_at_once(0);
+    assert!(hard < 18, "beetles caught {} of 20 hard-shelled pillbugs at once", hard);
+    assert_eq!(caught_at_once(10), 20);
+    
+    // Nor can a soft shell curl up against a beetle nearby
+    for seed in 0..60 {
+        let mut world = hunting_ground(seed, 2);
+        world.pillbug_states.insert((7, 5), PillbugState { soft: 200, ..PillbugState::default() });
+        for _ in 0..10 {
+            world.update();
+            assert!(!world.tiles.iter().flatten().any(|t| matches!(t, TileType::PillbugRolled(_, _))), "a soft pillbug rolled up");
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use pillbugplants::types::TileType;
+use pillbugplants::world::World;
+
+#[test]
+fn same_seed_gives_identical_runs() {
+    let mut first = World::new_seeded(60, 30, 1234);
+    let mut second = World::new_seeded(60, 30, 1234);
+    
+    for _ in 0..300 {
+        first.update();
+        second.update();
+    }
+    
+    assert_eq!(first.to_string(), second.to_string());
+}
+
+#[test]
+fn different_seeds_give_different_worlds() {
+    let first = World::new_seeded(60, 30, 1);
+    let second = World::new_seeded(60, 30, 2);
+    
+    assert_ne!(first.to_string(), second.to_string());
+}
+
+#[test]
+fn run_drives_the_update_loop() {
+    let mut world = World::new_seeded(60, 30, 5);
+    let mut twin = World::new_seeded(60, 30, 5);
+    let mut seen = Vec::new();
+    world.run(50, |step, world| seen.push((step, world.tick)));
+    for _ in 0..50 {
+        twin.update();
+    }
+    assert_eq!(seen, (1..=50).map(|step| (step, step)).collect::<Vec<_>>());
+    assert_eq!(world.to_string(), twin.to_string());
+    
+    // run_while stops as soon as the callback says so
+    let ran = world.run_while(50, |step, _| step < 7);
+    assert_eq!((ran, world.tick), (7, 57));
+    assert_eq!(world.run_while(3, |_, _| true), 3);
+}
+
+#[test]
+fn tick_diffs_replay_the_run() {
+    let mut world = World::new_seeded(60, 30, 99);
+    let mut twin = World::new_seeded(60, 30, 99);
+    let mut replayed = world.tiles.clone();
+    
+    for _ in 0..100 {
+        for change in world.tick_and_diff() {
+            assert_ne!(replayed[change.y][change.x], change.new_tile, "unchanged tile reported at ({}, {})", change.x, change.y);
+            replayed[change.y][change.x] = change.new_tile;
+        }
+        twin.update();
+        assert_eq!(replayed, world.tiles);
+    }
+    assert_eq!(world.tiles, twin.tiles);
+}
+
+#[test]
+fn seeded_populations_stay_in_range() {
+    let mut world = World::new_seeded(60, 30, 1234);
+    
+    for _ in 0..300 {
+        world.update();
+    }
+    
+    let (plants, pillbugs, water) = (world.plant_count(), world.pillbug_count(), world.water_tiles());
+    assert!((5..600).contains(&plants), "{} plant tiles", plants);
+    assert!((1..60).contains(&pillbugs), "{} pillbugs", pillbugs);
+    assert!(water < 60 * 30 / 2, "{} water tiles", water);
+    assert_eq!(world.tile_at(10, 20), world.tiles[20][10]);
+}
+
+#[test]
+fn stress_worlds_fill_to_their_density_and_step_alike() {
+    let crowded = |density: f32| {
+        let mut world = World::new_seeded(60, 30, 8);
+        world.populate_stress_test(density);
+        world
+    };
+    let occupancy = |world: &World| world.tiles.iter().flatten().filter(|&&t| t != TileType::Empty).count() as f32 / (60 * 30) as f32;
+    
+    let (mut first, mut second) = (crowded(0.7), crowded(0.7));
+    assert!((occupancy(&first) - 0.7).abs() < 0.01, "filled to {}", occupancy(&first));
+    assert!(first.tiles.iter().flatten().any(|t| t.is_pillbug()));
+    for _ in 0..20 {
+        first.step_once();
+        second.step_once();
+    }
+    assert_eq!(first.to_string(), second.to_string());
+    
+    // A world already fuller than asked is left as it is
+    let generated = World::new_seeded(60, 30, 8);
+    assert_eq!(crowded(0.0).to_string(), generated.to_string());
+}
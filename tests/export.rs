@@ -0,0 +1,144 @@
+use std::time::Duration;
+use pillbugplants::builder::WorldBuilder;
+use pillbugplants::export::{ThumbnailLayout, thumbnail_sheet, log_csv_header};
+use pillbugplants::types::{CLEAN_WATER, TileType, Size, Biome};
+use pillbugplants::world::PerformanceMetrics;
+
+#[test]
+fn tilemap_json_has_dimensions_legend_and_layers() {
+    let world = WorldBuilder::new(4, 3)
+        .fill_soil(1)
+        .place(1, 1, TileType::Water(90, CLEAN_WATER))
+        .place(2, 1, TileType::Seed(5, Size::Large))
+        .build();
+    let json = world.to_tilemap_json();
+    
+    assert!(json.contains("\"width\": 4,"));
+    assert!(json.contains("\"height\": 3,"));
+    assert!(json.contains("{ \"id\": 4, \"name\": \"water\" }"));
+    assert!(json.contains("{ \"name\": \"tiles\", \"width\": 4, \"height\": 3, \"data\": [0,0,0,0,0,4,18,0,1,1,1,1] }"));
+    assert!(json.contains("{ \"name\": \"values\", \"width\": 4, \"height\": 3, \"data\": [0,0,0,0,0,90,5,0,0,0,0,0] }"));
+    assert!(json.contains("{ \"name\": \"sizes\", \"width\": 4, \"height\": 3, \"data\": [0,0,0,0,0,0,3,0,0,0,0,0] }"));
+}
+
+#[test]
+fn tile_ids_match_legend_names() {
+    assert_eq!(TileType::NAMES[TileType::Dirt.tile_id() as usize], "dirt");
+    assert_eq!(TileType::NAMES[TileType::Spore(0).tile_id() as usize], "spore");
+    assert_eq!(TileType::NAMES[TileType::PillbugHead(0, Size::Small).tile_id() as usize], "pillbug_head");
+}
+
+#[test]
+fn ppm_has_header_and_scaled_pixels() {
+    let world = WorldBuilder::new(5, 4).fill_soil(1).build();
+    let ppm = world.to_ppm(3);
+    
+    let header = b"P6\n15 12\n255\n";
+    assert!(ppm.starts_with(header));
+    assert_eq!(ppm.len(), header.len() + 15 * 12 * 3);
+    
+    let image = world.to_image(3);
+    assert_eq!(image.pixel(0, 0), [0, 0, 0]);
+    assert_eq!(image.pixel(14, 11), [101, 67, 33]);
+}
+
+#[test]
+fn png_round_trips_the_tile_colors() {
+    let world = WorldBuilder::new(5, 4).fill_soil(1).build();
+    let png = world.to_image(2).to_png().unwrap();
+    
+    let decoded = image::load_from_memory(&png).unwrap().to_rgb8();
+    assert_eq!(decoded.dimensions(), (10, 8));
+    assert_eq!(decoded.get_pixel(0, 0).0, [0, 0, 0]);
+    assert_eq!(decoded.get_pixel(9, 7).0, [101, 67, 33]);
+}
+
+#[test]
+fn thumbnail_sheet_layouts() {
+    let world = WorldBuilder::new(4, 2).fill_soil(1).build();
+    let frames = vec![world.to_image(1); 5];
+    
+    let strip = thumbnail_sheet(&frames, ThumbnailLayout::Strip);
+    assert_eq!((strip.width, strip.height), (5 * 4 + 4 * 2, 2));
+    
+    let grid = thumbnail_sheet(&frames, ThumbnailLayout::Grid);
+    assert_eq!((grid.width, grid.height), (3 * 4 + 2 * 2, 2 * 2 + 2));
+}
+
+#[test]
+fn tile_census_counts_every_kind() {
+    let world = WorldBuilder::new(4, 3)
+        .fill_soil(1)
+        .place(1, 1, TileType::Water(90, CLEAN_WATER))
+        .place(2, 1, TileType::Seed(5, Size::Large))
+        .build();
+    let census = world.tile_census();
+    
+    assert_eq!(census.len(), TileType::NAMES.len());
+    assert_eq!(census.values().sum::<usize>(), 12);
+    assert_eq!(census["dirt"], 4);
+    assert_eq!(census["water"], 1);
+    assert_eq!(census["seed"], 1);
+    assert_eq!(census["rock"], 0);
+}
+
+#[test]
+fn log_csv_row_matches_header() {
+    let world = WorldBuilder::new(4, 3).fill_soil(1).build();
+    let header = log_csv_header();
+    let row = world.log_csv_row();
+    
+    assert!(header.starts_with("tick,season,temperature,humidity,rain_intensity,total_plants,"));
+    assert!(header.contains(",drought_stress,algae,beetle_body,beetle_head,clay,depleted_dirt,dirt,empty,"));
+    assert!(header.contains(",plants_by_biome_wetland,plants_by_biome_grassland,plants_by_biome_drylands,plants_by_biome_woodland,pillbugs_by_biome_wetland,"));
+    assert!(header.ends_with(",beetles_by_size_small,beetles_by_size_medium,beetles_by_size_large"));
+    assert_eq!(header.split(',').count(), row.split(',').count());
+    assert!(row.starts_with("0,Spring,0.300,0.500,0.000,0,0,0,0,0,"));
+}
+
+#[test]
+fn stats_break_counts_down_by_biome_and_size() {
+    let world = WorldBuilder::new(6, 4)
+        .biome(Biome::Drylands)
+        .place(1, 1, TileType::PlantStem(0, Size::Small))
+        .place(2, 1, TileType::PlantLeaf(0, Size::Small))
+        .place(3, 1, TileType::PillbugHead(0, Size::Large))
+        .place(4, 1, TileType::BeetleHead(0, Size::Medium))
+        .build();
+    let stats = world.ecosystem_report();
+    assert_eq!(stats.plants_by_biome[&Biome::Drylands], 2);
+    assert_eq!(stats.pillbugs_by_size[&Size::Large], 1);
+    let json = stats.to_json();
+    
+    assert!(json.starts_with("{\n  \"tick\": 0,\n  \"total_plants\": 2,"));
+    assert!(json.contains("\"plants_by_biome\": {\"wetland\": 0, \"grassland\": 0, \"drylands\": 2, \"woodland\": 0}"));
+    assert!(json.contains("\"pillbugs_by_biome\": {\"wetland\": 0, \"grassland\": 0, \"drylands\": 1, \"woodland\": 0}"));
+    assert!(json.contains("\"plants_by_size\": {\"small\": 2, \"medium\": 0, \"large\": 0}"));
+    assert!(json.contains("\"pillbugs_by_size\": {\"small\": 0, \"medium\": 0, \"large\": 1}"));
+    assert!(json.contains("\"beetles_by_size\": {\"small\": 0, \"medium\": 1, \"large\": 0}"));
+    assert!(json.trim_end().ends_with('}'));
+    assert!(world.to_string().contains("By biome (plants/pillbugs): wetland 0/0 grassland 0/0 drylands 2/1 woodland 0/0\n"));
+}
+
+#[test]
+fn performance_json_averages_timings_and_names_the_bottleneck() {
+    let mut world = WorldBuilder::new(20, 10).fill_soil(3).build();
+    let mut total = PerformanceMetrics::default();
+    for _ in 0..4 {
+        world.update();
+        total.accumulate(&world.performance_snapshot());
+    }
+    assert_eq!(world.performance_snapshot().total_update_time, world.performance.total_update_time);
+    
+    total.fire_time = Duration::from_millis(40);
+    total.life_update_time = Duration::from_millis(8);
+    let average = total.averaged(4);
+    assert_eq!(average.fire_time, Duration::from_millis(10));
+    assert_eq!(average.bottleneck(), "fire");
+    
+    let json = average.to_json();
+    assert!(json.starts_with("{\n  \"total_update_ms\": "));
+    assert!(json.contains("\n  \"fire_ms\": 10,\n"));
+    assert!(json.contains("\n  \"life_update_ms\": 2,\n"));
+    assert!(json.trim_end().ends_with("\"bottleneck\": \"fire\"\n}"));
+}
@@ -0,0 +1,64 @@
+use pillbugplants::builder::WorldBuilder;
+use pillbugplants::types::{CLEAN_WATER, TileType, Size};
+use pillbugplants::world::World;
+
+// A 3-row block of plant stems on bare rock, with weather pinned to the given temperature and humidity
+fn fuel_block(temperature: f32, humidity: f32) -> World {
+    let (width, height) = (40, 12);
+    let mut builder = WorldBuilder::new(width, height).seed(3);
+    for x in 0..width {
+        builder = builder.place(x, 10, TileType::Rock).place(x, 11, TileType::Rock);
+    }
+    for x in 2..width - 2 {
+        for y in 7..10 {
+            builder = builder.place(x, y, TileType::PlantStem(10, Size::Medium));
+        }
+    }
+    
+    let mut world = builder.build();
+    world.set_weather_controller(Box::new(move |world: &mut World| {
+        world.temperature = temperature;
+        world.humidity = humidity;
+        world.rain_intensity = 0.0;
+        world.wind_strength = 0.0;
+    }));
+    world
+}
+
+fn stems_left(world: &World) -> usize {
+    world.tiles.iter().flatten().filter(|t| matches!(t, TileType::PlantStem(_, _))).count()
+}
+
+fn burn(mut world: World) -> usize {
+    let before = stems_left(&world);
+    assert!(world.ignite(2, 8));
+    for _ in 0..150 {
+        world.update();
+    }
+    assert!(!world.tiles.iter().flatten().any(|t| matches!(t, TileType::Fire(_))), "fire should have burnt out");
+    before.saturating_sub(stems_left(&world))
+}
+
+#[test]
+fn fire_spreads_further_in_hot_dry_weather() {
+    let dry_burned = burn(fuel_block(0.8, 0.1));
+    let wet_burned = burn(fuel_block(-0.5, 1.0));
+    
+    assert!(dry_burned > 80, "dry weather burned only {} stems", dry_burned);
+    assert!(wet_burned < dry_burned / 2, "wet weather burned {} stems vs {} dry", wet_burned, dry_burned);
+}
+
+#[test]
+fn water_puts_out_fire() {
+    let mut world = fuel_block(0.8, 0.1);
+    world.tiles[6][10] = TileType::Water(100, CLEAN_WATER);
+    assert!(world.ignite(10, 7));
+    assert!(!world.ignite(0, 10), "rock shouldn't burn");
+    assert!(!world.ignite(0, 0), "empty air shouldn't burn");
+    assert_eq!(world.tiles[0][0], TileType::Empty);
+    assert!(!world.ignite(40, 7), "outside the world");
+    
+    world.update();
+    
+    assert_eq!(world.tiles[7][10], TileType::Empty);
+}
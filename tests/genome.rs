@@ -0,0 +1,142 @@
+use std::collections::HashSet;
+use pillbugplants::builder::WorldBuilder;
+use pillbugplants::config::DiseaseConfig;
+use pillbugplants::types::{TileType, Size, Biome, PlantGenome, WindMode};
+use pillbugplants::world::World;
+
+const PARENT: PlantGenome = PlantGenome { growth_speed: 1.2, drought_tolerance: 0.5, disease_resistance: 0.3 };
+
+// Bare rock floor two tiles deep
+fn rock_world(width: usize, height: usize) -> WorldBuilder {
+    let mut builder = WorldBuilder::new(width, height)
+        .seed(5)
+        .wind(WindMode::Deterministic { direction: 0.0, strength: 0.0 });
+    for x in 0..width {
+        builder = builder.place(x, height - 2, TileType::Rock).place(x, height - 1, TileType::Rock);
+    }
+    builder
+}
+
+fn dry_weather(world: &mut World) {
+    world.set_weather_controller(Box::new(|world: &mut World| world.rain_intensity = 0.0));
+}
+
+#[test]
+fn seeds_inherit_a_mutated_parent_genome() {
+    // A pair of flowers on stems, so each has the other's pollen
+    let mut world = rock_world(30, 12)
+        .place(15, 9, TileType::PlantStem(10, Size::Medium))
+        .place(17, 9, TileType::PlantStem(10, Size::Medium))
+        .place(15, 8, TileType::PlantFlower(10, Size::Medium))
+        .place(17, 8, TileType::PlantFlower(10, Size::Medium))
+        .build();
+    world.genomes[8][15] = Some(PARENT);
+    world.genomes[8][17] = Some(PARENT);
+    world.allow_respawn = false;
+    dry_weather(&mut world);
+    
+    let mut inherited = Vec::new();
+    for _ in 0..200 {
+        world.update();
+        for y in 0..world.height {
+            for x in 0..world.width {
+                if matches!(world.tiles[y][x], TileType::Seed(_, _)) {
+                    inherited.extend(world.genomes[y][x]);
+                }
+            }
+        }
+        if !inherited.is_empty() {
+            break;
+        }
+    }
+    
+    let child = inherited.first().expect("the flower should have dropped a seed");
+    assert_ne!(*child, PARENT);
+    assert!((child.growth_speed - PARENT.growth_speed).abs() <= 0.05);
+    assert!((child.drought_tolerance - PARENT.drought_tolerance).abs() <= 0.05);
+    assert!((child.disease_resistance - PARENT.disease_resistance).abs() <= 0.05);
+}
+
+#[test]
+fn drought_tolerance_pays_off_only_in_dry_ground() {
+    let mut world = WorldBuilder::new(10, 5).fill_soil(2).build();
+    world.humidity = 0.6;
+    let tolerant = PlantGenome { drought_tolerance: 1.0, ..PlantGenome::default() };
+    let growth = |genome: &PlantGenome, biome| world.get_seasonal_growth_modifier(genome, biome);
+    
+    assert!(growth(&tolerant, Biome::Drylands) > growth(&PlantGenome::default(), Biome::Drylands));
+    assert!(growth(&tolerant, Biome::Wetland) < growth(&PlantGenome::default(), Biome::Wetland));
+}
+
+fn sick_hedge(resistance: f32) -> World {
+    let mut builder = rock_world(30, 12);
+    // A hedge of leaves on stems, a few of them already sick
+    for x in 6..24 {
+        builder = builder.place(x, 9, TileType::PlantStem(10, Size::Medium)).place(x, 8, TileType::PlantLeaf(40, Size::Medium));
+    }
+    for x in [9, 15, 21] {
+        builder = builder.place(x, 8, TileType::PlantDiseased(20, Size::Medium));
+    }
+    let mut world = builder.build();
+    world.allow_respawn = false;
+    for x in 6..24 {
+        for y in 8..10 {
+            world.genomes[y][x] = Some(PlantGenome { disease_resistance: resistance, ..PlantGenome::default() });
+        }
+    }
+    dry_weather(&mut world);
+    world
+}
+
+fn infected_leaves(resistance: f32) -> usize {
+    let mut world = sick_hedge(resistance);
+    
+    // Count every position that falls sick, since diseased leaves eventually wither and rot away
+    let mut infected = HashSet::new();
+    for _ in 0..120 {
+        world.update();
+        for y in 0..world.height {
+            for x in 0..world.width {
+                if matches!(world.tiles[y][x], TileType::PlantDiseased(_, _)) {
+                    infected.insert((x, y));
+                }
+            }
+        }
+    }
+    infected.len()
+}
+
+#[test]
+fn resistant_plants_dont_catch_disease() {
+    let defenceless = infected_leaves(0.0);
+    assert!(defenceless > 4, "disease reached {} defenceless leaves", defenceless);
+    assert_eq!(infected_leaves(1.0), 3, "only the originally sick leaves should be sick");
+}
+
+#[test]
+fn disease_can_be_tuned_from_harmless_to_deadly() {
+    let sick = |world: &World| world.tiles[8].iter().filter(|t| matches!(t, TileType::PlantDiseased(_, _))).count();
+    let contained = DiseaseConfig { outbreak_chance: 0.0, spread_chance: 0.0, spore_chance: 0.0, recovery_chance: 0.0, ..DiseaseConfig::default() };
+    
+    // Surrounded by healthy leaves, sick ones soon pull through
+    let mut world = sick_hedge(0.0);
+    world.disease_config = DiseaseConfig { recovery_chance: 1.0, ..contained };
+    for _ in 0..20 {
+        world.update();
+    }
+    assert_eq!(sick(&world), 0);
+    assert!([9, 15, 21].iter().all(|&x| matches!(world.tiles[8][x], TileType::PlantLeaf(_, _))));
+    
+    // Without recovery a fast-acting disease kills them where they stand
+    let mut world = sick_hedge(0.0);
+    world.disease_config = DiseaseConfig { lethal_age: 25, ..contained };
+    for _ in 0..5 {
+        world.update();
+    }
+    assert_eq!(sick(&world), 3);
+    for _ in 0..5 {
+        world.update();
+    }
+    assert_eq!(sick(&world), 0);
+    assert!([9, 15, 21].iter().all(|&x| !matches!(world.tiles[8][x], TileType::PlantLeaf(_, _))));
+}
@@ -0,0 +1,544 @@
+use pillbugplants::builder::WorldBuilder;
+use pillbugplants::config::PhysicsConfig;
+use pillbugplants::types::{CLEAN_WATER, Biome, PlantSpecies, TileType, Size};
+use pillbugplants::world::World;
+
+// Highest local plant density seen away from the world edges over the second half of a run,
+// averaged over a few seeds so one lucky or unlucky world doesn't decide the outcome. The worlds
+// are wide enough for flowers to grow near one another and pollinate
+fn peak_density(density_cap: f32) -> f32 {
+    let seeds = 1..=4;
+    let mut total = 0.0;
+    for seed in seeds.clone() {
+        let mut world = WorldBuilder::new(60, 16).seed(seed).biome(Biome::Woodland).build();
+        world.growth_config.density_cap = density_cap;
+        
+        let mut peak: f32 = 0.0;
+        for tick in 0..300 {
+            world.update();
+            if tick >= 150 && tick % 10 == 0 {
+                for y in 3..13 {
+                    for x in 3..57 {
+                        peak = peak.max(world.plant_density_around(x, y));
+                    }
+                }
+            }
+        }
+        total += peak;
+    }
+    total / seeds.count() as f32
+}
+
+#[test]
+fn density_cap_keeps_vegetation_patchy() {
+    let capped = peak_density(0.3);
+    let uncapped = peak_density(1.0);
+    
+    // Growth in the same tick and neighbouring windows can overshoot the cap a little
+    assert!(capped < 0.45, "capped density reached {}", capped);
+    assert!(uncapped > capped + 0.1, "uncapped density {} vs capped {}", uncapped, capped);
+}
+
+#[test]
+fn dying_plants_disturb_ground_that_then_recovers() {
+    let mut world = WorldBuilder::new(20, 10)
+        .seed(1)
+        .fill_soil(3)
+        .place(10, 6, TileType::PlantLeaf(10, Size::Small))
+        .build();
+    // Severe drought in dry air from the first tick, so the sunlit leaf wilts and withers
+    world.drought_config.stress_after = 0;
+    world.drought_config.full_stress_after = 1;
+    world.allow_respawn = false;
+    world.set_weather_controller(Box::new(|world: &mut World| {
+        world.rain_intensity = 0.0;
+        world.humidity = 0.2;
+    }));
+    
+    let mut ticks = 0;
+    while matches!(world.tiles[6][10], TileType::PlantLeaf(_, _) | TileType::PlantWilting(_, _)) && ticks < 300 {
+        world.update();
+        ticks += 1;
+    }
+    assert!(matches!(world.tiles[6][10], TileType::PlantWithered(_, _)));
+    assert!(world.disturbance_at(10, 6) > 0.4);
+    assert_eq!(world.disturbance_at(12, 6), 0.0);
+    
+    for _ in 0..300 {
+        world.update();
+    }
+    assert!(world.disturbance_at(10, 6) < 0.15);
+}
+
+#[test]
+fn wilting_leaves_revive_when_water_returns() {
+    let mut world = WorldBuilder::new(20, 10)
+        .seed(1)
+        .fill_soil(3)
+        .place(10, 6, TileType::PlantWilting(0, Size::Small))
+        .build();
+    world.allow_respawn = false;
+    world.physics_config = PhysicsConfig { evaporation_rate: 0.0, absorption_rate: 0.0, ..PhysicsConfig::default() };
+    world.drought_config.stress_after = 0;
+    world.drought_config.full_stress_after = 1;
+    world.set_weather_controller(Box::new(|world: &mut World| {
+        world.rain_intensity = 0.0;
+        world.humidity = 0.2;
+    }));
+    for _ in 0..10 {
+        world.update();
+    }
+    assert!(world.is_parched(10, 6));
+    assert!(matches!(world.tiles[6][10], TileType::PlantWilting(10, Size::Small)), "{:?}", world.tiles[6][10]);
+    
+    // A puddle beside it perks it back up
+    world.tiles[6][12] = TileType::Water(200, CLEAN_WATER);
+    world.update();
+    assert!(matches!(world.tiles[6][10], TileType::PlantLeaf(_, Size::Small)), "{:?}", world.tiles[6][10]);
+}
+
+// Stems sprouted from a row of seeds of one size, on ground with the given disturbance
+fn sprouts(size: Size, disturbance: f32) -> usize {
+    let mut builder = WorldBuilder::new(30, 10)
+        .seed(4)
+        .fill_soil(3);
+    for x in 1..29 {
+        builder = builder.place(x, 6, TileType::Seed(0, size));
+    }
+    let mut world = builder.build();
+    world.allow_respawn = false;
+    for row in world.disturbance.iter_mut() {
+        row.fill(disturbance);
+    }
+    
+    for _ in 0..40 {
+        world.update();
+    }
+    world.tiles[6].iter().filter(|t| matches!(t, TileType::PlantStem(_, _))).count()
+}
+
+#[test]
+fn pioneers_colonize_disturbed_ground_and_climax_plants_wait() {
+    assert!(sprouts(Size::Small, 1.0) > sprouts(Size::Small, 0.0));
+    assert!(sprouts(Size::Large, 0.0) > 0);
+    assert_eq!(sprouts(Size::Large, 1.0), 0);
+}
+
+// Grass stems sprouted from a row of seeds with plants of another species growing among them,
+// whose effect on grass seedlings is set to `effect`
+fn sprouts_beside(neighbour: PlantSpecies, effect: f32) -> usize {
+    let mut builder = WorldBuilder::new(30, 10)
+        .seed(4)
+        .biome(Biome::Grassland)
+        .fill_soil(3);
+    for x in 1..29 {
+        builder = builder.place(x, 6, TileType::Seed(0, Size::Medium));
+    }
+    // Buried stems of the neighbouring species
+    let stems: Vec<usize> = (2..29).step_by(4).collect();
+    for &x in &stems {
+        builder = builder.place(x, 8, TileType::PlantStem(10, Size::Medium));
+    }
+    let mut world = builder.build();
+    world.allow_respawn = false;
+    for &x in &stems {
+        world.species[8][x] = Some(neighbour);
+    }
+    world.interaction_config.germination[neighbour as usize][PlantSpecies::Grass as usize] = effect;
+    
+    for _ in 0..40 {
+        world.update();
+    }
+    world.tiles[6].iter().filter(|t| matches!(t, TileType::PlantStem(_, _))).count()
+}
+
+#[test]
+fn seeds_lie_dormant_through_the_cold() {
+    let mut builder = WorldBuilder::new(30, 10)
+        .seed(4)
+        .fill_soil(3);
+    let sizes = [(1..10, Size::Small), (10..19, Size::Medium), (19..29, Size::Large)];
+    for (columns, size) in sizes {
+        for x in columns {
+            builder = builder.place(x, 6, TileType::Seed(50, size));
+        }
+    }
+    let mut world = builder.build();
+    world.allow_respawn = false;
+    // A still winter for 200 ticks, then a mild spring
+    world.set_weather_controller(Box::new(|world: &mut World| {
+        world.temperature = if world.tick <= 200 { -0.5 } else { 0.5 };
+        world.humidity = 0.7;
+        world.rain_intensity = 0.0;
+        world.wind_strength = 0.0;
+    }));
+    
+    for _ in 0..200 {
+        world.update();
+    }
+    let seeds = |world: &World, size: Size| world.tiles[6].iter().filter(|t| matches!(t, TileType::Seed(_, s) if *s == size)).count();
+    let stems = |world: &World| world.tiles[6].iter().filter(|t| matches!(t, TileType::PlantStem(_, _))).count();
+    
+    // Nothing sprouts in the cold; large seeds don't age at all, small ones age out over the winter
+    assert_eq!(stems(&world), 0);
+    assert!(world.tiles[6][19..29].iter().all(|&t| t == TileType::Seed(50, Size::Large)));
+    assert_eq!(seeds(&world, Size::Medium), 9);
+    assert_eq!(seeds(&world, Size::Small), 0);
+    
+    // Come spring the banked seeds sprout
+    for _ in 0..100 {
+        world.update();
+    }
+    assert!(stems(&world) > 0);
+}
+
+// Seedlings sprouted in a row, left to establish at a steady temperature and humidity; returns how
+// many of the eight grew into stems within the establishment window
+fn established(temperature: f32, humidity: f32) -> usize {
+    let mut builder = WorldBuilder::new(20, 10)
+        .seed(2)
+        .fill_soil(3);
+    for x in 8..16 {
+        builder = builder.place(x, 6, TileType::Seedling(0, Size::Medium));
+    }
+    let mut world = builder.build();
+    world.allow_respawn = false;
+    world.set_weather_controller(Box::new(move |world: &mut World| {
+        world.temperature = temperature;
+        world.humidity = humidity;
+        world.rain_intensity = 0.0;
+        world.wind_strength = 0.0;
+    }));
+    
+    for _ in 0..29 {
+        world.update();
+    }
+    assert!(!world.tiles[6][8..16].iter().any(|t| matches!(t, TileType::PlantStem(_, _))), "seedlings rooted early");
+    world.update();
+    world.tiles[6][8..16].iter().filter(|t| matches!(t, TileType::PlantStem(_, _))).count()
+}
+
+#[test]
+fn seedlings_establish_unless_frost_or_thirst_kills_them() {
+    assert_eq!(established(0.5, 0.7), 8);
+    assert!(established(-0.5, 0.7) <= 4);
+    assert!(established(0.5, 0.2) <= 4);
+}
+
+#[test]
+fn neighbours_nurse_or_suppress_seedlings() {
+    assert_eq!(sprouts_beside(PlantSpecies::Tree, 0.0), 0);
+    assert!(sprouts_beside(PlantSpecies::Tree, 1.0) > 0);
+    assert!(sprouts_beside(PlantSpecies::Shrub, 4.0) > sprouts_beside(PlantSpecies::Shrub, 1.0));
+}
+
+// Cells of nutrient-rich dirt after a lone root of a species fixing nitrogen at `chance` has
+// had a while in plain dirt
+fn enriched_by_root(chance: f32) -> usize {
+    let mut world = WorldBuilder::new(20, 10)
+        .seed(1)
+        .fill_soil(3)
+        .place(12, 8, TileType::PlantRoot(0, Size::Medium))
+        .build();
+    world.allow_respawn = false;
+    world.species[8][12] = Some(PlantSpecies::Shrub);
+    world.interaction_config.nitrogen_fixing = [0.0, chance, 0.0, 0.0];
+    for _ in 0..20 {
+        world.update();
+    }
+    world.tiles.iter().flatten().filter(|tile| matches!(tile, TileType::NutrientDirt(_))).count()
+}
+
+#[test]
+fn nitrogen_fixers_enrich_the_soil_around_them() {
+    assert_eq!(enriched_by_root(0.0), 0);
+    assert!(enriched_by_root(1.0) > 0);
+}
+
+#[test]
+fn roots_exhaust_soil_that_nutrients_restore() {
+    let (width, height) = (20, 8);
+    let mut builder = WorldBuilder::new(width, height).seed(6).fill_soil(4);
+    for x in 2..width - 2 {
+        builder = builder.place(x, 5, TileType::PlantRoot(10, Size::Large));
+    }
+    let mut world = builder.build();
+    world.allow_respawn = false;
+    world.set_weather_controller(Box::new(|world: &mut World| {
+        world.rain_intensity = 0.0;
+        world.wind_strength = 0.0;
+    }));
+    for _ in 0..100 {
+        world.update();
+    }
+    
+    let depleted = world.tiles.iter().flatten().filter(|t| **t == TileType::DepletedDirt).count();
+    assert!(depleted > 5, "roots exhausted only {} tiles", depleted);
+    let (x, y) = (0..width).flat_map(|x| (0..height).map(move |y| (x, y)))
+        .find(|&(x, y)| world.tiles[y][x] == TileType::DepletedDirt).unwrap();
+    world.tiles[y - 1][x] = TileType::PlantStem(10, Size::Medium);
+    assert!(world.soil_fertility(x, y - 1) < 0.5);
+    
+    // A nutrient walled in by exhausted soil soaks into it and restores it
+    let pocket = [(4, 1), (3, 2), (5, 2), (4, 3)];
+    let mut builder = WorldBuilder::new(9, 6).seed(2).fill_soil(2);
+    for (x, y) in pocket {
+        builder = builder.place(x, y, TileType::DepletedDirt);
+    }
+    let mut world = builder.place(4, 2, TileType::Nutrient).build();
+    world.allow_respawn = false;
+    world.set_weather_controller(Box::new(|world: &mut World| {
+        world.rain_intensity = 0.0;
+        world.wind_strength = 0.0;
+    }));
+    for _ in 0..200 {
+        world.update();
+    }
+    assert_ne!(world.tiles[2][4], TileType::Nutrient);
+    assert!(pocket.iter().any(|&(x, y)| world.tiles[y][x] == TileType::Dirt));
+}
+
+#[test]
+fn seeds_sprout_their_biomes_native_species() {
+    let mut builder = WorldBuilder::new(30, 10)
+        .seed(4)
+        .biome(Biome::Drylands)
+        .fill_soil(3);
+    for x in 1..29 {
+        builder = builder.place(x, 6, TileType::Seed(0, Size::Medium));
+    }
+    let mut world = builder.build();
+    world.allow_respawn = false;
+    for _ in 0..40 {
+        world.update();
+    }
+    
+    let sprouted: Vec<usize> = (0..30).filter(|&x| matches!(world.tiles[6][x], TileType::PlantStem(_, _))).collect();
+    assert!(!sprouted.is_empty());
+    for x in sprouted {
+        assert_eq!(world.species[6][x], Some(PlantSpecies::Cactus));
+        assert_eq!(world.tile_char(x, 6), '‡');
+    }
+}
+
+#[test]
+fn seeds_sprout_on_any_loose_earth_but_not_ice() {
+    for tile in [TileType::Dirt, TileType::NutrientDirt(120), TileType::DepletedDirt, TileType::Sand, TileType::Clay] {
+        assert!(tile.is_earth() && tile.can_support_plants(), "{:?}", tile);
+    }
+    assert!(TileType::Ice(100).can_support_plants() && !TileType::Ice(100).is_earth());
+    assert!(!TileType::Rock.can_support_plants() && !TileType::Water(100, CLEAN_WATER).can_support_plants());
+    
+    let mut builder = WorldBuilder::new(30, 10)
+        .seed(4)
+        .fill_soil(3);
+    for x in 1..29 {
+        builder = builder.place(x, 7, TileType::NutrientDirt(120)).place(x, 6, TileType::Seed(0, Size::Medium));
+    }
+    let mut world = builder.build();
+    world.allow_respawn = false;
+    for _ in 0..40 {
+        world.update();
+    }
+    assert!(world.tiles[6].iter().any(|t| matches!(t, TileType::PlantStem(_, _))), "no seed sprouted on nutrient-rich dirt");
+}
+
+#[test]
+fn nutrient_rich_dirt_holds_plants_up_like_dirt() {
+    let mut builder = WorldBuilder::new(12, 8)
+        .seed(2)
+        .fill_soil(2);
+    for x in 0..12 {
+        builder = builder.place(x, 6, TileType::NutrientDirt(150));
+    }
+    let mut world = builder
+        .place(4, 5, TileType::PlantStem(10, Size::Medium))
+        .place(8, 5, TileType::PlantLeaf(10, Size::Medium))
+        .build();
+    world.allow_respawn = false;
+    world.humidity = 0.8;
+    for _ in 0..5 {
+        world.update();
+    }
+    assert!(matches!(world.tiles[5][4], TileType::PlantStem(_, _)), "stem became {:?}", world.tiles[5][4]);
+    assert!(matches!(world.tiles[5][8], TileType::PlantLeaf(_, _)), "leaf became {:?}", world.tiles[5][8]);
+}
+
+// Leaves, branches, and the tallest stem grown from a row of young stems of one species
+fn grown(species: PlantSpecies) -> (usize, usize, usize) {
+    let (width, height) = (40, 20);
+    let mut builder = WorldBuilder::new(width, height).seed(9).fill_soil(4);
+    for x in (4..width - 4).step_by(4) {
+        builder = builder.place(x, 15, TileType::PlantStem(0, Size::Medium));
+    }
+    let mut world = builder.build();
+    for x in (4..width - 4).step_by(4) {
+        world.species[15][x] = Some(species);
+    }
+    world.allow_respawn = false;
+    world.set_weather_controller(Box::new(|world: &mut World| {
+        world.wind_strength = 0.0;
+    }));
+    for _ in 0..300 {
+        world.update();
+    }
+    
+    let count = |kind: fn(&TileType) -> bool| world.tiles.iter().flatten().filter(|t| kind(t)).count();
+    let leaves = count(|t| matches!(t, TileType::PlantLeaf(_, _)));
+    let branches = count(|t| matches!(t, TileType::PlantBranch(_, _)));
+    let tallest = (0..height).find(|&y| world.tiles[y].iter().any(|t| matches!(t, TileType::PlantStem(_, _)))).map_or(0, |y| 16 - y);
+    (leaves, branches, tallest)
+}
+
+#[test]
+fn species_shape_how_plants_grow() {
+    let (cactus_leaves, cactus_branches, cactus_height) = grown(PlantSpecies::Cactus);
+    let (tree_leaves, tree_branches, _) = grown(PlantSpecies::Tree);
+    let (_, _, shrub_height) = grown(PlantSpecies::Shrub);
+    
+    assert!(cactus_leaves < tree_leaves, "cacti grew {} leaves vs {} on trees", cactus_leaves, tree_leaves);
+    assert!(cactus_branches < tree_branches, "cacti grew {} branches vs {} on trees", cactus_branches, tree_branches);
+    assert!(cactus_height > shrub_height, "cacti reached {} vs {} for shrubs", cactus_height, shrub_height);
+}
+
+// Whether flowers at these columns on an otherwise bare, calm world launch any seeds
+fn flowers_seed(columns: &[usize]) -> bool {
+    let mut builder = WorldBuilder::new(30, 8).seed(4).fill_soil(3);
+    for &x in columns {
+        builder = builder.place(x, 4, TileType::PlantFlower(0, Size::Medium));
+    }
+    let mut world = builder.build();
+    world.allow_respawn = false;
+    world.set_weather_controller(Box::new(|world: &mut World| {
+        world.rain_intensity = 0.0;
+        world.wind_strength = 0.0;
+        world.gust = None;
+    }));
+    (0..60).any(|_| {
+        world.update();
+        world.get_projectile_count() > 0
+    })
+}
+
+#[test]
+fn flowers_need_pollen_from_a_neighbour_to_set_seed() {
+    assert!(!flowers_seed(&[10]));
+    assert!(!flowers_seed(&[5, 20]));
+    assert!(flowers_seed(&[10, 12]));
+    
+    // Wind carries pollen between flowers too far apart for calm air
+    let mut world = WorldBuilder::new(30, 8).fill_soil(3)
+        .place(10, 4, TileType::PlantFlower(0, Size::Medium))
+        .place(16, 4, TileType::PlantFlower(0, Size::Medium))
+        .build();
+    world.wind_strength = 0.0;
+    world.gust = None;
+    assert!(!world.is_pollinated(10, 4));
+    world.wind_strength = 1.0;
+    assert!(world.is_pollinated(10, 4));
+}
+
+#[test]
+fn mycelium_feeds_starving_roots_and_dies_back_without_them() {
+    let mut builder = WorldBuilder::new(30, 8)
+        .seed(3)
+        .fill_soil(4);
+    // Two roots walled in by exhausted soil; only the second is on a network reaching rich dirt
+    for (rx, ry) in [(6, 5), (20, 5)] {
+        for y in ry - 1..=ry + 1 {
+            for x in rx - 1..=rx + 1 {
+                builder = builder.place(x, y, TileType::DepletedDirt);
+            }
+        }
+        builder = builder.place(rx, ry, TileType::PlantRoot(10, Size::Small));
+    }
+    builder = builder.place(21, 5, TileType::Mycelium(0));
+    for x in 22..=25 {
+        builder = builder.place(x, 4, TileType::NutrientDirt(250))
+            .place(x, 5, TileType::Mycelium(0))
+            .place(x, 6, TileType::NutrientDirt(250));
+    }
+    let mut world = builder.build();
+    world.allow_respawn = false;
+    world.set_weather_controller(Box::new(|world: &mut World| {
+        world.rain_intensity = 0.0;
+        world.wind_strength = 0.0;
+    }));
+    for _ in 0..60 {
+        world.update();
+    }
+    
+    let (TileType::PlantRoot(alone, _), TileType::PlantRoot(fed, _)) = (world.tiles[5][6], world.tiles[5][20]) else {
+        panic!("roots died: {:?} {:?}", world.tiles[5][6], world.tiles[5][20]);
+    };
+    assert!(fed + 30 < alone, "fed root aged to {}, lone root to {}", fed, alone);
+    assert!(world.tiles[4].iter().any(|t| matches!(t, TileType::NutrientDirt(level) if *level < 250)));
+    
+    // With the roots gone the network withers back into soil
+    world.tiles[5][6] = TileType::DepletedDirt;
+    world.tiles[5][20] = TileType::DepletedDirt;
+    for _ in 0..200 {
+        world.update();
+    }
+    assert!(!world.tiles.iter().flatten().any(|t| matches!(t, TileType::Mycelium(_))));
+}
+
+#[test]
+fn nutrient_field_blurs_nutrient_sources() {
+    let world = WorldBuilder::new(20, 10)
+        .fill_soil(2)
+        .place(5, 3, TileType::Nutrient)
+        .place(15, 3, TileType::NutrientDirt(255))
+        .place(15, 4, TileType::NutrientDirt(255))
+        .build();
+    let field = world.nutrient_field();
+    assert_eq!((field.len(), field[0].len()), (10, 20));
+    
+    // Spread evenly over the 5x5 around a source and nothing beyond it; plain dirt counts a little
+    assert_eq!(field[3][5], field[5][7]);
+    assert!(field[3][5] > 0.0);
+    assert_eq!(field[3][8], 0.0);
+    assert!(field[3][15] > field[3][5]);
+    assert!(field[9][10] > 0.0 && field[9][10] < field[3][5]);
+}
+
+#[test]
+fn canopy_shades_the_leaves_beneath_it() {
+    let mut builder = WorldBuilder::new(30, 12)
+        .seed(1)
+        .fill_soil(3);
+    // Stems each holding up a stack of five leaves: the top of each is in full sun, the bottom
+    // under four layers of canopy
+    let columns = [8, 11, 14, 17, 20, 23, 26];
+    for x in columns {
+        for y in 4..=8 {
+            builder = builder
+                .place(x - 1, y, TileType::PlantStem(0, Size::Medium))
+                .place(x, y, TileType::PlantLeaf(0, Size::Medium));
+        }
+    }
+    let mut world = builder.build();
+    // Nothing grows, so the canopy stays as placed
+    world.growth_config.density_cap = 0.0;
+    world.allow_respawn = false;
+    world.set_weather_controller(Box::new(|world: &mut World| {
+        world.rain_intensity = 0.0;
+        world.humidity = 0.9;
+    }));
+    
+    world.day_cycle = std::f32::consts::FRAC_PI_2;
+    assert_eq!(world.light_at(8, 4), 1.0);
+    assert!((world.light_at(8, 5) - 0.7).abs() < 1e-6);
+    assert_eq!(world.light_at(8, 8), 0.0);
+    assert_eq!(world.light_at(9, 8), 1.0);
+    world.day_cycle = -std::f32::consts::FRAC_PI_2;
+    assert_eq!(world.light_at(8, 4), 0.0);
+    
+    // Through the day the understory starves while the sunlit tops carry on
+    for _ in 0..300 {
+        world.update();
+    }
+    let leaves_in_row = |y: usize| columns.iter().filter(|&&x| matches!(world.tiles[y][x], TileType::PlantLeaf(_, _))).count();
+    assert_eq!(leaves_in_row(4), columns.len());
+    assert!(leaves_in_row(8) < columns.len() - 1, "{} of the shaded leaves survived", leaves_in_row(8));
+}
@@ -0,0 +1,492 @@
+use pillbugplants::builder::WorldBuilder;
+use pillbugplants::config::PillbugConfig;
+use pillbugplants::types::{TileType, Size, WindMode, PlantGenome};
+use pillbugplants::world::{World, PillbugState};
+
+fn pillbug_world(meals: u16) -> World {
+    let mut world = WorldBuilder::new(20, 10)
+        .seed(3)
+        .fill_soil(3)
+        .place(5, 6, TileType::PillbugHead(40, Size::Small))
+        .place(6, 6, TileType::PillbugBody(40, Size::Small))
+        .place(7, 6, TileType::PillbugLegs(40, Size::Small))
+        .build();
+    world.allow_respawn = false;
+    world.pillbug_states.insert((5, 6), PillbugState { meals, ..PillbugState::default() });
+    world
+}
+
+fn pillbug_sizes(world: &World) -> Vec<Size> {
+    world.tiles.iter()
+        .flatten()
+        .filter(|t| t.is_pillbug())
+        .filter_map(|t| t.get_size())
+        .collect()
+}
+
+#[test]
+fn well_fed_pillbug_molts_as_a_whole() {
+    let mut world = pillbug_world(6);
+    world.update();
+    
+    let sizes = pillbug_sizes(&world);
+    assert_eq!(sizes.len(), 3);
+    assert!(sizes.iter().all(|s| *s == Size::Medium), "{:?}", sizes);
+    assert_eq!(world.pillbug_states.get(&(5, 6)).map(|s| s.meals), Some(0));
+    
+    // The new shell hardens after a while
+    let soft = |world: &World| world.pillbug_states.values().next().map_or(0, |s| s.soft);
+    assert!(soft(&world) > 0);
+    for _ in 0..40 {
+        world.update();
+    }
+    assert_eq!(soft(&world), 0);
+}
+
+#[test]
+fn hungry_pillbug_stays_small() {
+    let mut world = pillbug_world(0);
+    world.update();
+    
+    assert!(pillbug_sizes(&world).iter().all(|s| *s == Size::Small));
+}
+
+fn fed_or_starved(food: bool) -> World {
+    let mut world = pillbug_world(0);
+    world.pillbug_states.get_mut(&(5, 6)).unwrap().energy = 5;
+    if food {
+        world.tiles[6][4] = TileType::Nutrient;
+        world.tiles[5][5] = TileType::Nutrient;
+    }
+    world.set_weather_controller(Box::new(|world: &mut World| {
+        world.temperature = 0.3;
+        world.rain_intensity = 0.0;
+    }));
+    for _ in 0..8 {
+        world.update();
+    }
+    world
+}
+
+#[test]
+fn pillbugs_starve_without_food_and_eating_restores_energy_not_youth() {
+    // With nothing to eat the young pillbug runs out of energy and dies whole
+    let starved = fed_or_starved(false);
+    let carcass = starved.tiles.iter().flatten().filter(|tile| matches!(tile, TileType::PillbugDecaying(_, _))).count();
+    assert_eq!(carcass, 3);
+    
+    // A meal tops its energy up but no longer turns back its age
+    let fed = fed_or_starved(true);
+    let (x, y) = head_position(&fed).unwrap();
+    assert!(fed.pillbug_states[&(x, y)].energy > 5);
+    assert!(matches!(fed.tiles[y][x], TileType::PillbugHead(48, Size::Small)));
+}
+
+// Pillbug on a narrow pillar with drops to the floor on both sides
+fn pillar_world(seed: u64) -> World {
+    let mut builder = WorldBuilder::new(20, 10)
+        .seed(seed)
+        .fill_soil(1)
+        .wind(WindMode::Deterministic { direction: 0.0, strength: 0.0 })
+        .place(0, 8, TileType::Rock)
+        .place(1, 8, TileType::Rock)
+        .place(2, 8, TileType::Rock)
+        .place(3, 8, TileType::Rock)
+        .place(4, 8, TileType::Rock)
+        .place(7, 5, TileType::PillbugLegs(30, Size::Small))
+        .place(8, 5, TileType::PillbugBody(30, Size::Small))
+        .place(9, 5, TileType::PillbugHead(30, Size::Small));
+    for y in 6..9 {
+        for x in 7..10 {
+            builder = builder.place(x, y, TileType::Dirt);
+        }
+    }
+    let mut world = builder.build();
+    world.allow_respawn = false;
+    world.set_weather_controller(Box::new(|world: &mut World| {
+        world.rain_intensity = 0.0;
+        keep_fed(world);
+    }));
+    world
+}
+
+// Top up every pillbug's energy, so one walking bare ground lives out the test
+fn keep_fed(world: &mut World) {
+    for state in world.pillbug_states.values_mut() {
+        state.energy = 100;
+    }
+}
+
+fn head_position(world: &World) -> Option<(usize, usize)> {
+    (0..world.height)
+        .flat_map(|y| (0..world.width).map(move |x| (x, y)))
+        .find(|&(x, y)| matches!(world.tiles[y][x], TileType::PillbugHead(_, _)))
+}
+
+#[test]
+fn pillbugs_prefer_staying_on_solid_ground() {
+    // Count steps from standing on the pillar into open air (off the side or hopping up)
+    let mut steps_into_air = 0;
+    for seed in 0..40 {
+        let mut world = pillar_world(seed);
+        let mut on_pillar = true;
+        for _ in 0..200 {
+            world.update();
+            let now_on_pillar = matches!(head_position(&world), Some((x, 5)) if (7..=11).contains(&x));
+            if on_pillar && !now_on_pillar {
+                steps_into_air += 1;
+            }
+            on_pillar = now_on_pillar;
+        }
+    }
+    
+    // Walking blindly this happens around 50 times
+    assert!(steps_into_air <= 20, "pillbugs stepped into the air {} times", steps_into_air);
+}
+
+// Seeds scattered on bare rock (so they can't germinate), with or without a pillbug foraging among them
+fn seed_bank_after(with_pillbug: bool) -> usize {
+    let mut builder = WorldBuilder::new(20, 10)
+        .seed(6)
+        .fill_soil(3)
+        .wind(WindMode::Deterministic { direction: 0.0, strength: 0.0 });
+    for x in 0..20 {
+        builder = builder.place(x, 7, TileType::Rock);
+    }
+    for x in (2..18).filter(|x| x % 2 == 0) {
+        builder = builder.place(x, 6, TileType::Seed(0, Size::Small));
+    }
+    if with_pillbug {
+        builder = builder
+            .place(9, 6, TileType::PillbugHead(30, Size::Medium))
+            .place(9, 5, TileType::PillbugBody(30, Size::Medium));
+    }
+    let mut world = builder.build();
+    world.allow_respawn = false;
+    
+    for _ in 0..20 {
+        world.update();
+    }
+    world.tile_census()["seed"]
+}
+
+#[test]
+fn pillbugs_eat_seeds() {
+    assert_eq!(seed_bank_after(false), 8);
+    assert!(seed_bank_after(true) < 8);
+}
+
+// Pillbugs wandering a bare rock floor at night, with seeds strewn among them if `seeded`
+const FORAGERS: [usize; 4] = [8, 20, 32, 44];
+
+fn forager_world(seeded: bool) -> World {
+    let mut builder = WorldBuilder::new(56, 10)
+        .seed(9)
+        .fill_soil(3)
+        .wind(WindMode::Deterministic { direction: 0.0, strength: 0.0 });
+    for x in 0..56 {
+        builder = builder.place(x, 7, TileType::Rock);
+        if seeded && x % 4 == 2 {
+            builder = builder.place(x, 6, TileType::Seed(0, Size::Large));
+        }
+    }
+    for x in FORAGERS {
+        builder = builder
+            .place(x, 6, TileType::PillbugHead(30, Size::Medium))
+            .place(x + 1, 6, TileType::PillbugBody(30, Size::Medium))
+            .place(x + 2, 6, TileType::PillbugLegs(30, Size::Medium));
+    }
+    let mut world = builder.build();
+    world.allow_respawn = false;
+    world.set_weather_controller(Box::new(|world: &mut World| {
+        world.day_cycle = 4.7;
+        world.rain_intensity = 0.0;
+        keep_fed(world);
+    }));
+    world
+}
+
+#[test]
+fn pillbugs_carry_seeds_to_new_ground() {
+    // Bugs with seeds on their backs drop them, genome and all, on ground they walk off
+    const CARRIED: PlantGenome = PlantGenome { growth_speed: 0.7, drought_tolerance: 0.9, disease_resistance: 0.1 };
+    let mut world = forager_world(false);
+    for x in FORAGERS {
+        world.pillbug_states.insert((x, 6), PillbugState { seed: Some((TileType::Seed(0, Size::Large), CARRIED)), carried_for: 10, ..PillbugState::default() });
+    }
+    let dropped = (0..400).find_map(|_| {
+        world.update();
+        (0..world.width).find(|&x| matches!(world.tiles[6][x], TileType::Seed(_, _))).map(|x| world.genomes[6][x])
+    });
+    assert_eq!(dropped, Some(Some(CARRIED)));
+    
+    // Bugs foraging among seeds pick some up
+    let mut world = forager_world(true);
+    let picked_up = (0..400).any(|_| {
+        world.update();
+        world.pillbug_states.values().any(|state| state.seed.is_some())
+    });
+    assert!(picked_up);
+}
+
+// Pillbug head moves over a stretch of pinned day or night and temperature on open ground
+fn head_moves(day: bool, nocturnal: bool, temperature: f32) -> usize {
+    let mut builder = WorldBuilder::new(80, 10)
+        .seed(8)
+        .fill_soil(3)
+        .wind(WindMode::Deterministic { direction: 0.0, strength: 0.0 });
+    for x in (6..80).step_by(10) {
+        builder = builder
+            .place(x - 2, 6, TileType::PillbugLegs(10, Size::Medium))
+            .place(x - 1, 6, TileType::PillbugBody(10, Size::Medium))
+            .place(x, 6, TileType::PillbugHead(10, Size::Medium));
+    }
+    let mut world = builder.build();
+    world.allow_respawn = false;
+    world.activity_config.nocturnal = nocturnal;
+    world.set_weather_controller(Box::new(move |world: &mut World| {
+        world.day_cycle = if day { 1.5 } else { 4.7 };
+        world.temperature = temperature;
+        world.rain_intensity = 0.0;
+        keep_fed(world);
+    }));
+    
+    let heads = |world: &World| (0..world.height).flat_map(|y| (0..world.width).map(move |x| (x, y)))
+        .filter(|&(x, y)| matches!(world.tiles[y][x], TileType::PillbugHead(_, _)))
+        .collect::<Vec<_>>();
+    let mut moves = 0;
+    for _ in 0..300 {
+        let before = heads(&world);
+        world.update();
+        moves += heads(&world).iter().filter(|head| !before.contains(head)).count();
+    }
+    moves
+}
+
+#[test]
+fn pillbugs_follow_a_daily_rhythm() {
+    let (day, night) = (head_moves(true, true, 0.3), head_moves(false, true, 0.3));
+    assert!(night * 2 > day * 3, "nocturnal pillbugs moved {} times by night and {} by day", night, day);
+    
+    let (day, night) = (head_moves(true, false, 0.3), head_moves(false, false, 0.3));
+    assert!(day * 2 > night * 3, "diurnal pillbugs moved {} times by day and {} by night", day, night);
+}
+
+#[test]
+fn cold_slows_pillbugs_and_deep_cold_sends_them_into_hibernation() {
+    let (mild, cold, frozen) = (head_moves(false, true, 0.3), head_moves(false, true, -0.3), head_moves(false, true, -0.8));
+    assert!(mild > cold && cold > frozen, "moves mild {} cold {} frozen {}", mild, cold, frozen);
+    assert!(frozen * 5 < mild, "hibernating pillbugs moved {} times", frozen);
+    
+    // A lone hungry pillbug ages with its metabolism
+    let aged = |temperature: f32| {
+        let mut world = pillbug_world(0);
+        world.set_weather_controller(Box::new(move |world: &mut World| {
+            world.temperature = temperature;
+            world.rain_intensity = 0.0;
+        }));
+        for _ in 0..40 {
+            world.update();
+        }
+        world.tiles.iter().flatten().find_map(|tile| match tile {
+            TileType::PillbugHead(age, _) | TileType::PillbugRolled(age, _) => Some(*age),
+            _ => None,
+        }).unwrap()
+    };
+    assert_eq!(aged(0.3), 80);
+    assert!(aged(0.9) > 95);
+    assert!(aged(-0.3) < 70);
+    assert!(aged(-0.8) < 45);
+}
+
+#[test]
+fn pillbug_index_finds_the_same_bugs_as_the_tiles() {
+    let mut world = World::new_seeded(60, 30, 8);
+    for _ in 0..50 {
+        world.update();
+    }
+    let before = world.tiles.clone();
+    world.update();
+    
+    // The index holds the heads as they stood when the tick started
+    for (y, x) in [(5, 5), (20, 30), (29, 59), (0, 0), (15, 2)] {
+        for radius in [0, 3, 6, 100] {
+            let mut expected = Vec::new();
+            for (ny, row) in before.iter().enumerate() {
+                for (nx, tile) in row.iter().enumerate() {
+                    if let TileType::PillbugHead(_, size) = tile {
+                        if nx.abs_diff(x) <= radius && ny.abs_diff(y) <= radius {
+                            expected.push((nx, ny, *size));
+                        }
+                    }
+                }
+            }
+            assert_eq!(world.pillbugs_near(x, y, radius).collect::<Vec<_>>(), expected);
+        }
+    }
+    assert!(!world.pillbug_positions.is_empty());
+}
+
+// Grown Medium pillbug heads on bare flat ground at the given columns, left to move by day until
+// just before old age slows them, returning how many times a head moved and where they ended up
+fn herd(columns: &[usize], seed: u64) -> (usize, Vec<usize>) {
+    let mut builder = WorldBuilder::new(60, 10)
+        .seed(seed)
+        .fill_soil(3)
+        .wind(WindMode::Deterministic { direction: 0.0, strength: 0.0 });
+    for &x in columns {
+        builder = builder.place(x, 6, TileType::PillbugHead(20, Size::Medium));
+    }
+    let mut world = builder.build();
+    world.allow_respawn = false;
+    world.activity_config.nocturnal = false;
+    world.set_weather_controller(Box::new(|world: &mut World| {
+        world.day_cycle = 1.5;
+        world.temperature = 0.3;
+        world.rain_intensity = 0.0;
+    }));
+    
+    let heads = |world: &World| (0..world.height).flat_map(|y| (0..world.width).map(move |x| (x, y)))
+        .filter(|&(x, y)| matches!(world.tiles[y][x], TileType::PillbugHead(_, _) | TileType::PillbugRolled(_, _)))
+        .collect::<Vec<_>>();
+    let mut moves = 0;
+    for _ in 0..90 {
+        let before = heads(&world);
+        world.update();
+        moves += heads(&world).iter().filter(|head| !before.contains(head)).count();
+    }
+    (moves, heads(&world).iter().map(|&(x, _)| x).collect())
+}
+
+#[test]
+fn pillbugs_huddle_in_clusters_and_stragglers_rejoin() {
+    let world = WorldBuilder::new(20, 10)
+        .fill_soil(3)
+        .place(5, 6, TileType::PillbugHead(40, Size::Small))
+        .place(6, 5, TileType::PillbugRolled(40, Size::Large))
+        .place(7, 6, TileType::PillbugHead(40, Size::Medium))
+        .place(8, 6, TileType::PillbugBody(40, Size::Medium))
+        .place(10, 6, TileType::PillbugHead(40, Size::Medium))
+        .build();
+    assert_eq!(world.count_nearby_pillbugs(5, 6, 2), 2);
+    assert_eq!(world.count_nearby_pillbugs(7, 6, 3), 3);
+    assert_eq!(world.count_nearby_pillbugs(10, 6, 1), 0);
+    
+    let (mut huddled, mut scattered, mut rejoined) = (0, 0, 0);
+    for seed in 1..=8 {
+        huddled += herd(&[20, 21, 22, 23], seed).0;
+        scattered += herd(&[10, 20, 30, 40], seed).0;
+        
+        // A straggler four cells off drifts back to its group
+        let (_, columns) = herd(&[20, 21, 22, 26], seed);
+        if columns.iter().all(|&x| x < 26) {
+            rejoined += 1;
+        }
+    }
+    assert!(huddled * 4 < scattered, "huddled bugs moved {} times, scattered ones {}", huddled, scattered);
+    assert!(rejoined >= 6, "only {} of 8 stragglers rejoined their group", rejoined);
+}
+
+// A grown pillbug left to wander bare flat ground by day from column 30, with a fresh pheromone
+// trail kept laid along the ground to its right or not
+fn wanderer(trail: bool, seed: u64) -> World {
+    let mut world = WorldBuilder::new(60, 10)
+        .seed(seed)
+        .fill_soil(3)
+        .wind(WindMode::Deterministic { direction: 0.0, strength: 0.0 })
+        .place(30, 6, TileType::PillbugHead(20, Size::Medium))
+        .build();
+    world.allow_respawn = false;
+    world.activity_config.nocturnal = false;
+    world.set_weather_controller(Box::new(move |world: &mut World| {
+        world.day_cycle = 1.5;
+        world.temperature = 0.3;
+        world.rain_intensity = 0.0;
+        keep_fed(world);
+        if trail {
+            for x in 31..60 {
+                world.pheromone[6][x] = 200;
+            }
+        }
+    }));
+    for _ in 0..90 {
+        world.update();
+    }
+    world
+}
+
+#[test]
+fn pillbugs_follow_pheromone_trails() {
+    let (mut trailed, mut free, mut laid) = (0, 0, 0);
+    for seed in 1..=8 {
+        if head_position(&wanderer(true, seed)).unwrap().0 > 30 {
+            trailed += 1;
+        }
+        let world = wanderer(false, seed);
+        if head_position(&world).unwrap().0 > 30 {
+            free += 1;
+        }
+        if world.pheromone.iter().flatten().any(|&scent| scent > 0) {
+            laid += 1;
+        }
+    }
+    assert!(trailed >= 7 && trailed > free + 2, "{} of 8 bugs followed the trail right, {} wandered right without one", trailed, free);
+    assert!(laid >= 6, "only {} of 8 wandering bugs left a trail", laid);
+    
+    // A trail left alone fades
+    let mut world = WorldBuilder::new(20, 10).fill_soil(3).build();
+    world.pheromone[6][5] = 3;
+    world.update();
+    assert_eq!(world.pheromone[6][5], 2);
+}
+
+// A lone grown pillbug on wide open ground, with a nutrient kept beside its head every tick,
+// bred under the given config; returns how many were born and whether every newborn came out whole
+fn litters(config: PillbugConfig) -> (usize, bool) {
+    let builder = WorldBuilder::new(40, 12)
+        .seed(5)
+        .fill_soil(3)
+        .wind(WindMode::Deterministic { direction: 0.0, strength: 0.0 })
+        .place(20, 8, TileType::PillbugHead(35, Size::Medium))
+        .place(21, 8, TileType::PillbugBody(35, Size::Medium))
+        .place(22, 8, TileType::PillbugLegs(35, Size::Medium));
+    let mut world = builder.build();
+    world.allow_respawn = false;
+    world.pillbug_config = config;
+    world.set_weather_controller(Box::new(|world: &mut World| {
+        world.temperature = 0.3;
+        world.rain_intensity = 0.0;
+        let heads: Vec<(usize, usize)> = world.pillbug_states.keys().copied().collect();
+        for (x, y) in heads {
+            if y > 0 && world.tiles[y - 1][x] == TileType::Empty {
+                world.tiles[y - 1][x] = TileType::Nutrient;
+            }
+        }
+    }));
+    
+    let mut whole = true;
+    for _ in 0..40 {
+        world.update();
+        for y in 0..world.height {
+            for x in 0..world.width {
+                if matches!(world.tiles[y][x], TileType::PillbugHead(0, _)) {
+                    let beside = |dx: i32| world.neighbor(x, y, dx, 0).map(|(nx, ny)| world.tiles[ny][nx]);
+                    whole &= [-1, 1].iter().any(|&dx| matches!(beside(dx), Some(TileType::PillbugBody(0, _))));
+                }
+            }
+        }
+    }
+    let born = world.events.iter().filter(|event| event.message.starts_with("A pillbug was born")).count();
+    (born, whole)
+}
+
+#[test]
+fn breeding_follows_the_pillbug_config_and_babies_are_born_whole() {
+    let (born, whole) = litters(PillbugConfig { breed_chance: 1.0, ..PillbugConfig::default() });
+    assert!(born > 0, "an adult fed every tick never bred");
+    assert!(whole, "a baby was born without its body");
+    
+    assert_eq!(litters(PillbugConfig { breed_chance: 0.0, ..PillbugConfig::default() }).0, 0);
+    // Too young to breed for the whole run
+    assert_eq!(litters(PillbugConfig { breed_chance: 1.0, maturity_age: 200, ..PillbugConfig::default() }).0, 0);
+}
@@ -0,0 +1,78 @@
+use std::io;
+use pillbugplants::app::App;
+use pillbugplants::replay::Replay;
+use pillbugplants::types::TileType;
+use pillbugplants::world::World;
+
+// A seeded run recorded for `ticks` ticks, with the tiles after every tick
+fn recorded_run(ticks: usize) -> (Vec<u8>, Vec<Vec<Vec<TileType>>>) {
+    let mut world = World::new_seeded(40, 20, 21);
+    let mut bytes = Vec::new();
+    let mut history = vec![world.tiles.clone()];
+    let mut recording = world.record_to_writer(&mut bytes).unwrap();
+    for _ in 0..ticks {
+        recording.tick(&mut world).unwrap();
+        history.push(world.tiles.clone());
+    }
+    drop(recording);
+    (bytes, history)
+}
+
+#[test]
+fn replays_scrub_through_the_recorded_run() {
+    let (bytes, history) = recorded_run(60);
+    let (mut world, mut replay) = Replay::load_from_reader(&mut &bytes[..]).unwrap();
+    assert_eq!(replay.len(), 60);
+    assert_eq!(world.tiles, history[0]);
+    
+    for (tick, tiles) in history.iter().enumerate().skip(1) {
+        assert!(replay.step_forward(&mut world));
+        assert_eq!(&world.tiles, tiles, "tick {} differs", tick);
+        assert_eq!(world.tick, tick as u64);
+    }
+    assert!(!replay.step_forward(&mut world));
+    
+    for tick in (0..60).rev() {
+        assert!(replay.step_back(&mut world));
+        assert_eq!(world.tiles, history[tick], "stepping back to tick {} differs", tick);
+    }
+    assert!(!replay.step_back(&mut world));
+    assert_eq!((replay.position(), world.tick), (0, 0));
+}
+
+#[test]
+fn recordings_are_smaller_than_saving_every_frame() {
+    let (bytes, _) = recorded_run(60);
+    let mut snapshot = Vec::new();
+    World::new_seeded(40, 20, 21).save_to_writer(&mut snapshot).unwrap();
+    
+    assert!(bytes.len() < snapshot.len() * 5, "{} bytes for 60 ticks vs {} per snapshot", bytes.len(), snapshot.len());
+}
+
+#[test]
+fn cut_off_recordings_play_up_to_their_last_whole_frame() {
+    let (bytes, history) = recorded_run(10);
+    let (mut world, mut replay) = Replay::load_from_reader(&mut &bytes[..bytes.len() - 3]).unwrap();
+    // The last frame lost its end, so it's dropped whole
+    assert_eq!(replay.len(), 9);
+    while replay.step_forward(&mut world) {}
+    assert_eq!(world.tiles, history[replay.len()]);
+    
+    let err = Replay::load_from_reader(&mut &b"PBPS\x09"[..]).err().unwrap();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn the_app_steps_through_replays_from_a_paused_start() {
+    let (bytes, history) = recorded_run(5);
+    let (world, replay) = Replay::load_from_reader(&mut &bytes[..]).unwrap();
+    let mut app = App::with_replay(world, replay);
+    assert!(app.paused);
+    
+    for _ in 0..7 {
+        app.tick();
+    }
+    assert_eq!(app.world.tiles, history[5]);
+    app.step_back();
+    assert_eq!(app.world.tiles, history[4]);
+}
@@ -0,0 +1,122 @@
+use std::io;
+use pillbugplants::types::{WindMode, EdgeBehavior, TileType, Size, PlantGenome, SystemFlags};
+use pillbugplants::builder::WorldBuilder;
+use pillbugplants::world::{World, PillbugState};
+
+fn saved(world: &World) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    world.save_to_writer(&mut bytes).unwrap();
+    bytes
+}
+
+#[test]
+fn save_round_trips_world_state() {
+    let mut world = World::new_seeded(40, 20, 7);
+    world.wind_mode = WindMode::Deterministic { direction: 1.0, strength: 0.4 };
+    world.gust = Some((0.5, 250));
+    world.wrap_horizontal = true;
+    world.edge_behavior = EdgeBehavior::Wrap;
+    world.drought_config.stress_after = 123;
+    world.activity_config.nocturnal = false;
+    world.physics_config.spore_fall_chance = 0.9;
+    world.interaction_config.germination[2][0] = 0.1;
+    world.interaction_config.nitrogen_fixing[3] = 0.5;
+    world.allow_respawn = false;
+    world.respawn_config.plant_target = 7;
+    world.systems.set(SystemFlags::WIND, false);
+    world.disease_config.lethal_age = 90;
+    world.disease_config.recovery_chance = 0.2;
+    world.pillbug_config.maturity_age = 12;
+    world.pillbug_config.breed_chance = 0.3;
+    world.set_year_length(300);
+    world.ticks_per_day = 50;
+    for _ in 0..100 {
+        world.update();
+    }
+    let carrier = PillbugState { seed: Some((TileType::Seed(40, Size::Large), PlantGenome::default())), carried_for: 3, soft: 5, ..PillbugState::default() };
+    world.pillbug_states.insert((1, 2), carrier);
+    world.biome_pressure[3][4] = 2;
+    
+    let bytes = saved(&world);
+    let loaded = World::load_from_reader(&mut &bytes[..]).unwrap();
+    
+    assert_eq!(loaded.tiles, world.tiles);
+    assert!(loaded.biome_map == world.biome_map);
+    assert_eq!(loaded.soil_layers, world.soil_layers);
+    assert_eq!(loaded.disturbance, world.disturbance);
+    assert_eq!(loaded.biome_pressure, world.biome_pressure);
+    assert_eq!(loaded.pheromone, world.pheromone);
+    assert_eq!(loaded.elevation, world.elevation);
+    assert_eq!(loaded.species, world.species);
+    assert_eq!(loaded.genomes, world.genomes);
+    assert_eq!((loaded.width, loaded.height, loaded.tick, loaded.seed), (world.width, world.height, world.tick, world.seed));
+    assert_eq!(loaded.season_cycle, world.season_cycle);
+    assert_eq!((loaded.ticks_per_year, loaded.ticks_per_day), (300, 50));
+    assert_eq!(loaded.rain_intensity, world.rain_intensity);
+    assert_eq!(loaded.ticks_since_rain, world.ticks_since_rain);
+    assert_eq!(loaded.evaporated, world.evaporated);
+    assert_eq!(loaded.wind_mode, world.wind_mode);
+    assert_eq!(loaded.gust, world.gust);
+    assert_eq!(loaded.edge_behavior, world.edge_behavior);
+    assert!(loaded.wrap_horizontal);
+    assert_eq!(loaded.drought_config.stress_after, 123);
+    assert_eq!(loaded.activity_config, world.activity_config);
+    assert_eq!(loaded.physics_config, world.physics_config);
+    assert_eq!(loaded.interaction_config, world.interaction_config);
+    assert!(!loaded.allow_respawn);
+    assert_eq!(loaded.respawn_config, world.respawn_config);
+    assert_eq!(loaded.systems, world.systems);
+    assert_eq!(loaded.disease_config, world.disease_config);
+    assert_eq!(loaded.pillbug_config, world.pillbug_config);
+    assert_eq!(loaded.get_projectile_count(), world.get_projectile_count());
+    assert_eq!(loaded.pillbug_states[&(1, 2)].seed, world.pillbug_states[&(1, 2)].seed);
+    assert_eq!(loaded.pillbug_states[&(1, 2)].soft, 5);
+    // Everything saved, including seeds in flight and pillbug states, comes back byte for byte;
+    // only the resume seed at bytes 29..37 is drawn afresh from the loaded world's generator
+    let resaved = saved(&loaded);
+    assert_eq!(resaved[..29], bytes[..29]);
+    assert_eq!(resaved[37..], bytes[37..]);
+}
+
+#[test]
+fn loaded_worlds_resume_identically() {
+    let mut world = World::new_seeded(40, 20, 11);
+    for _ in 0..50 {
+        world.update();
+    }
+    let bytes = saved(&world);
+    
+    let mut first = World::load_from_reader(&mut &bytes[..]).unwrap();
+    let mut second = World::load_from_reader(&mut &bytes[..]).unwrap();
+    for _ in 0..100 {
+        first.update();
+        second.update();
+    }
+    
+    assert_eq!(first.to_string(), second.to_string());
+}
+
+#[test]
+fn load_rejects_other_data() {
+    let err = World::load_from_reader(&mut &b"PBPF\x01"[..]).err().unwrap();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    
+    let bytes = saved(&WorldBuilder::new(10, 5).fill_soil(2).build());
+    let err = World::load_from_reader(&mut &bytes[..bytes.len() - 1]).err().unwrap();
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    
+    // Only the current version loads
+    let mut other_version = bytes.clone();
+    other_version[4] = 2;
+    let err = World::load_from_reader(&mut &other_version[..]).err().unwrap();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    
+    // Headers claiming empty or enormous worlds are refused before anything is allocated
+    for (width, height) in [(0u32, 5u32), (10, 0), (u32::MAX, 5), (4000, 4000)] {
+        let mut header = bytes[..5].to_vec();
+        header.extend(width.to_be_bytes());
+        header.extend(height.to_be_bytes());
+        let err = World::load_from_reader(&mut &header[..]).err().unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData, "{}x{}", width, height);
+    }
+}
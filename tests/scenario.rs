@@ -0,0 +1,536 @@
+use pillbugplants::builder::WorldBuilder;
+use pillbugplants::app::{App, MIN_TICK_INTERVAL_MS, MAX_TICK_INTERVAL_MS};
+use pillbugplants::world::{World, EcosystemPhase, TileEdit, MAX_EDIT_HISTORY, MAX_EVENTS, MIN_WORLD_WIDTH, MIN_WORLD_HEIGHT};
+use pillbugplants::config::{PhysicsConfig, RespawnConfig};
+use pillbugplants::types::{Biome, CLEAN_WATER, RenderPalette, Season, Size, SystemFlags, TileType, WindMode, blend};
+use crossterm::event::{Event, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::layout::Rect;
+use ratatui::style::Color;
+
+#[test]
+fn custom_layout_skips_generation() {
+    let world = WorldBuilder::new(20, 10).seed(7).fill_soil(2).build();
+    
+    for y in 0..8 {
+        assert!(world.tiles[y].iter().all(|t| *t == TileType::Empty), "row {} should be empty", y);
+    }
+    for y in 8..10 {
+        assert!(world.tiles[y].iter().all(|t| *t == TileType::Dirt), "row {} should be dirt", y);
+    }
+}
+
+#[test]
+fn sand_settles_on_soil() {
+    let mut world = WorldBuilder::new(20, 10)
+        .seed(1)
+        .fill_soil(3)
+        .place(10, 0, TileType::Sand)
+        .build();
+    
+    for _ in 0..20 {
+        world.update();
+    }
+    
+    assert_eq!(world.tiles[6][10], TileType::Sand);
+}
+
+// Where sand perched on a rock in an edge column ends up, with its inward slide blocked
+fn edge_sand(column: usize, wrap: bool) -> Vec<(usize, usize)> {
+    let inward = if column == 0 { 1 } else { column - 1 };
+    let mut world = WorldBuilder::new(20, 10)
+        .seed(1)
+        .fill_soil(3)
+        .wrap_horizontal(wrap)
+        .wind(WindMode::Deterministic { direction: 0.0, strength: 0.0 })
+        .place(column, 6, TileType::Rock)
+        .place(inward, 6, TileType::Rock)
+        .place(column, 5, TileType::Sand)
+        .build();
+    for _ in 0..5 {
+        world.update();
+    }
+    (0..world.height)
+        .flat_map(|y| (0..world.width).map(move |x| (x, y)))
+        .filter(|&(x, y)| world.tiles[y][x] == TileType::Sand)
+        .collect()
+}
+
+#[test]
+fn sand_at_the_edges_stays_in_the_world() {
+    // Sand can't slide out of the world, so it stays perched
+    assert_eq!(edge_sand(0, false), vec![(0, 5)]);
+    assert_eq!(edge_sand(19, false), vec![(19, 5)]);
+    // With the sides joined it slides across the seam onto the other side's ground
+    assert_eq!(edge_sand(0, true), vec![(19, 6)]);
+    assert_eq!(edge_sand(19, true), vec![(0, 6)]);
+}
+
+// Rows holding spores after a few still ticks with the given fall chance
+fn spore_rows(fall_chance: f32) -> Vec<usize> {
+    let mut builder = WorldBuilder::new(20, 12)
+        .seed(1)
+        .fill_soil(3)
+        .wind(WindMode::Deterministic { direction: 0.0, strength: 0.0 });
+    for x in 6..14 {
+        builder = builder.place(x, 1, TileType::Spore(0));
+    }
+    let mut world = builder.build();
+    world.physics_config = PhysicsConfig { spore_fall_chance: fall_chance, ..PhysicsConfig::default() };
+    world.allow_respawn = false;
+    world.set_weather_controller(Box::new(|world: &mut World| world.rain_intensity = 0.0));
+    for _ in 0..8 {
+        world.update();
+    }
+    (0..world.height).flat_map(|y| world.tiles[y].iter().filter(|tile| matches!(tile, TileType::Spore(_))).map(move |_| y)).collect()
+}
+
+#[test]
+fn fall_chances_decide_how_floaty_particles_are() {
+    assert_eq!(spore_rows(0.0), vec![1; 8]);
+    assert_eq!(spore_rows(1.0), vec![8; 8]);
+    let drifting = spore_rows(0.3);
+    assert!(drifting.iter().any(|&y| y < 8), "spores all settled at the default chance: {:?}", drifting);
+}
+
+#[test]
+fn same_seed_generates_same_world() {
+    let a = WorldBuilder::new(40, 20).seed(99).build();
+    let b = WorldBuilder::new(40, 20).seed(99).build();
+    
+    assert_eq!(a.tiles, b.tiles);
+}
+
+#[test]
+fn regenerate_moves_to_next_seed_and_keeps_config() {
+    let mut app = App::with_world(World::new_seeded(40, 20, 7));
+    app.world.physics_config.evaporation_rate = 0.5;
+    app.world.interaction_config.radius = 5;
+    app.world.allow_respawn = false;
+    for _ in 0..10 {
+        app.tick();
+    }
+    let settings = app.world.settings();
+    
+    app.regenerate();
+    
+    assert_eq!(app.world.seed, 8);
+    assert_eq!(app.world.tick, 0);
+    assert_eq!(app.world.physics_config.evaporation_rate, 0.5);
+    assert_eq!(app.world.interaction_config.radius, 5);
+    assert_eq!(app.world.settings(), settings);
+    assert_eq!(app.world.tiles, World::new_seeded(40, 20, 8).tiles);
+}
+
+#[test]
+fn tick_rate_stays_within_bounds() {
+    let mut app = App::with_world(WorldBuilder::new(10, 5).fill_soil(2).build());
+    for _ in 0..10 {
+        app.speed_up();
+    }
+    assert_eq!(app.tick_interval_ms, MIN_TICK_INTERVAL_MS);
+    
+    for _ in 0..20 {
+        app.slow_down();
+    }
+    assert_eq!(app.tick_interval_ms, MAX_TICK_INTERVAL_MS);
+}
+
+#[test]
+fn clicks_map_to_world_tiles() {
+    let mut app = App::with_world(WorldBuilder::new(20, 10).fill_soil(2).build());
+    let area = Rect::new(0, 0, 40, 20);
+    
+    // Screen margin, view margin, and the view's border put the first tile at (3, 3)
+    assert_eq!(app.tile_at(area, 3, 3), Some((0, 0)));
+    assert_eq!(app.tile_at(area, 7, 5), Some((4, 2)));
+    assert_eq!(app.tile_at(area, 2, 3), None);
+    assert_eq!(app.tile_at(area, 23, 3), None); // Past the world's right edge
+    
+    app.select_brush('3');
+    let (x, y) = app.tile_at(area, 7, 5).unwrap();
+    app.paint(x, y);
+    assert_eq!(app.world.tiles[2][4], TileType::Water(150, CLEAN_WATER));
+}
+
+#[test]
+fn painting_does_not_advance_the_simulation() {
+    let mut app = App::with_world(WorldBuilder::new(20, 10).fill_soil(2).build());
+    let area = Rect::new(0, 0, 40, 20);
+    app.select_brush('1');
+    let mouse = |kind, column| Event::Mouse(MouseEvent { kind, column, row: 5, modifiers: KeyModifiers::NONE });
+    
+    assert!(!app.handle_event(mouse(MouseEventKind::Down(MouseButton::Left), 3), area));
+    for column in 4..15 {
+        assert!(!app.handle_event(mouse(MouseEventKind::Drag(MouseButton::Left), column), area));
+    }
+    
+    assert_eq!(app.world.tiles[2][11], TileType::Dirt);
+    assert_eq!(app.world.tick, 0);
+}
+
+#[test]
+fn camera_pans_within_world_and_offsets_clicks() {
+    let mut app = App::with_world(WorldBuilder::new(100, 50).fill_soil(2).build());
+    let area = Rect::new(0, 0, 40, 20);
+    app.fit_view(area);
+    assert_eq!(app.view, (34, 11));
+    
+    app.pan(1000, 1000);
+    assert_eq!(app.camera, (66, 39)); // Far edge of the view on the far edge of the world
+    assert_eq!(app.tile_at(area, 3, 3), Some((66, 39)));
+    
+    app.pan(-1000, -5);
+    assert_eq!(app.camera, (0, 34));
+    
+    // The cursor drags the camera along when it leaves the view
+    app.cursor = (0, 34);
+    app.move_cursor(0, -1);
+    assert_eq!(app.camera, (0, 33));
+}
+
+#[test]
+fn offsets_stay_inside_the_world() {
+    let mut world = WorldBuilder::new(10, 5).fill_soil(1).build();
+    world.wrap_horizontal = true;
+    
+    assert_eq!(world.offset(3, 2, -1, 1), Some((2, 3)));
+    assert_eq!(world.offset(0, 2, -1, 0), None);
+    assert_eq!(world.offset(4, 0, 0, -1), None);
+    assert_eq!(world.offset(9, 4, 1, 0), None, "offset never wraps, even in a wrapping world");
+    assert_eq!(world.offset(9, 4, 0, 1), None);
+    assert_eq!(world.offset(0, 0, -11, -6), None);
+}
+
+#[test]
+fn undo_reverts_whole_edit_batches() {
+    let mut app = App::with_world(WorldBuilder::new(20, 10).fill_soil(2).build());
+    let original = app.world.tiles.clone();
+    
+    // One drag across three cells, passing back over the first
+    app.world.begin_edit();
+    app.select_brush('3');
+    for x in [2, 3, 4, 2] {
+        app.paint(x, 5);
+    }
+    app.world.begin_edit();
+    app.select_brush('8');
+    app.paint(3, 8);
+    
+    app.undo();
+    assert_eq!(app.world.tiles[8][3], original[8][3]);
+    assert_eq!(app.world.tiles[5][4], TileType::Water(150, CLEAN_WATER));
+    app.undo();
+    assert_eq!(app.world.tiles, original);
+    assert_eq!(app.status.as_deref(), Some("Undid 3 tile edits"));
+    app.undo();
+    assert_eq!(app.status.as_deref(), Some("Nothing to undo"));
+    
+    // Only the most recent batches are kept
+    for i in 0..MAX_EDIT_HISTORY + 5 {
+        app.world.begin_edit();
+        app.world.edit_tile(5, 1, if i % 2 == 0 { TileType::Sand } else { TileType::Rock });
+    }
+    assert_eq!(app.world.history.len(), MAX_EDIT_HISTORY);
+    // Each batch remembers what its edits overwrote
+    assert_eq!(app.world.history.last().unwrap(), &[TileEdit { x: 5, y: 1, old_tile: TileType::Rock }]);
+}
+
+#[test]
+fn palettes_cycle_and_tell_plant_parts_apart() {
+    let mut palette = RenderPalette::default();
+    for _ in 0..3 {
+        palette = palette.next();
+    }
+    assert_eq!(palette, RenderPalette::Default);
+    
+    // In high contrast every plant part and creature is far from every other, whatever its age
+    let parts = [
+        TileType::PlantStem(40, Size::Medium), TileType::PlantBranch(40, Size::Medium), TileType::PlantLeaf(40, Size::Medium),
+        TileType::PlantBud(40, Size::Medium), TileType::PlantFlower(40, Size::Medium), TileType::PlantRoot(40, Size::Medium),
+        TileType::PlantWilting(40, Size::Medium), TileType::PlantWithered(40, Size::Medium), TileType::PlantDiseased(40, Size::Medium),
+        TileType::PillbugHead(40, Size::Medium), TileType::BeetleHead(40, Size::Medium),
+    ];
+    let rgb = |tile: TileType| match tile.to_color_with(RenderPalette::HighContrast) {
+        Color::Rgb(red, green, blue) => [red, green, blue],
+        color => panic!("expected an RGB color, got {:?}", color),
+    };
+    for (i, a) in parts.iter().enumerate() {
+        for b in &parts[i + 1..] {
+            let distance: u32 = rgb(*a).iter().zip(rgb(*b)).map(|(x, y)| x.abs_diff(y) as u32).sum();
+            assert!(distance >= 100, "{:?} and {:?} are only {} apart", a, b, distance);
+        }
+    }
+    assert_eq!(rgb(TileType::PlantStem(0, Size::Small)), rgb(TileType::PlantStem(90, Size::Large)));
+    
+    let grays = [Color::Black, Color::DarkGray, Color::Gray, Color::White];
+    for tile in [TileType::Empty, TileType::Dirt, TileType::Nutrient, TileType::Water(150, CLEAN_WATER), parts[0], parts[1]] {
+        assert!(grays.contains(&tile.to_color_with(RenderPalette::Monochrome)));
+    }
+}
+
+#[test]
+fn scene_tint_follows_the_season_and_the_night() {
+    let mut world = World::new_seeded(20, 15, 1);
+    let rgb = |color: Color| match color {
+        Color::Rgb(red, green, blue) => (red as i32, green as i32, blue as i32),
+        color => panic!("expected an RGB color, got {:?}", color),
+    };
+    world.day_cycle = std::f32::consts::FRAC_PI_2;
+    world.set_season(Season::Summer);
+    let (red, _, blue) = rgb(world.ambient_tint());
+    assert!(red > blue, "summer should be warm");
+    world.set_season(Season::Winter);
+    let (red, _, blue) = rgb(world.ambient_tint());
+    assert!(blue > red, "winter should be cold");
+    
+    let brightness = |world: &World| {
+        let (red, green, blue) = rgb(world.ambient_tint());
+        red + green + blue
+    };
+    let noon = brightness(&world);
+    world.day_cycle = -std::f32::consts::FRAC_PI_2;
+    assert!(brightness(&world) < noon / 2);
+    
+    assert_eq!(blend(Color::Rgb(0, 100, 200), Color::Rgb(100, 100, 0), 0.0), Color::Rgb(0, 100, 200));
+    assert_eq!(blend(Color::Rgb(0, 100, 200), Color::Rgb(100, 100, 0), 0.25), Color::Rgb(25, 100, 150));
+    assert_eq!(blend(Color::Black, Color::Yellow, 1.0), Color::Rgb(255, 255, 0));
+}
+
+#[test]
+fn ecosystem_phase_follows_the_populations() {
+    // Stems buried in ground packed solid, and a buried pillbug with nothing to eat
+    let mut builder = WorldBuilder::new(20, 10)
+        .seed(1)
+        .fill_soil(10)
+        .place(10, 2, TileType::PillbugHead(10, Size::Medium));
+    for x in (0..20).step_by(2) {
+        builder = builder.place(x, 8, TileType::PlantStem(10, Size::Medium));
+    }
+    let mut world = builder.build();
+    world.allow_respawn = false;
+    world.set_weather_controller(Box::new(|world: &mut World| {
+        world.temperature = 0.3;
+        world.rain_intensity = 0.0;
+    }));
+    for _ in 0..100 {
+        world.update();
+    }
+    assert_eq!(world.ecosystem_phase(), EcosystemPhase::Thriving);
+    
+    // Clearing away every plant but one stem leaves a sliver of their peak
+    let mut kept_stem = false;
+    for tile in world.tiles.iter_mut().flatten() {
+        if matches!(tile, TileType::PlantStem(_, _)) && !kept_stem {
+            kept_stem = true;
+        } else if tile.is_plant() {
+            *tile = TileType::Dirt;
+        }
+    }
+    for _ in 0..10 {
+        world.update();
+    }
+    assert_eq!(world.ecosystem_phase(), EcosystemPhase::Collapsed);
+    
+    // Then the pillbug starves
+    for _ in 0..30 {
+        world.update();
+    }
+    assert_eq!(world.ecosystem_phase(), EcosystemPhase::Extinct);
+}
+
+// Stems and pillbugs the spawner brings into a lifeless world in one tick
+fn respawned(allow_respawn: bool, respawn_config: RespawnConfig) -> (usize, usize) {
+    let mut world = WorldBuilder::new(20, 10).seed(3).fill_soil(3).build();
+    world.allow_respawn = allow_respawn;
+    world.respawn_config = respawn_config;
+    world.update();
+    let stems = world.tiles.iter().flatten().filter(|t| matches!(t, TileType::PlantStem(_, _))).count();
+    let pillbugs = world.tiles.iter().flatten().filter(|t| matches!(t, TileType::PillbugHead(_, _))).count();
+    (stems, pillbugs)
+}
+
+#[test]
+fn respawning_can_be_tuned_or_turned_off() {
+    let (stems, pillbugs) = respawned(true, RespawnConfig::default());
+    assert!(stems > 0 && stems <= 3);
+    assert!(pillbugs > 0 && pillbugs <= 2);
+    
+    let (stems, _) = respawned(true, RespawnConfig { plant_target: 10, ..RespawnConfig::default() });
+    assert!(stems > 3);
+    assert_eq!(respawned(true, RespawnConfig { min_plants: 0, min_pillbugs: 0, ..RespawnConfig::default() }), (0, 0));
+    assert_eq!(respawned(false, RespawnConfig::default()), (0, 0));
+    
+    // A closed world that dies out stays dead
+    let mut world = WorldBuilder::new(20, 10).seed(3).fill_soil(3).build();
+    world.allow_respawn = false;
+    for _ in 0..500 {
+        world.update();
+    }
+    assert_eq!(world.ecosystem_phase(), EcosystemPhase::Extinct);
+}
+
+#[test]
+fn systems_can_be_switched_off_one_at_a_time() {
+    let mut flags = SystemFlags::default();
+    assert_eq!(flags, SystemFlags::ALL);
+    flags.toggle(SystemFlags::WIND);
+    flags.set(SystemFlags::RAIN, false);
+    assert!(!flags.contains(SystemFlags::WIND) && flags.contains(SystemFlags::LIFE));
+    assert_eq!(flags.disabled_names(), vec!["rain", "wind"]);
+    assert_eq!(SystemFlags::from_bits_truncate(flags.bits() | 0xC0), flags);
+    
+    // With every system off nothing moves at all
+    let mut world = World::new_seeded(40, 20, 5);
+    world.systems = SystemFlags::NONE;
+    let start = world.tiles.clone();
+    for _ in 0..50 {
+        world.update();
+    }
+    assert_eq!(world.tiles, start);
+    
+    // Without life, water settles around plants and pillbugs that neither grow nor move
+    let mut world = WorldBuilder::new(20, 10)
+        .seed(5)
+        .fill_soil(3)
+        .place(4, 6, TileType::PlantStem(10, Size::Medium))
+        .place(4, 5, TileType::PlantLeaf(10, Size::Medium))
+        .place(15, 6, TileType::PillbugHead(10, Size::Medium))
+        .place(10, 2, TileType::Water(200, CLEAN_WATER))
+        .build();
+    world.systems.set(SystemFlags::LIFE | SystemFlags::RAIN, false);
+    world.physics_config = PhysicsConfig { evaporation_rate: 0.0, absorption_rate: 0.0, ..PhysicsConfig::default() };
+    for _ in 0..50 {
+        world.update();
+    }
+    assert_eq!(world.tiles[6][4], TileType::PlantStem(10, Size::Medium));
+    assert_eq!(world.tiles[5][4], TileType::PlantLeaf(10, Size::Medium));
+    assert_eq!(world.tiles[6][15], TileType::PillbugHead(10, Size::Medium));
+    assert!(world.tiles[2][10] != TileType::Water(200, CLEAN_WATER));
+    assert!(world.tiles[6].iter().any(|t| t.is_water()));
+}
+
+#[test]
+fn describe_tile_tells_everything_about_a_cell() {
+    let mut world = WorldBuilder::new(20, 10)
+        .seed(1)
+        .fill_soil(3)
+        .place(10, 6, TileType::PlantLeaf(12, Size::Large))
+        .place(12, 6, TileType::PillbugHead(30, Size::Small))
+        .place(13, 6, TileType::Water(90, 200))
+        .build();
+    world.pillbug_states.entry((12, 6)).or_default().energy = 42;
+    
+    let leaf = world.describe_tile(10, 6);
+    assert!(leaf.starts_with("plant_leaf at (10, 6)"), "{}", leaf);
+    for fact in ["Age: 12", "Size: Large", "Species: grass", "Genome:", "Biome:", "Elevation:", "Light:", "1 pillbugs"] {
+        assert!(leaf.contains(fact), "missing {:?} in:\n{}", fact, leaf);
+    }
+    let pillbug = world.describe_tile(12, 6);
+    assert!(pillbug.contains("Energy: 42"), "{}", pillbug);
+    let water = world.describe_tile(13, 6);
+    assert!(water.contains("Depth: 90 | Quality: 200"), "{}", water);
+    assert!(!world.describe_tile(10, 2).contains("Age:"));
+}
+
+#[test]
+fn biomes_follow_the_ground_they_cover() {
+    let mut world = WorldBuilder::new(20, 10).biome(Biome::Grassland).fill_soil(3).build();
+    // A pool over the west end, sand and dead plants over the east end
+    for x in 0..7 {
+        for y in 5..7 {
+            world.tiles[y][x] = TileType::Water(200, CLEAN_WATER);
+        }
+    }
+    for x in 12..20 {
+        world.tiles[7][x] = TileType::Sand;
+        world.tiles[8][x] = TileType::Sand;
+    }
+    for x in 13..17 {
+        world.tiles[6][x] = TileType::PlantWithered(5, Size::Small);
+    }
+    
+    for _ in 0..4 {
+        world.update_biomes();
+    }
+    assert!(world.get_biome_at(3, 8) == Biome::Grassland, "one bad spell doesn't change a biome");
+    world.update_biomes();
+    assert!(world.get_biome_at(3, 8) == Biome::Wetland);
+    assert!(world.get_biome_at(15, 8) == Biome::Drylands);
+    assert!(world.get_biome_at(8, 8) == Biome::Grassland);
+    assert!(world.get_biome_at(3, 0) == Biome::Grassland, "open sky never changes");
+    
+    // Drained, the wetland dries back out to grassland
+    for x in 0..7 {
+        for y in 5..7 {
+            world.tiles[y][x] = TileType::Empty;
+        }
+    }
+    for _ in 0..5 {
+        world.update_biomes();
+    }
+    assert!(world.get_biome_at(3, 8) == Biome::Grassland);
+    assert!(world.get_biome_at(15, 8) == Biome::Drylands);
+}
+
+#[test]
+fn tiny_worlds_generate_and_run() {
+    for (width, height) in [(1, 1), (1, 6), (6, 1), (3, 2), (4, 6), (10, 8)] {
+        for seed in 0..5 {
+            let mut world = World::new_seeded(width, height, seed);
+            for _ in 0..300 {
+                world.update();
+            }
+            assert_eq!((world.width, world.height), (width, height));
+        }
+    }
+    let mut world = World::new_seeded(0, 0, 3);
+    world.update();
+    assert_eq!((world.width, world.height), (1, 1), "an empty world is widened to a single tile");
+    
+    let mut world = World::new_seeded(MIN_WORLD_WIDTH, MIN_WORLD_HEIGHT, 3);
+    assert!(world.tiles[MIN_WORLD_HEIGHT - 1].iter().any(|&tile| tile != TileType::Empty), "the smallest world still has ground");
+    for _ in 0..200 {
+        world.update();
+    }
+}
+
+#[test]
+fn notable_moments_land_in_the_event_feed() {
+    let mut builder = WorldBuilder::new(30, 12).seed(2).wind(WindMode::Deterministic { direction: 0.0, strength: 0.0 });
+    for x in 0..30 {
+        builder = builder.place(x, 11, TileType::Rock);
+    }
+    // A walled pool of 28 water tiles, and a sick plant sealed in rock on the other side
+    for y in 8..11 {
+        builder = builder.place(2, y, TileType::Rock).place(17, y, TileType::Rock);
+    }
+    for x in 3..17 {
+        builder = builder.place(x, 10, TileType::Water(200, CLEAN_WATER)).place(x, 9, TileType::Water(200, CLEAN_WATER));
+    }
+    let mut world = builder
+        .place(24, 10, TileType::PlantDiseased(0, Size::Medium))
+        .place(23, 10, TileType::Rock)
+        .place(25, 10, TileType::Rock)
+        .place(24, 9, TileType::Rock)
+        .build();
+    world.physics_config = PhysicsConfig { evaporation_rate: 0.0, absorption_rate: 0.0, ..PhysicsConfig::default() };
+    world.set_weather_controller(Box::new(|world: &mut World| world.rain_intensity = 0.0));
+    for _ in 0..10 {
+        world.update();
+    }
+    
+    let messages: Vec<String> = world.events.iter().map(|event| event.to_string()).collect();
+    assert!(messages.iter().any(|message| message.starts_with("Tick 10: A lake of 28 water tiles formed")), "{:?}", messages);
+    assert!(messages.contains(&"Tick 10: Disease broke out among the plants at (24, 10)".to_string()), "{:?}", messages);
+    
+    // Each threshold is reported once, and the feed keeps only the latest events
+    for _ in 0..10 {
+        world.update();
+    }
+    assert_eq!(world.events.iter().filter(|event| event.message.contains("lake")).count(), 1);
+    for i in 0..MAX_EVENTS {
+        world.log_event(format!("Event {}", i));
+    }
+    assert_eq!(world.events.len(), MAX_EVENTS);
+    assert_eq!(world.events[0].message, "Event 0");
+}
@@ -0,0 +1,109 @@
+use std::io::{self, Write};
+use std::net::TcpStream;
+use std::sync::mpsc;
+use std::thread;
+use pillbugplants::builder::WorldBuilder;
+use pillbugplants::export::WorldSnapshot;
+use pillbugplants::server::{FrameServer, FrameFormat, FramePacing, ListenEvent};
+use pillbugplants::world::World;
+
+// Split a stream of length-prefixed frames
+fn frames(mut stream: &[u8]) -> Vec<&[u8]> {
+    let mut frames = Vec::new();
+    while !stream.is_empty() {
+        let len = u32::from_be_bytes(stream[..4].try_into().unwrap()) as usize;
+        frames.push(&stream[4..4 + len]);
+        stream = &stream[4 + len..];
+    }
+    frames
+}
+
+#[test]
+fn on_demand_commands_advance_and_send_frames() {
+    let world = WorldBuilder::new(12, 6).seed(2).fill_soil(2).build();
+    let mut server = FrameServer::new(world, FrameFormat::Json);
+    let mut output = Vec::new();
+    
+    server.serve(&b"frame\nnext\n\nnext 4\nquit\nnext\n"[..], &mut output, FramePacing::OnDemand).unwrap();
+    
+    let frames = frames(&output);
+    assert_eq!(frames.len(), 3);
+    for (frame, tick) in frames.iter().zip([0, 1, 5]) {
+        let json = std::str::from_utf8(frame).unwrap();
+        assert!(json.contains(&format!("\"tick\": {},", tick)));
+    }
+    assert_eq!(server.world().tick, 5);
+}
+
+#[test]
+fn binary_frames_carry_header_and_cells() {
+    let world = WorldBuilder::new(12, 6).seed(2).fill_soil(2).build();
+    let bytes = world.to_frame_bytes();
+    
+    assert_eq!(&bytes[..5], b"PBPF\x01");
+    assert_eq!(u32::from_be_bytes(bytes[5..9].try_into().unwrap()), 12);
+    assert_eq!(u32::from_be_bytes(bytes[9..13].try_into().unwrap()), 6);
+    assert_eq!(u64::from_be_bytes(bytes[13..21].try_into().unwrap()), 0);
+    assert_eq!(bytes.len(), 21 + 12 * 6 * 2);
+    // Bottom-left cell is dirt
+    let last_row = 21 + 12 * 5 * 2;
+    assert_eq!(bytes[last_row], 1);
+}
+
+#[test]
+fn unknown_commands_are_rejected() {
+    let world = WorldBuilder::new(12, 6).fill_soil(2).build();
+    let mut server = FrameServer::new(world, FrameFormat::Binary);
+    
+    assert!(server.serve(&b"jump\n"[..], Vec::new(), FramePacing::OnDemand).is_err());
+}
+
+#[test]
+fn listen_reports_its_address_and_failed_clients() {
+    let world = WorldBuilder::new(12, 6).fill_soil(2).build();
+    let (sender, receiver) = mpsc::channel();
+    // The listener never returns on its own; it's left running when the test ends
+    thread::spawn(move || {
+        let mut server = FrameServer::new(world, FrameFormat::Binary);
+        server.listen("127.0.0.1:0", FramePacing::OnDemand, |event| sender.send(event).unwrap())
+    });
+    
+    let addr = match receiver.recv().unwrap() {
+        ListenEvent::Listening(addr) => addr,
+        event => panic!("Expected the bound address first, got {:?}", event),
+    };
+    let mut client = TcpStream::connect(addr).unwrap();
+    client.write_all(b"jump\n").unwrap();
+    match receiver.recv().unwrap() {
+        ListenEvent::ClientFailed(peer, err) => {
+            assert_eq!(peer, client.local_addr().unwrap());
+            assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        }
+        event => panic!("Expected the client to fail, got {:?}", event),
+    }
+}
+
+#[test]
+fn worlds_run_on_a_background_thread_and_send_snapshots_back() {
+    fn shareable<T: Send + Sync>() {}
+    shareable::<World>();
+    
+    let mut world = WorldBuilder::new(20, 10).seed(3).fill_soil(3).build();
+    let mut expected = WorldBuilder::new(20, 10).seed(3).fill_soil(3).build();
+    let (sender, receiver) = mpsc::channel();
+    let simulation = thread::spawn(move || {
+        for _ in 0..5 {
+            world.update();
+            sender.send(world.clone_snapshot()).unwrap();
+        }
+    });
+    
+    let snapshots: Vec<WorldSnapshot> = receiver.iter().collect();
+    simulation.join().unwrap();
+    assert_eq!(snapshots.len(), 5);
+    for snapshot in &snapshots {
+        expected.update();
+        assert_eq!(snapshot, &expected.clone_snapshot());
+    }
+    assert_eq!((snapshots[4].tick, snapshots[4].width, snapshots[4].height), (5, 20, 10));
+}
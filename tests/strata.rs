@@ -0,0 +1,81 @@
+use std::io;
+use pillbugplants::builder::WorldBuilder;
+use pillbugplants::config::WorldConfig;
+use pillbugplants::types::{TileType, SoilLayer, Biome};
+use pillbugplants::world::{World, parse_biome_map};
+
+#[test]
+fn generated_terrain_is_layered() {
+    let world = WorldBuilder::new(40, 30).seed(11).biome(Biome::Grassland).build();
+    
+    let layer_row = |y: usize| (0..world.width).filter_map(|x| world.soil_layer_at(x, y)).collect::<Vec<_>>();
+    assert!(layer_row(world.height - 1).iter().all(|l| *l == SoilLayer::ParentRock));
+    assert!(layer_row(world.height - 6).iter().all(|l| *l == SoilLayer::Subsoil));
+    
+    let bottom = &world.tiles[world.height - 1];
+    assert!(bottom.iter().all(|t| matches!(t, TileType::Rock | TileType::Clay | TileType::PlantRoot(_, _))));
+}
+
+#[test]
+fn falling_sand_keeps_its_layer() {
+    let mut world = WorldBuilder::new(10, 10)
+        .seed(2)
+        .fill_soil(2)
+        .place(4, 2, TileType::Sand)
+        .build();
+    world.soil_layers[2][4] = Some(SoilLayer::Subsoil);
+    
+    world.update();
+    
+    assert_eq!(world.tiles[3][4], TileType::Sand);
+    assert_eq!(world.soil_layer_at(4, 3), Some(SoilLayer::Subsoil));
+    assert_eq!(world.soil_layer_at(4, 2), None);
+}
+
+#[test]
+fn world_config_shapes_the_starting_world() {
+    let count = |world: &World, pred: fn(&TileType) -> bool| world.tiles.iter().flatten().filter(|t| pred(t)).count();
+    let is_plant = |t: &TileType| matches!(t, TileType::PlantStem(_, _) | TileType::PlantRoot(_, _));
+    let is_pillbug = |t: &TileType| matches!(t, TileType::PillbugHead(_, _) | TileType::PillbugBody(_, _) | TileType::PillbugLegs(_, _));
+    
+    let barren = World::new_seeded_with_config(40, 30, 5, WorldConfig {
+        initial_plants: 0,
+        initial_pillbugs: 0,
+        initial_nutrients: 0,
+        ..WorldConfig::default()
+    });
+    assert_eq!(count(&barren, is_plant), 0);
+    assert_eq!(count(&barren, is_pillbug), 0);
+    assert_eq!(count(&barren, |t| *t == TileType::Nutrient), 0);
+    
+    let lush = World::new_seeded_with_config(40, 30, 5, WorldConfig { initial_plants: 60, plant_density: 1.0, ..WorldConfig::default() });
+    let default = World::new_seeded(40, 30, 5);
+    assert!(count(&lush, is_plant) > count(&default, is_plant));
+    
+    // Terrain reaches only as high as the soil depth
+    let shallow = World::new_seeded_with_config(40, 30, 5, WorldConfig { soil_depth: 4, ..WorldConfig::default() });
+    let first_soil_row = |world: &World| (0..world.height).find(|&y| (0..world.width).any(|x| world.soil_layer_at(x, y).is_some())).unwrap();
+    assert!(first_soil_row(&shallow) >= 30 - 5);
+    assert!(first_soil_row(&default) <= 30 - 10);
+}
+
+#[test]
+fn biome_maps_lay_out_biomes_from_letters() {
+    let text = "WWWWWWDDDDDD\n".repeat(16);
+    let map = parse_biome_map(&text).unwrap();
+    let world = WorldBuilder::new(12, 16).seed(3).biome_map(map.clone()).build();
+    assert!(world.get_biome_at(0, 10) == Biome::Wetland);
+    assert!(world.get_biome_at(11, 10) == Biome::Drylands);
+    assert!(world.tiles.iter().flatten().any(|tile| tile.is_ground()), "no terrain was generated");
+    
+    let mut other = World::new_seeded(12, 16, 3);
+    other.set_biome_map(map).unwrap();
+    assert!(other.biome_map == world.biome_map);
+    let err = other.set_biome_map(vec![vec![Biome::Grassland; 12]; 15]).err().unwrap();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    
+    assert!(parse_biome_map("gf\r\n\nwd\n").unwrap() == vec![vec![Biome::Grassland, Biome::Woodland], vec![Biome::Wetland, Biome::Drylands]]);
+    assert!(parse_biome_map("WG\nWX\n").err().unwrap().to_string().contains("'X' at row 2, column 2"));
+    assert!(parse_biome_map("WGD\nWG\n").is_err());
+    assert!(parse_biome_map("\n").is_err());
+}
@@ -0,0 +1,40 @@
+use std::collections::HashSet;
+use std::io;
+use ratatui::style::Color;
+use pillbugplants::app::App;
+use pillbugplants::builder::WorldBuilder;
+use pillbugplants::types::{TileType, Size, parse_color_theme};
+
+#[test]
+fn legend_lists_every_tile_kind_as_drawn() {
+    let tiles = TileType::all_variants();
+    assert_eq!(tiles.len(), TileType::NAMES.len());
+    assert!(tiles.iter().enumerate().all(|(id, tile)| tile.tile_id() as usize == id));
+    
+    let mut descriptions = HashSet::new();
+    for tile in tiles {
+        let (ch, color, description) = tile.legend_entry();
+        assert_eq!((ch, color), (tile.to_char(), tile.to_color()));
+        assert!(descriptions.insert(description), "{} shares a description", tile.name());
+    }
+    
+    // Water is shown at an ordinary depth, not as a droplet
+    assert_eq!(TileType::all_variants()[4].legend_entry().0, '~');
+}
+
+#[test]
+fn color_themes_recolor_only_the_tiles_they_name() {
+    let theme = parse_color_theme("# Leaves a little bluer\n\nplant_leaf = 40, 180, 90\nwater=0,0,255\n").unwrap();
+    assert_eq!(theme.len(), 2);
+    assert_eq!(TileType::PlantLeaf(10, Size::Large).to_color_themed(&theme), Color::Rgb(40, 180, 90));
+    assert_eq!(TileType::Water(10, 0).to_color_themed(&theme), Color::Rgb(0, 0, 255));
+    assert_eq!(TileType::Dirt.to_color_themed(&theme), TileType::Dirt.to_color());
+    
+    for bad in ["plant_leaf", "leaf=1,2,3", "plant_leaf=1,2", "plant_leaf=1,2,300"] {
+        assert_eq!(parse_color_theme(bad).err().unwrap().kind(), io::ErrorKind::InvalidData, "{}", bad);
+    }
+    
+    let mut app = App::with_world(WorldBuilder::new(10, 5).fill_soil(2).build());
+    assert!(app.load_palette("no/such/palette.txt").is_err());
+    assert!(app.theme.is_empty());
+}
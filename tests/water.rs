@@ -0,0 +1,513 @@
+use std::sync::{Arc, Mutex};
+use pillbugplants::builder::WorldBuilder;
+use pillbugplants::config::PhysicsConfig;
+use pillbugplants::types::{Biome, CLEAN_WATER, TileType, Size, WindMode};
+use pillbugplants::world::World;
+
+// Water layer of the grid: depth per cell, None where there's no water
+fn water_layer(world: &World) -> Vec<Option<u8>> {
+    world.tiles.iter().flatten().map(|t| t.get_water_depth()).collect()
+}
+
+// A dirt basin with rock walls on both world edges, no rain, no evaporation, no soaking, and the
+// given wind strength
+fn sealed_basin(wind_strength: f32) -> World {
+    let (width, height) = (30, 20);
+    let mut builder = WorldBuilder::new(width, height).seed(4).fill_soil(4);
+    for y in 4..height {
+        builder = builder.place(0, y, TileType::Rock).place(width - 1, y, TileType::Rock);
+    }
+    // Water pressed against both walls plus some falling from above
+    for x in 1..6 {
+        builder = builder.place(x, 15, TileType::Water(200, CLEAN_WATER)).place(width - 1 - x, 15, TileType::Water(250, CLEAN_WATER));
+    }
+    for x in (8..22).step_by(3) {
+        builder = builder.place(x, 2, TileType::Water(90, CLEAN_WATER)).place(x, 8, TileType::Water(40, CLEAN_WATER));
+    }
+    
+    let mut world = builder.build();
+    world.physics_config = PhysicsConfig { evaporation_rate: 0.0, absorption_rate: 0.0, ..PhysicsConfig::default() };
+    // Keep the emergency spawner from dropping life into the basin
+    world.allow_respawn = false;
+    world.set_weather_controller(Box::new(move |world: &mut World| {
+        world.rain_intensity = 0.0;
+        world.wind_strength = wind_strength;
+    }));
+    world
+}
+
+#[test]
+fn sealed_basin_conserves_water() {
+    let mut world = sealed_basin(0.0);
+    let initial = world.total_water();
+    assert!(initial > 0);
+    
+    for tick in 0..1000 {
+        world.update();
+        assert_eq!(world.total_water(), initial, "water changed at tick {}", tick);
+    }
+}
+
+#[test]
+fn windblown_droplets_and_seeds_neither_make_nor_lose_water() {
+    let mut world = sealed_basin(1.0);
+    world.temperature = 0.3;
+    // Light droplets and seeds up in the gale, some of them landing on the shallow pools
+    for x in 3..27 {
+        world.tiles[3][x] = if x % 2 == 0 { TileType::Water(20, CLEAN_WATER) } else { TileType::Seed(0, Size::Small) };
+        world.tiles[7][x] = TileType::Water(15 + x as u8, CLEAN_WATER);
+    }
+    let initial = world.total_water();
+    
+    for tick in 0..300 {
+        world.update();
+        assert_eq!(world.total_water(), initial, "water changed at tick {}", tick);
+    }
+}
+
+#[test]
+fn settled_basin_water_is_still() {
+    let mut world = sealed_basin(0.0);
+    for _ in 0..200 {
+        world.update();
+    }
+    
+    let settled = water_layer(&world);
+    for tick in 0..50 {
+        world.update();
+        assert_eq!(water_layer(&world), settled, "settled water moved {} ticks later", tick + 1);
+    }
+}
+
+// A row of roots under a flat sheet of water of the given quality, in still, dry-soaking-free weather
+fn pond_over_roots(quality: u8) -> World {
+    let (width, height) = (30, 12);
+    let mut builder = WorldBuilder::new(width, height).seed(4).fill_soil(4);
+    for x in 0..width {
+        builder = builder.place(x, 8, TileType::PlantRoot(10, Size::Medium)).place(x, 7, TileType::Water(60, quality));
+    }
+    
+    let mut world = builder.build();
+    world.physics_config = PhysicsConfig { evaporation_rate: 0.0, absorption_rate: 0.0, ..PhysicsConfig::default() };
+    world.allow_respawn = false;
+    world.set_weather_controller(Box::new(|world: &mut World| {
+        world.rain_intensity = 0.0;
+        world.wind_strength = 0.0;
+    }));
+    world
+}
+
+fn roots_sickened(mut world: World) -> usize {
+    let mut sickened = 0;
+    for _ in 0..40 {
+        world.update();
+        sickened += world.tiles[8].iter().filter(|t| matches!(t, TileType::PlantDiseased(_, _))).count();
+    }
+    sickened
+}
+
+#[test]
+fn merging_water_mixes_quality_by_depth() {
+    let mut builder = WorldBuilder::new(3, 6).seed(4).fill_soil(1);
+    for y in 0..5 {
+        builder = builder.place(0, y, TileType::Rock).place(2, y, TileType::Rock);
+    }
+    let mut world = builder
+        .place(1, 3, TileType::Water(50, CLEAN_WATER))
+        .place(1, 4, TileType::Water(200, 0))
+        .build();
+    world.physics_config = PhysicsConfig { evaporation_rate: 0.0, absorption_rate: 0.0, ..PhysicsConfig::default() };
+    world.set_weather_controller(Box::new(|world: &mut World| {
+        world.rain_intensity = 0.0;
+        world.wind_strength = 0.0;
+    }));
+    
+    world.update();
+    
+    // 200 parts foul to 50 parts clean, give or take a tick of clearing
+    let TileType::Water(depth, quality) = world.tiles[4][1] else { panic!("no water at the bottom: {:?}", world.tiles[4][1]) };
+    assert_eq!(depth, 250);
+    assert!((50..=53).contains(&quality), "mixed quality {}", quality);
+}
+
+#[test]
+fn foul_water_sickens_roots() {
+    let foul = roots_sickened(pond_over_roots(0));
+    let clean = roots_sickened(pond_over_roots(CLEAN_WATER));
+    
+    assert!(foul > 5, "foul water sickened only {} roots", foul);
+    assert!(clean < foul / 4, "clean water sickened {} roots vs {} foul", clean, foul);
+}
+
+#[test]
+fn thirsty_roots_drain_pools() {
+    let mut world = pond_over_roots(CLEAN_WATER);
+    let initial = world.total_water();
+    for _ in 0..20 {
+        world.update();
+    }
+    
+    // Without roots the sheet would hold its volume: nothing evaporates or soaks in
+    assert!(world.total_water() < initial / 2, "roots drank only {} of {}", initial - world.total_water(), initial);
+}
+
+#[test]
+fn water_collects_in_valleys() {
+    let (width, height) = (31, 12);
+    // Drylands, so the sheet of water doesn't turn the ground to pooling wetland as it runs off
+    let mut builder = WorldBuilder::new(width, height).seed(4).biome(Biome::Drylands);
+    for x in 0..width {
+        builder = builder.place(x, 10, TileType::Rock).place(x, 11, TileType::Rock).place(x, 9, TileType::Water(30, CLEAN_WATER));
+    }
+    
+    let mut world = builder.build();
+    // A V-shaped valley with its floor in the middle column
+    for row in world.elevation.iter_mut() {
+        for (x, e) in row.iter_mut().enumerate() {
+            *e = (x as i32 - 15).unsigned_abs() as u8 * 6;
+        }
+    }
+    world.physics_config = PhysicsConfig { evaporation_rate: 0.0, absorption_rate: 0.0, ..PhysicsConfig::default() };
+    world.allow_respawn = false;
+    world.set_weather_controller(Box::new(|world: &mut World| {
+        world.rain_intensity = 0.0;
+        world.wind_strength = 0.0;
+    }));
+    for _ in 0..1000 {
+        world.update();
+    }
+    
+    let depth_at = |x: usize| world.tiles[9][x].get_water_depth().unwrap_or(0) as u32;
+    let valley: u32 = (12..19).map(depth_at).sum();
+    let slopes: u32 = (0..7).chain(24..31).map(depth_at).sum();
+    assert!(valley > slopes * 2, "valley holds {} vs {} on the slopes", valley, slopes);
+}
+
+#[test]
+fn seeds_float_on_water_without_displacing_it() {
+    let (width, height) = (20, 10);
+    let mut builder = WorldBuilder::new(width, height).seed(4);
+    for x in 0..width {
+        builder = builder.place(x, 8, TileType::Rock).place(x, 9, TileType::Rock);
+    }
+    builder = builder.place(4, 7, TileType::Rock).place(11, 7, TileType::Rock);
+    for x in 5..11 {
+        builder = builder.place(x, 7, TileType::Water(100, CLEAN_WATER));
+    }
+    
+    let mut world = builder.build();
+    world.physics_config = PhysicsConfig { evaporation_rate: 0.0, absorption_rate: 0.0, ..PhysicsConfig::default() };
+    world.allow_respawn = false;
+    world.set_weather_controller(Box::new(|world: &mut World| {
+        world.rain_intensity = 0.0;
+        world.wind_strength = 0.0;
+    }));
+    let initial = world.total_water();
+    // Two seeds on the same path reach the pond in the same tick
+    world.launch_seed(7.5, 1.0, 0.0, 1.0, Size::Medium);
+    world.launch_seed(7.5, 1.0, 0.0, 1.0, Size::Medium);
+    for _ in 0..10 {
+        world.update();
+    }
+    
+    assert_eq!(world.get_projectile_count(), 0);
+    assert_eq!(world.total_water(), initial);
+    assert!(matches!(world.tiles[6][7], TileType::Seed(_, Size::Medium)));
+    let seeds = world.tiles.iter().flatten().filter(|t| matches!(t, TileType::Seed(_, _))).count();
+    assert_eq!(seeds, 1, "only one seed can take the spot on the surface");
+}
+
+// Deep water against the west border of a flat rock floor, optionally joined to the east border
+fn water_at_the_west_border(wrap_horizontal: bool) -> World {
+    let (width, height) = (40, 8);
+    let mut builder = WorldBuilder::new(width, height).seed(4).wrap_horizontal(wrap_horizontal);
+    for x in 0..width {
+        builder = builder.place(x, 6, TileType::Rock).place(x, 7, TileType::Rock);
+    }
+    for x in 0..3 {
+        builder = builder.place(x, 5, TileType::Water(250, CLEAN_WATER));
+    }
+    
+    let mut world = builder.build();
+    world.physics_config = PhysicsConfig { evaporation_rate: 0.0, absorption_rate: 0.0, ..PhysicsConfig::default() };
+    world.allow_respawn = false;
+    world.set_weather_controller(Box::new(|world: &mut World| {
+        world.rain_intensity = 0.0;
+        world.wind_strength = 0.0;
+    }));
+    world
+}
+
+#[test]
+fn water_flows_across_a_wrapped_border() {
+    let mut wrapped = water_at_the_west_border(true);
+    let mut walled = water_at_the_west_border(false);
+    let initial = wrapped.total_water();
+    
+    for _ in 0..10 {
+        wrapped.update();
+        walled.update();
+    }
+    
+    assert_eq!(wrapped.total_water(), initial);
+    assert!(wrapped.tiles[5][39].is_water(), "water should spill over the west border into the east");
+    assert!(!walled.tiles[5][39].is_water());
+}
+
+// A rock-walled pond three full cells deep, no rain or wind, at whatever temperature the cell holds
+fn pond(temperature: Arc<Mutex<f32>>) -> World {
+    let mut builder = WorldBuilder::new(20, 14).seed(4).fill_soil(3);
+    for y in 6..11 {
+        builder = builder.place(4, y, TileType::Rock).place(15, y, TileType::Rock);
+    }
+    for x in 5..15 {
+        builder = builder.place(x, 11, TileType::Rock);
+        for y in 8..11 {
+            builder = builder.place(x, y, TileType::Water(255, CLEAN_WATER));
+        }
+    }
+    let mut world = builder.build();
+    world.physics_config = PhysicsConfig { evaporation_rate: 0.0, absorption_rate: 0.0, ..PhysicsConfig::default() };
+    world.allow_respawn = false;
+    world.set_weather_controller(Box::new(move |world: &mut World| {
+        world.rain_intensity = 0.0;
+        world.wind_strength = 0.0;
+        world.temperature = *temperature.lock().unwrap();
+        // Every pillbug is kept fed, so the one on the ice lasts until the thaw
+        for state in world.pillbug_states.values_mut() {
+            state.energy = 100;
+        }
+    }));
+    world
+}
+
+#[test]
+fn ponds_ice_over_at_the_surface_and_drop_pillbugs_in_when_they_thaw() {
+    let temperature = Arc::new(Mutex::new(-1.0));
+    let mut world = pond(temperature.clone());
+    for _ in 0..300 {
+        world.update();
+    }
+    for x in 5..15 {
+        assert!(matches!(world.tiles[8][x], TileType::Ice(_)), "surface at column {} is {:?}", x, world.tiles[8][x]);
+        assert!(world.tiles[9][x].is_water() && world.tiles[10][x].is_water(), "deep water froze at column {}", x);
+    }
+    
+    // A pillbug walks out onto the ice and stays up while it holds
+    world.tiles[7][9] = TileType::PillbugHead(10, Size::Medium);
+    world.tiles[7][10] = TileType::PillbugBody(10, Size::Medium);
+    world.tiles[7][11] = TileType::PillbugLegs(10, Size::Medium);
+    for _ in 0..20 {
+        world.update();
+    }
+    assert!((5..15).any(|x| world.tiles[7][x].is_pillbug()), "the pillbug left the ice");
+    
+    *temperature.lock().unwrap() = 0.8;
+    for _ in 0..200 {
+        world.update();
+    }
+    assert!(world.tiles.iter().flatten().all(|tile| !matches!(tile, TileType::Ice(_))), "ice left after the thaw");
+    let sunk = (8..11).any(|y| (5..15).any(|x| world.tiles[y][x].is_pillbug()));
+    assert!(sunk, "the pillbug didn't fall into the pond");
+}
+
+#[test]
+fn poured_water_spreads_evenly_both_ways() {
+    // Water poured into the middle of a walled, level basin should spread as far left as right;
+    // sweeping the rows one way only let it run further toward the side visited last
+    let mut drift = 0.0;
+    let seeds = 1..=8;
+    for seed in seeds.clone() {
+        let mut builder = WorldBuilder::new(40, 12)
+            .seed(seed)
+            .fill_soil(2);
+        for y in 0..10 {
+            builder = builder.place(0, y, TileType::Rock).place(39, y, TileType::Rock);
+        }
+        let mut world = builder.build();
+        world.physics_config = PhysicsConfig { evaporation_rate: 0.0, absorption_rate: 0.0, ..PhysicsConfig::default() };
+        world.allow_respawn = false;
+        world.set_weather_controller(Box::new(|world: &mut World| {
+            world.rain_intensity = 0.0;
+            world.wind_strength = 0.0;
+            world.tiles[0][19] = TileType::Water(255, CLEAN_WATER);
+            world.tiles[0][20] = TileType::Water(255, CLEAN_WATER);
+        }));
+        for _ in 0..200 {
+            world.update();
+        }
+        
+        // Depth-weighted centre of the water, relative to the middle of the basin
+        let (mut total, mut moment) = (0.0, 0.0);
+        for row in &world.tiles {
+            for (x, tile) in row.iter().enumerate() {
+                if let Some(depth) = tile.get_water_depth() {
+                    total += depth as f64;
+                    moment += depth as f64 * x as f64;
+                }
+            }
+        }
+        drift += moment / total - 19.5;
+    }
+    drift /= seeds.count() as f64;
+    assert!(drift.abs() < 0.25, "poured water drifted {:.2} tiles off centre on average", drift);
+}
+
+// A reservoir of deep water on a rock floor, held back by a one-tile wall with open ground beyond
+fn dammed(wall: TileType) -> World {
+    let mut builder = WorldBuilder::new(20, 10).seed(6);
+    for x in 0..20 {
+        builder = builder.place(x, 9, TileType::Rock);
+    }
+    for y in 2..9 {
+        builder = builder.place(0, y, TileType::Rock);
+    }
+    for y in 5..9 {
+        for x in 1..5 {
+            builder = builder.place(x, y, TileType::Water(250, CLEAN_WATER));
+        }
+        builder = builder.place(5, y, wall);
+    }
+    let mut world = builder.build();
+    world.physics_config = PhysicsConfig { evaporation_rate: 0.0, absorption_rate: 0.0, ..PhysicsConfig::default() };
+    world.allow_respawn = false;
+    world.set_weather_controller(Box::new(|world: &mut World| {
+        world.rain_intensity = 0.0;
+        world.wind_strength = 0.0;
+    }));
+    world
+}
+
+fn escaped(world: &World) -> bool {
+    world.tiles.iter().any(|row| row[6..].iter().any(|t| t.is_water()))
+}
+
+#[test]
+fn deep_water_bursts_thin_earthen_dams() {
+    let mut burst = dammed(TileType::Dirt);
+    let initial = burst.total_water();
+    let mut ticks = 0;
+    while !escaped(&burst) && ticks < 1000 {
+        burst.update();
+        ticks += 1;
+    }
+    assert!(escaped(&burst), "the dirt dam held");
+    assert_eq!(burst.total_water(), initial);
+    
+    let mut held = dammed(TileType::Rock);
+    for _ in 0..ticks.max(200) {
+        held.update();
+    }
+    assert!(!escaped(&held), "water got through rock");
+}
+
+// A reservoir emptying over a bed of sand on a rock floor, into an open basin beyond it
+fn sandy_stream() -> World {
+    let mut builder = WorldBuilder::new(40, 10).seed(4);
+    for x in 0..40 {
+        builder = builder.place(x, 9, TileType::Rock);
+    }
+    for y in 2..9 {
+        builder = builder.place(0, y, TileType::Rock);
+    }
+    for x in 1..13 {
+        builder = builder.place(x, 8, TileType::Sand);
+    }
+    for y in 3..8 {
+        for x in 1..6 {
+            builder = builder.place(x, y, TileType::Water(250, CLEAN_WATER));
+        }
+    }
+    let mut world = builder.build();
+    world.physics_config = PhysicsConfig { evaporation_rate: 0.0, absorption_rate: 0.0, ..PhysicsConfig::default() };
+    world.allow_respawn = false;
+    world.set_weather_controller(Box::new(|world: &mut World| {
+        world.rain_intensity = 0.0;
+        world.wind_strength = 0.0;
+    }));
+    world
+}
+
+fn count(world: &World, columns: std::ops::Range<usize>, kind: impl Fn(TileType) -> bool) -> usize {
+    world.tiles.iter().map(|row| row[columns.clone()].iter().filter(|&&t| kind(t)).count()).sum()
+}
+
+#[test]
+fn flowing_water_carries_sand_downstream() {
+    let mut world = sandy_stream();
+    let initial = world.total_water();
+    let sandy = |t: TileType| matches!(t, TileType::Sand | TileType::Sediment(_));
+    let mut suspended = false;
+    for _ in 0..400 {
+        world.update();
+        suspended |= count(&world, 0..40, |t| matches!(t, TileType::Sediment(_))) > 0;
+        // Only a burst dam can wash sand away for good
+        assert!(count(&world, 0..40, sandy) <= 12, "sand was created");
+    }
+    
+    assert!(suspended, "no sand was ever lifted");
+    assert!(world.tiles[8][1..13].iter().any(|t| t.is_water()), "no channel was cut into the bed");
+    assert!(count(&world, 13..40, sandy) > 0, "no sand was carried past the end of the bed");
+    assert_eq!(world.total_water(), initial);
+}
+
+fn algae_count(world: &World) -> usize {
+    world.tiles.iter().flatten().filter(|t| matches!(t, TileType::Algae(_))).count()
+}
+
+#[test]
+fn nutrient_runoff_blooms_algae_that_dies_back_as_the_water_clears() {
+    let mut clean = pond(Arc::new(Mutex::new(0.5)));
+    let mut fed = pond(Arc::new(Mutex::new(0.5)));
+    // Loose nutrients heaped on the pond, to wash into it
+    for x in 5..15 {
+        for y in 5..8 {
+            fed.tiles[y][x] = TileType::Nutrient;
+        }
+    }
+    
+    let mut bloom = 0;
+    for _ in 0..400 {
+        clean.update();
+        fed.update();
+        assert_eq!(algae_count(&clean), 0, "algae grew on clean water");
+        bloom = bloom.max(algae_count(&fed));
+    }
+    
+    assert!(bloom >= 3, "the fed pond only grew {} algae", bloom);
+    // The bloom has used up the nutrients and died away, and the water has cleared
+    assert_eq!(algae_count(&fed), 0);
+    for x in 5..15 {
+        assert!(fed.tiles[8][x].get_water_quality().is_some_and(|quality| quality > 200), "column {} is {:?}", x, fed.tiles[8][x]);
+    }
+}
+
+// A trench of clean water over a bed of roots, with or without a mat of algae on it
+fn roots_under(algae: bool) -> usize {
+    let mut builder = WorldBuilder::new(20, 10).seed(9).fill_soil(3)
+        .wind(WindMode::Deterministic { direction: 0.0, strength: 0.0 })
+        .place(4, 7, TileType::Rock)
+        .place(15, 7, TileType::Rock);
+    for x in 5..15 {
+        builder = builder.place(x, 7, TileType::Water(100, CLEAN_WATER)).place(x, 8, TileType::PlantRoot(10, Size::Medium));
+        if algae {
+            builder = builder.place(x, 6, TileType::Algae(0));
+        }
+    }
+    let mut world = builder.build();
+    world.physics_config = PhysicsConfig { evaporation_rate: 0.0, absorption_rate: 0.0, ..PhysicsConfig::default() };
+    world.set_weather_controller(Box::new(|world: &mut World| {
+        world.temperature = 0.5;
+        world.rain_intensity = 0.0;
+    }));
+    for _ in 0..50 {
+        world.update();
+    }
+    world.tiles[8][5..15].iter().filter(|t| matches!(t, TileType::PlantRoot(_, _))).count()
+}
+
+#[test]
+fn algae_suffocates_the_roots_under_it() {
+    let (smothered, open) = (roots_under(true), roots_under(false));
+    assert!(smothered * 2 < open, "{} roots lived under algae, {} under open water", smothered, open);
+}
@@ -0,0 +1,236 @@
+use pillbugplants::builder::WorldBuilder;
+use pillbugplants::weather::{WeatherController, SeasonalWeather};
+use pillbugplants::types::{TileType, Season, SystemFlags, WindMode};
+use pillbugplants::world::World;
+
+// Scripted timeline: dry spell followed by heavy rain
+struct DroughtThenMonsoon {
+    drought_ticks: u64,
+}
+
+impl WeatherController for DroughtThenMonsoon {
+    fn update_weather(&mut self, world: &mut World) {
+        if world.tick <= self.drought_ticks {
+            world.humidity = 0.1;
+            world.rain_intensity = 0.0;
+        } else {
+            world.humidity = 1.0;
+            world.rain_intensity = 0.9;
+        }
+    }
+}
+
+fn water_tiles(world: &World) -> usize {
+    world.tiles.iter().flatten().filter(|t| t.is_water()).count()
+}
+
+#[test]
+fn scripted_timeline_drives_weather() {
+    let mut world = WorldBuilder::new(30, 15).seed(4).fill_soil(3).build();
+    world.set_weather_controller(Box::new(DroughtThenMonsoon { drought_ticks: 50 }));
+    
+    for _ in 0..50 {
+        world.update();
+        assert_eq!(world.rain_intensity, 0.0);
+        assert_eq!(water_tiles(&world), 0);
+    }
+    
+    for _ in 0..20 {
+        world.update();
+    }
+    assert_eq!(world.humidity, 1.0);
+    assert!(water_tiles(&world) > 0);
+}
+
+#[test]
+fn seasonal_weather_is_the_default() {
+    let seed = 8;
+    let mut default_world = WorldBuilder::new(30, 15).seed(seed).fill_soil(3).build();
+    let mut explicit_world = WorldBuilder::new(30, 15).seed(seed).fill_soil(3).build();
+    explicit_world.set_weather_controller(Box::new(SeasonalWeather));
+    
+    for _ in 0..100 {
+        default_world.update();
+        explicit_world.update();
+    }
+    
+    assert_eq!(default_world.humidity, explicit_world.humidity);
+    assert_eq!(default_world.tiles, explicit_world.tiles);
+}
+
+#[test]
+fn dry_spell_builds_drought_stress_until_it_rains() {
+    let mut world = WorldBuilder::new(30, 15).seed(4).fill_soil(3).build();
+    world.drought_config.stress_after = 20;
+    world.drought_config.full_stress_after = 10;
+    world.set_weather_controller(Box::new(DroughtThenMonsoon { drought_ticks: 40 }));
+    
+    for _ in 0..20 {
+        world.update();
+    }
+    assert_eq!(world.ticks_since_rain, 20);
+    assert_eq!(world.drought_stress(), 0.0);
+    
+    for _ in 0..5 {
+        world.update();
+    }
+    assert_eq!(world.drought_stress(), 0.5);
+    
+    for _ in 0..15 {
+        world.update();
+    }
+    assert_eq!(world.drought_stress(), 1.0);
+    assert_eq!(world.calculate_ecosystem_stats().ticks_since_rain, 40);
+    
+    world.update();
+    assert_eq!(world.ticks_since_rain, 0);
+    assert_eq!(world.drought_stress(), 0.0);
+}
+
+#[test]
+fn standing_water_keeps_the_air_humid() {
+    let mut dry = WorldBuilder::new(30, 15).seed(6).fill_soil(3).build();
+    let mut wet = (0..30).fold(WorldBuilder::new(30, 15).seed(6).fill_soil(3), |builder, x| {
+        builder.place(x, 11, TileType::Water(200, 255))
+    }).build();
+    // Keep the lake from soaking away so only the air differs
+    dry.physics_config.absorption_rate = 0.0;
+    wet.physics_config.absorption_rate = 0.0;
+    assert_eq!(dry.surface_water_fraction(), 0.0);
+    assert_eq!(wet.surface_water_fraction(), 1.0);
+    
+    let mut evaporated = 0;
+    for _ in 0..200 {
+        dry.update();
+        wet.update();
+        evaporated += wet.evaporated;
+    }
+    
+    // The lake both evaporates into the air and pulls the seasonal target up
+    assert!(evaporated > 0);
+    assert!(wet.humidity > dry.humidity + 0.05, "wet {} vs dry {}", wet.humidity, dry.humidity);
+}
+
+#[test]
+fn long_seeded_runs_never_roll_an_empty_rain_range() {
+    // Humidity can sit at its floor, where the heaviest possible shower used
+    // to fall below the lightest and the rain roll panicked. Only the weather
+    // and the water feeding the air matter here, so the rest stay off.
+    let mut world = World::new_seeded(120, 50, 5);
+    world.systems = SystemFlags::RAIN | SystemFlags::PHYSICS;
+    for _ in 0..3000 {
+        world.update();
+        assert!(world.humidity >= 0.1, "humidity {} fell below its floor", world.humidity);
+    }
+    
+    let mut wrapped = World::new_seeded(80, 40, 7);
+    wrapped.wrap_horizontal = true;
+    wrapped.systems = SystemFlags::RAIN | SystemFlags::PHYSICS;
+    for _ in 0..3000 {
+        wrapped.update();
+    }
+}
+
+fn snow_tiles(world: &World) -> usize {
+    world.tiles.iter().flatten().filter(|t| matches!(t, TileType::Snow(_))).count()
+}
+
+#[test]
+fn winter_snowpack_melts_in_spring() {
+    let mut world = WorldBuilder::new(30, 15).seed(5).build();
+    for x in 0..30 {
+        world.tiles[14][x] = TileType::Rock;
+    }
+    world.physics_config.evaporation_rate = 0.0;
+    world.set_weather_controller(Box::new(|world: &mut World| {
+        world.wind_strength = 0.0;
+        if world.tick <= 150 {
+            // Snowy winter
+            world.season_cycle = 0.8;
+            world.temperature = -0.6;
+            world.rain_intensity = 0.8;
+        } else {
+            // Warm spring, no more precipitation
+            world.season_cycle = 0.1;
+            world.temperature = 0.5;
+            world.rain_intensity = 0.0;
+        }
+    }));
+    
+    for _ in 0..150 {
+        world.update();
+    }
+    assert!(snow_tiles(&world) > 20, "only {} snow tiles after winter", snow_tiles(&world));
+    assert_eq!(water_tiles(&world), 0);
+    
+    for _ in 0..300 {
+        world.update();
+    }
+    assert_eq!(snow_tiles(&world), 0);
+    assert!(water_tiles(&world) > 0);
+}
+
+#[test]
+fn year_and_day_lengths_set_the_cycles() {
+    let mut world = WorldBuilder::new(10, 5).fill_soil(2).build();
+    world.set_year_length(40);
+    world.ticks_per_day = 8;
+    
+    let mut seasons = Vec::new();
+    for _ in 0..40 {
+        world.update();
+        seasons.push(world.get_season_name());
+    }
+    assert_eq!(world.season_cycle, 0.0, "a whole year brings spring back");
+    assert_eq!(seasons[..9], ["Spring"; 9]);
+    assert_eq!(seasons[10..19], ["Summer"; 9]);
+    assert_eq!(seasons[30..39], ["Winter"; 9]);
+    
+    // Two ticks into an eight-tick day is a quarter of the way round
+    world.update();
+    world.update();
+    assert!((world.day_cycle - std::f32::consts::FRAC_PI_2).abs() < 1e-5);
+    
+    world.set_year_length(0);
+    assert_eq!(world.ticks_per_year, 1);
+}
+
+#[test]
+fn scripts_can_set_the_weather_wind_season_and_rain() {
+    let mut world = WorldBuilder::new(10, 5).fill_soil(2).build();
+    world.set_year_length(40);
+    for _ in 0..7 {
+        world.update();
+    }
+    
+    world.set_season(Season::Winter);
+    assert_eq!(world.get_season_name(), "Winter");
+    assert_eq!(world.tick, 7);
+    for _ in 0..9 {
+        world.update();
+        assert_eq!(world.get_season_name(), "Winter");
+    }
+    world.update();
+    assert_eq!(world.get_season_name(), "Spring", "the year carries on from the new season");
+    
+    world.set_weather(-2.0, 0.2);
+    assert_eq!((world.temperature, world.humidity), (-1.0, 0.2));
+    world.set_weather(0.0, 0.0);
+    assert_eq!(world.humidity, 0.1, "humidity stays within the weather model's range");
+    world.set_weather(-2.0, 0.2);
+    world.trigger_rain(0.7);
+    assert_eq!(world.rain_intensity, 0.7);
+    
+    world.set_wind(1.0, 0.6);
+    assert_eq!((world.wind_direction, world.wind_strength), (1.0, 0.6));
+    world.wind_mode = WindMode::Deterministic { direction: 3.0, strength: 0.1 };
+    world.set_wind(2.0, 0.9);
+    world.update();
+    assert_eq!((world.wind_direction, world.wind_strength), (2.0, 0.9), "pinned wind stays at the new setting");
+    
+    // The season survives a save
+    let mut bytes = Vec::new();
+    world.save_to_writer(&mut bytes).unwrap();
+    let loaded = World::load_from_reader(&mut &bytes[..]).unwrap();
+    assert_eq!(loaded.season_offset, world.season_offset);
+}
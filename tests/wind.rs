@@ -0,0 +1,195 @@
+use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+use pillbugplants::builder::WorldBuilder;
+use pillbugplants::types::{TileType, Size, WindMode, EdgeBehavior};
+use pillbugplants::world::compass_point;
+
+#[test]
+fn deterministic_wind_stays_pinned() {
+    let mut world = WorldBuilder::new(20, 10)
+        .seed(5)
+        .fill_soil(2)
+        .wind(WindMode::Deterministic { direction: 1.0, strength: 0.7 })
+        .build();
+    
+    for _ in 0..200 {
+        world.update();
+        assert_eq!(world.wind_direction, 1.0);
+        assert_eq!(world.wind_strength, 0.7);
+    }
+}
+
+#[test]
+fn seasonal_wind_varies() {
+    let mut world = WorldBuilder::new(20, 10).seed(5).fill_soil(2).build();
+    let start = (world.wind_direction, world.wind_strength);
+    
+    for _ in 0..200 {
+        world.update();
+    }
+    
+    assert_ne!((world.wind_direction, world.wind_strength), start);
+}
+
+const SPORES: usize = 20;
+
+fn spores_after_steady_wind(edge_behavior: EdgeBehavior, direction: f32) -> usize {
+    let mut builder = WorldBuilder::new(30, 12)
+        .seed(9)
+        .fill_soil(2)
+        .wind(WindMode::Deterministic { direction, strength: 1.0 })
+        .edges(edge_behavior);
+    for x in 5..5 + SPORES {
+        builder = builder.place(x, 5, TileType::Spore(0));
+    }
+    let mut world = builder.build();
+    world.allow_respawn = false;
+    
+    for _ in 0..40 {
+        world.update();
+    }
+    
+    world.tiles.iter().flatten().filter(|t| matches!(t, TileType::Spore(_))).count()
+}
+
+#[test]
+fn edge_behavior_decides_whether_blown_particles_survive() {
+    // Due east, and east with a lift toward the ceiling
+    for direction in [0.0, -FRAC_PI_4] {
+        let absorbed = spores_after_steady_wind(EdgeBehavior::Absorb, direction);
+        let reflected = spores_after_steady_wind(EdgeBehavior::Reflect, direction);
+        let wrapped = spores_after_steady_wind(EdgeBehavior::Wrap, direction);
+        
+        assert!(absorbed < SPORES / 2, "absorbing edges should lose most spores, {} left", absorbed);
+        assert_eq!(reflected, SPORES, "reflecting edges should keep every spore");
+        assert_eq!(wrapped, SPORES, "wrapping edges should keep every spore");
+    }
+}
+
+#[test]
+fn ceiling_holds_particles_whatever_the_edge_behavior() {
+    for edge_behavior in [EdgeBehavior::Absorb, EdgeBehavior::Reflect, EdgeBehavior::Wrap] {
+        assert_eq!(spores_after_steady_wind(edge_behavior, -FRAC_PI_2), SPORES, "{:?} lost spores through the ceiling", edge_behavior);
+    }
+}
+
+fn spores_moved_in_light_wind(gust: Option<(f32, u8)>) -> usize {
+    let mut builder = WorldBuilder::new(30, 12)
+        .seed(9)
+        .fill_soil(2)
+        .wind(WindMode::Deterministic { direction: 1.0, strength: 0.05 });
+    for x in 5..15 {
+        builder = builder.place(x, 5, TileType::Spore(0));
+    }
+    let mut world = builder.build();
+    world.gust = gust;
+    
+    for _ in 0..5 {
+        world.update();
+    }
+    
+    // Spores drift down either way, so only count those blown out of the columns they started in
+    let stayed = world.tiles.iter()
+        .flat_map(|row| &row[5..15])
+        .filter(|tile| matches!(tile, TileType::Spore(_)))
+        .count();
+    10 - stayed
+}
+
+#[test]
+fn gusts_scatter_particles_that_light_wind_leaves_alone() {
+    let calm = spores_moved_in_light_wind(None);
+    let gusty = spores_moved_in_light_wind(Some((0.8, 5)));
+    
+    assert!(gusty > calm, "a gust moved {} spores, steady wind {}", gusty, calm);
+}
+
+#[test]
+fn gusts_come_and_go_in_seasonal_wind() {
+    let mut world = WorldBuilder::new(20, 10).seed(5).fill_soil(2).build();
+    let mut gusty_ticks = 0;
+    
+    for _ in 0..1000 {
+        world.update();
+        if let Some((extra, ticks)) = world.gust {
+            gusty_ticks += 1;
+            assert!(ticks >= 1 && extra > 0.0);
+            assert!(world.effective_wind_strength() > world.wind_strength || world.wind_strength >= 1.5);
+        } else {
+            assert_eq!(world.effective_wind_strength(), world.wind_strength);
+        }
+    }
+    
+    assert!(gusty_ticks > 0, "no gust in 1000 ticks");
+    assert!(gusty_ticks < 500, "gusts should be bursts, not the norm ({} gusty ticks)", gusty_ticks);
+}
+
+#[test]
+fn horizontal_wrap_keeps_blown_particles_despite_absorbing_edges() {
+    let mut builder = WorldBuilder::new(30, 12)
+        .seed(9)
+        .fill_soil(2)
+        .wind(WindMode::Deterministic { direction: 0.0, strength: 1.0 })
+        .edges(EdgeBehavior::Absorb)
+        .wrap_horizontal(true);
+    for x in 5..25 {
+        builder = builder.place(x, 5, TileType::Spore(0));
+    }
+    let mut world = builder.build();
+    
+    for _ in 0..40 {
+        world.update();
+    }
+    
+    let spores = world.tiles.iter().flatten().filter(|t| matches!(t, TileType::Spore(_))).count();
+    assert!(spores >= 15, "wrapping east-west should keep spores, {} left", spores);
+}
+
+#[test]
+fn neighbors_wrap_only_east_west() {
+    let mut world = WorldBuilder::new(10, 5).fill_soil(1).build();
+    assert_eq!(world.neighbor(9, 2, 1, 0), None);
+    assert_eq!(world.neighbor(4, 2, 1, -1), Some((5, 1)));
+    
+    world.wrap_horizontal = true;
+    assert_eq!(world.neighbor(9, 2, 1, 0), Some((0, 2)));
+    assert_eq!(world.neighbor(0, 2, -1, 1), Some((9, 3)));
+    assert_eq!(world.neighbor(3, 4, 0, 1), None);
+}
+
+#[test]
+fn wind_reads_as_a_vector_and_a_compass_point() {
+    let mut world = WorldBuilder::new(20, 10).fill_soil(2).build();
+    world.set_wind(0.0, 0.5);
+    let (x, y) = world.wind_vector();
+    assert!((x - 0.5).abs() < 1e-6 && y.abs() < 1e-6);
+    assert_eq!(world.wind_compass(), "E");
+    
+    // Angles turn toward the bottom of the world, which is south
+    for (direction, point) in [(FRAC_PI_2, "S"), (PI, "W"), (3.0 * FRAC_PI_2, "N"), (-FRAC_PI_4, "NE"), (3.0 * FRAC_PI_4, "SW")] {
+        world.set_wind(direction, 0.5);
+        assert_eq!(world.wind_compass(), point, "direction {}", direction);
+    }
+    assert_eq!(compass_point(2.0 * PI + 0.1), "E");
+}
+
+#[test]
+fn seeds_drift_with_the_wind() {
+    let drift = |direction: f32| {
+        let mut world = WorldBuilder::new(60, 30)
+            .fill_soil(2)
+            .wind(WindMode::Deterministic { direction, strength: 0.8 })
+            .build();
+        assert_eq!(world.net_dispersal_direction(), None);
+        for x in [20.0, 30.0, 40.0] {
+            world.launch_seed(x, 2.0, 0.0, 0.0, Size::Small);
+        }
+        for _ in 0..10 {
+            world.update();
+        }
+        world.net_dispersal_direction().unwrap()
+    };
+    
+    // Seeds fall as they go, so they drift down and downwind
+    assert_eq!(compass_point(drift(0.0)), "SE");
+    assert_eq!(compass_point(drift(PI)), "SW");
+}